@@ -9,7 +9,8 @@
 //! ## Sample Size Calculation
 //!
 //! To calculate the required sample size for ANOVA tests, you can use the following function:
-//! - `f_sample_size`: Calculates the necessary sample size for one-way ANOVA tests
+//! - `f_sample_size`: Calculates the necessary sample size per group for one-way ANOVA tests, iterating on exact power.
+//! - `f_power`: Calculates the exact power of a one-way ANOVA test from the noncentral F-distribution.
 //!
 //! ## Submodules
 //!
@@ -21,14 +22,15 @@
 //! The following functions are made available for use:
 //! - `anova`: Performs one-way ANOVA tests on multiple groups of data.
 //! - `f_sample_size`: Calculates the required sample size for one-way ANOVA tests
+//! - `f_power`: Calculates the exact power of one-way ANOVA tests
 //!
 //! ## Example
 //! ```rust
-//! use hypors::anova::{anova, f_sample_size};
+//! use hypors::anova::{anova, f_power, f_sample_size};
 //! ```
 
 pub mod one_way;
 pub mod sample_size;
 
 pub use one_way::anova;
-pub use sample_size::f_sample_size;
+pub use sample_size::{f_power, f_sample_size};
@@ -1,9 +1,51 @@
+use crate::common::calc::solve_sample_size;
+use crate::noncentral::NoncentralFisherSnedecor;
 use statrs::distribution::{ContinuousCDF, FisherSnedecor};
 
+/// Calculates the exact power of a one-way ANOVA test with equal group sizes.
+///
+/// Under the alternative, the F-statistic follows a noncentral F-distribution with
+/// `num_groups - 1` and `num_groups * (n - 1)` degrees of freedom and noncentrality
+/// `λ = f² * num_groups * n`, where `f` is Cohen's f.
+///
+/// # Arguments
+///
+/// * `effect_size` - The effect size (Cohen's f).
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `n` - The sample size per group (at least 2).
+/// * `num_groups` - The number of groups in the ANOVA.
+///
+/// # Returns
+///
+/// The probability of rejecting the null hypothesis, or `NaN` if the inputs are invalid.
+///
+/// # Example
+/// ```rust
+/// use hypors::anova::f_power;
+///
+/// let power = f_power(0.25, 0.05, 53.0, 3);
+/// assert!((power - 0.8048).abs() < 1e-3);
+/// ```
+pub fn f_power(effect_size: f64, alpha: f64, n: f64, num_groups: usize) -> f64 {
+    let k = num_groups as f64;
+    let df1 = k - 1.0; // Degrees of freedom for the numerator
+    let df2 = k * (n - 1.0); // Degrees of freedom for the denominator
+    let lambda = effect_size.powi(2) * k * n;
+
+    match (
+        FisherSnedecor::new(df1, df2),
+        NoncentralFisherSnedecor::new(df1, df2, lambda),
+    ) {
+        (Ok(f_dist), Ok(ncf)) => 1.0 - ncf.cdf(f_dist.inverse_cdf(1.0 - alpha)),
+        _ => f64::NAN,
+    }
+}
+
 /// Calculates the required sample size for an ANOVA test.
 ///
-/// This function computes the necessary sample size to detect a minimum detectable effect size
-/// for a given alpha, power, and number of groups.
+/// This function finds the smallest sample size per group whose exact power, computed from the
+/// noncentral F-distribution, reaches the desired power for the given effect size, alpha, and
+/// number of groups.
 ///
 /// # Arguments
 ///
@@ -14,7 +56,8 @@ use statrs::distribution::{ContinuousCDF, FisherSnedecor};
 ///
 /// # Returns
 ///
-/// The estimated sample size required to achieve the specified power and significance level.
+/// The sample size per group required to achieve the specified power and significance level,
+/// or `NaN` if the inputs are invalid.
 ///
 /// # Example
 /// ```rust
@@ -26,19 +69,13 @@ use statrs::distribution::{ContinuousCDF, FisherSnedecor};
 /// let num_groups = 3; // Number of groups
 ///
 /// let sample_size = f_sample_size(effect_size, alpha, power, num_groups);
-/// println!("Required sample size: {}", sample_size);
+/// assert_eq!(sample_size, 53.0);
 /// ```
 pub fn f_sample_size(effect_size: f64, alpha: f64, power: f64, num_groups: usize) -> f64 {
-    let df1 = (num_groups - 1) as f64; // Degrees of freedom for the numerator
-    let df2 = 1e6; // Approximation for large sample sizes (will be refined later)
-
-    let f_dist = FisherSnedecor::new(df1, df2).expect("Failed to create Fisher's F distribution");
-
-    // Calculate critical F-values based on alpha and power
-    let f_alpha = f_dist.inverse_cdf(1.0 - alpha);
-    let f_beta = f_dist.inverse_cdf(power);
-
-    // Formula: n = ((f_alpha + f_beta) * (num_groups - 1) / effect_size^2)^2
-    let n = ((f_alpha + f_beta) * df1 / effect_size.powi(2)).powi(2);
-    n.ceil() // Rounds up to the next whole sample size
+    solve_sample_size(
+        |n| f_power(effect_size, alpha, n, num_groups),
+        alpha,
+        power,
+        2.0,
+    )
 }
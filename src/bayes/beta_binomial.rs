@@ -0,0 +1,125 @@
+use super::posterior::{BetaPosterior, beta_prob_greater, compare};
+use super::types::{BayesResult, BetaPrior};
+use crate::common::StatError;
+
+/// Performs a Bayesian A/B comparison of two conversion rates with Beta-Binomial conjugate
+/// updating.
+///
+/// Each variant's rate has a `Beta(prior.alpha + successes, prior.beta + failures)` posterior.
+/// `P(B > A)` is computed exactly (in closed form whenever a posterior shape parameter is a
+/// whole number, as with the default uniform prior), and the expected losses and credible
+/// interval for `B - A` by numerical integration.
+///
+/// # Arguments
+///
+/// * `data_a` - An iterator over 0/1 outcomes of variant A (the control).
+/// * `data_b` - An iterator over 0/1 outcomes of variant B (the treatment).
+/// * `prior` - The Beta prior shared by both rates.
+/// * `alpha` - One minus the credible level (e.g., 0.05 for 95% credible intervals).
+///
+/// # Returns
+///
+/// A `BayesResult` struct containing `P(B > A)`, expected losses, posterior means and
+/// credible intervals.
+///
+/// # Errors
+///
+/// Returns a `StatError` if either group is empty, an outcome is not 0 or 1, the prior is not
+/// proper, or alpha lies outside `(0, 1)`.
+///
+/// # Example
+/// ```rust
+/// use hypors::bayes::{beta_binomial_ab, BetaPrior};
+///
+/// let a = vec![1, 0, 0, 1, 0, 0, 0, 1, 0, 0];
+/// let b = vec![1, 1, 0, 1, 0, 1, 0, 1, 1, 0];
+///
+/// let result = beta_binomial_ab(a, b, &BetaPrior::default(), 0.05).unwrap();
+/// assert!(result.prob_b_beats_a > 0.9);
+/// ```
+pub fn beta_binomial_ab<I1, I2, T>(
+    data_a: I1,
+    data_b: I2,
+    prior: &BetaPrior,
+    alpha: f64,
+) -> Result<BayesResult, StatError>
+where
+    I1: IntoIterator<Item = T>,
+    I2: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    let count = |data: Vec<f64>| -> Result<(f64, f64), StatError> {
+        if data.iter().any(|&x| x != 0.0 && x != 1.0) {
+            return Err(StatError::ComputeError(
+                "Outcomes must be 0 or 1".to_string(),
+            ));
+        }
+        Ok((data.iter().sum(), data.len() as f64))
+    };
+    let (successes_a, trials_a) = count(data_a.into_iter().map(|x| x.into()).collect())?;
+    let (successes_b, trials_b) = count(data_b.into_iter().map(|x| x.into()).collect())?;
+
+    beta_binomial_ab_counts(successes_a, trials_a, successes_b, trials_b, prior, alpha)
+}
+
+/// Performs a Bayesian A/B comparison of two conversion rates from success and trial counts.
+///
+/// See `beta_binomial_ab` for details.
+///
+/// # Arguments
+///
+/// * `successes_a`, `trials_a` - The conversions and visitors of variant A (the control).
+/// * `successes_b`, `trials_b` - The conversions and visitors of variant B (the treatment).
+/// * `prior` - The Beta prior shared by both rates.
+/// * `alpha` - One minus the credible level (e.g., 0.05 for 95% credible intervals).
+///
+/// # Returns
+///
+/// A `BayesResult` struct containing `P(B > A)`, expected losses, posterior means and
+/// credible intervals.
+///
+/// # Example
+/// ```rust
+/// use hypors::bayes::{beta_binomial_ab_counts, BetaPrior};
+///
+/// let result = beta_binomial_ab_counts(100.0, 1000.0, 130.0, 1000.0, &BetaPrior::default(), 0.05)
+///     .unwrap();
+/// assert!((result.prob_b_beats_a - 0.9822).abs() < 1e-3);
+/// assert!(result.expected_loss_b < result.expected_loss_a);
+/// ```
+pub fn beta_binomial_ab_counts(
+    successes_a: f64,
+    trials_a: f64,
+    successes_b: f64,
+    trials_b: f64,
+    prior: &BetaPrior,
+    alpha: f64,
+) -> Result<BayesResult, StatError> {
+    if trials_a <= 0.0 || trials_b <= 0.0 {
+        return Err(StatError::EmptyData);
+    }
+    if successes_a < 0.0 || successes_b < 0.0 || successes_a > trials_a || successes_b > trials_b {
+        return Err(StatError::ComputeError(
+            "Successes must be between 0 and the number of trials".to_string(),
+        ));
+    }
+    if prior.alpha <= 0.0 || prior.beta <= 0.0 {
+        return Err(StatError::ComputeError(
+            "Beta prior parameters must be positive".to_string(),
+        ));
+    }
+    if alpha <= 0.0 || alpha >= 1.0 {
+        return Err(StatError::ComputeError(
+            "Alpha must be between 0 and 1".to_string(),
+        ));
+    }
+
+    let posterior = |successes: f64, trials: f64| {
+        BetaPosterior::new(prior.alpha + successes, prior.beta + trials - successes)
+            .ok_or_else(|| StatError::ComputeError("Failed to create Beta posterior".to_string()))
+    };
+    let a = posterior(successes_a, trials_a)?;
+    let b = posterior(successes_b, trials_b)?;
+
+    Ok(compare(&a, &b, beta_prob_greater(&a, &b), alpha))
+}
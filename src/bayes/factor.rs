@@ -0,0 +1,322 @@
+use super::types::SamplingPlan;
+use crate::common::{StatError, TailType};
+use crate::t::{t_test, t_test_ind, t_test_paired};
+use statrs::function::gamma::ln_gamma;
+
+/// Number of Simpson intervals used to integrate over the log of `g`.
+const INTERVALS: usize = 4000;
+/// Integration range of `ln g`, covering all but a negligible part of the prior mass.
+const LOG_G_RANGE: (f64, f64) = (-12.0, 80.0);
+
+/// Calculates the JZS Bayes factor `BF10` from a t-statistic.
+///
+/// The effect size has a Cauchy prior with scale `r_scale`, written as a normal prior whose
+/// variance `g r²` has an inverse-gamma(1/2, 1/2) distribution (Rouder et al., 2009); the
+/// integral over `g` is evaluated numerically on the log scale.
+///
+/// # Arguments
+///
+/// * `t_stat` - The t-statistic.
+/// * `n_eff` - The effective sample size: `n` for one-sample and paired tests and
+///   `n1 n2 / (n1 + n2)` for independent tests.
+/// * `df` - The degrees of freedom of the t-statistic.
+/// * `r_scale` - The scale of the Cauchy prior on the standardized effect size (e.g., `√2/2`).
+///
+/// # Returns
+///
+/// The Bayes factor in favour of the alternative hypothesis, or `NaN` if the inputs are
+/// invalid.
+///
+/// # Example
+/// ```rust
+/// use hypors::bayes::jzs_bf_t;
+///
+/// let bf10 = jzs_bf_t(2.5, 30.0, 29.0, 2.0_f64.sqrt() / 2.0);
+/// assert!((bf10 - 2.7091).abs() < 1e-3);
+/// ```
+pub fn jzs_bf_t(t_stat: f64, n_eff: f64, df: f64, r_scale: f64) -> f64 {
+    if !(n_eff > 0.0 && df > 0.0 && r_scale > 0.0 && t_stat.is_finite()) {
+        return f64::NAN;
+    }
+
+    let log_null = -(df + 1.0) / 2.0 * (1.0 + t_stat.powi(2) / df).ln();
+    let log_integrand = |u: f64| {
+        let g = u.exp();
+        let spread = 1.0 + n_eff * g * r_scale.powi(2);
+        -0.5 * spread.ln()
+            - (df + 1.0) / 2.0 * (1.0 + t_stat.powi(2) / (spread * df)).ln()
+            - 0.5 * (2.0 * std::f64::consts::PI).ln()
+            - 1.5 * u
+            - 1.0 / (2.0 * g)
+            + u // Jacobian of g = e^u
+    };
+
+    let (low, high) = LOG_G_RANGE;
+    let h = (high - low) / INTERVALS as f64;
+    let logs: Vec<f64> = (0..=INTERVALS)
+        .map(|i| log_integrand(low + i as f64 * h))
+        .collect();
+    let max = logs.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let sum: f64 = logs
+        .iter()
+        .enumerate()
+        .map(|(i, &l)| {
+            let weight = if i == 0 || i == INTERVALS {
+                1.0
+            } else if i % 2 == 1 {
+                4.0
+            } else {
+                2.0
+            };
+            weight * (l - max).exp()
+        })
+        .sum();
+
+    (max + (sum * h / 3.0).ln() - log_null).exp()
+}
+
+/// Calculates the JZS Bayes factor for a one-sample t-test.
+///
+/// # Arguments
+///
+/// * `data` - An iterator containing the sample data (any type that can be converted to f64).
+/// * `pop_mean` - The hypothesized population mean.
+/// * `r_scale` - The scale of the Cauchy prior on the standardized effect size (e.g., `√2/2`).
+///
+/// # Returns
+///
+/// The Bayes factor `BF10` in favour of the alternative hypothesis; its reciprocal `BF01`
+/// measures the evidence for the null.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the t-test cannot be computed or `r_scale` is not positive.
+///
+/// # Example
+/// ```rust
+/// use hypors::bayes::jzs_bf;
+///
+/// let data = vec![0.8, 1.2, 0.4, 1.5, 0.9, 1.1, 0.7, 1.3];
+/// let bf10 = jzs_bf(data, 0.0, 2.0_f64.sqrt() / 2.0).unwrap();
+/// assert!(bf10 > 100.0);
+/// ```
+pub fn jzs_bf<I, T>(data: I, pop_mean: f64, r_scale: f64) -> Result<f64, StatError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    let sample: Vec<f64> = data.into_iter().map(|x| x.into()).collect();
+    let n = sample.len() as f64;
+    let result = t_test(sample, pop_mean, TailType::Two, 0.05)?;
+    checked_bf(result.test_statistic, n, n - 1.0, r_scale)
+}
+
+/// Calculates the JZS Bayes factor for a paired t-test.
+///
+/// # Arguments
+///
+/// * `data1` - An iterator containing the first sample.
+/// * `data2` - An iterator containing the second sample, paired with the first.
+/// * `r_scale` - The scale of the Cauchy prior on the standardized effect size (e.g., `√2/2`).
+///
+/// # Returns
+///
+/// The Bayes factor `BF10` in favour of a nonzero mean difference.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the t-test cannot be computed or `r_scale` is not positive.
+///
+/// # Example
+/// ```rust
+/// use hypors::bayes::jzs_bf_paired;
+///
+/// let before = vec![5.0, 6.0, 7.0, 8.0, 9.0];
+/// let after = vec![5.1, 5.9, 7.2, 7.9, 9.1];
+/// let bf10 = jzs_bf_paired(before, after, 2.0_f64.sqrt() / 2.0).unwrap();
+/// assert!(bf10 < 1.0); // Evidence for no difference
+/// ```
+pub fn jzs_bf_paired<I1, I2, T1, T2>(data1: I1, data2: I2, r_scale: f64) -> Result<f64, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    let sample1: Vec<f64> = data1.into_iter().map(|x| x.into()).collect();
+    let n = sample1.len() as f64;
+    let result = t_test_paired(sample1, data2, TailType::Two, 0.05)?;
+    checked_bf(result.test_statistic, n, n - 1.0, r_scale)
+}
+
+/// Calculates the JZS Bayes factor for an independent two-sample t-test with pooled variance.
+///
+/// # Arguments
+///
+/// * `data1` - An iterator containing the first sample.
+/// * `data2` - An iterator containing the second sample.
+/// * `r_scale` - The scale of the Cauchy prior on the standardized effect size (e.g., `√2/2`).
+///
+/// # Returns
+///
+/// The Bayes factor `BF10` in favour of a difference in means.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the t-test cannot be computed or `r_scale` is not positive.
+///
+/// # Example
+/// ```rust
+/// use hypors::bayes::jzs_bf_ind;
+///
+/// let group1 = vec![5.1, 4.9, 5.3, 5.0, 4.8, 5.2];
+/// let group2 = vec![5.6, 5.4, 5.9, 5.5, 5.7, 5.3];
+/// let bf10 = jzs_bf_ind(group1, group2, 2.0_f64.sqrt() / 2.0).unwrap();
+/// assert!(bf10 > 10.0);
+/// ```
+pub fn jzs_bf_ind<I1, I2, T1, T2>(data1: I1, data2: I2, r_scale: f64) -> Result<f64, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    let sample1: Vec<f64> = data1.into_iter().map(|x| x.into()).collect();
+    let sample2: Vec<f64> = data2.into_iter().map(|x| x.into()).collect();
+    let (n1, n2) = (sample1.len() as f64, sample2.len() as f64);
+    let result = t_test_ind(sample1, sample2, TailType::Two, 0.05, true)?;
+    checked_bf(
+        result.test_statistic,
+        n1 * n2 / (n1 + n2),
+        n1 + n2 - 2.0,
+        r_scale,
+    )
+}
+
+fn checked_bf(t_stat: f64, n_eff: f64, df: f64, r_scale: f64) -> Result<f64, StatError> {
+    if r_scale <= 0.0 {
+        return Err(StatError::ComputeError(
+            "Cauchy prior scale must be positive".to_string(),
+        ));
+    }
+    let bf = jzs_bf_t(t_stat, n_eff, df, r_scale);
+    if bf.is_nan() {
+        Err(StatError::ComputeError(
+            "Failed to compute the Bayes factor".to_string(),
+        ))
+    } else {
+        Ok(bf)
+    }
+}
+
+/// Calculates the log of the multivariate Beta function, `Σ ln Γ(v_k) - ln Γ(Σ v_k)`.
+fn ln_multi_beta(values: &[f64]) -> f64 {
+    values.iter().map(|&v| ln_gamma(v)).sum::<f64>() - ln_gamma(values.iter().sum())
+}
+
+/// Calculates the Gunel-Dickey Bayes factor for independence in a contingency table.
+///
+/// Cell probabilities have a Dirichlet prior with concentration `prior_concentration` in every
+/// cell under the alternative; the priors under independence are the margins implied by it
+/// (Gunel and Dickey, 1974; Jamil et al., 2017). The Bayes factor depends on the sampling plan:
+/// the joint multinomial fixes only the grand total, and the independent multinomials fix the
+/// row or column totals.
+///
+/// # Arguments
+///
+/// * `contingency_table` - The observed counts, as rows of equal length.
+/// * `sampling_plan` - How the table was sampled.
+/// * `prior_concentration` - The Dirichlet concentration per cell (1 for the default prior).
+///
+/// # Returns
+///
+/// The Bayes factor `BF10` in favour of dependence between rows and columns.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the table has fewer than two rows or columns, rows of unequal
+/// length, negative counts, or a concentration too small for a proper prior under
+/// independence.
+///
+/// # Example
+/// ```rust
+/// use hypors::bayes::{gunel_dickey_bf, SamplingPlan};
+///
+/// let table = vec![vec![30.0, 10.0], vec![15.0, 25.0]];
+/// let bf10 = gunel_dickey_bf(&table, SamplingPlan::IndependentRows, 1.0).unwrap();
+/// assert!(bf10 > 10.0);
+/// ```
+pub fn gunel_dickey_bf(
+    contingency_table: &[Vec<f64>],
+    sampling_plan: SamplingPlan,
+    prior_concentration: f64,
+) -> Result<f64, StatError> {
+    let num_rows = contingency_table.len();
+    if num_rows < 2 {
+        return Err(StatError::ComputeError("At least two rows required".into()));
+    }
+    let num_cols = contingency_table[0].len();
+    if num_cols < 2 || !contingency_table.iter().all(|row| row.len() == num_cols) {
+        return Err(StatError::ComputeError(
+            "All rows must have equal and ≥2 columns".into(),
+        ));
+    }
+    if contingency_table.iter().flatten().any(|&y| y < 0.0) {
+        return Err(StatError::ComputeError(
+            "Counts must be non-negative".into(),
+        ));
+    }
+
+    // Transpose so that fixed column totals become fixed row totals
+    let table: Vec<Vec<f64>> = match sampling_plan {
+        SamplingPlan::IndependentColumns => (0..num_cols)
+            .map(|j| contingency_table.iter().map(|row| row[j]).collect())
+            .collect(),
+        _ => contingency_table.to_vec(),
+    };
+    let (rows, cols) = (table.len() as f64, table[0].len() as f64);
+
+    let a = prior_concentration;
+    let row_totals: Vec<f64> = table.iter().map(|row| row.iter().sum()).collect();
+    let col_totals: Vec<f64> = (0..table[0].len())
+        .map(|j| table.iter().map(|row| row[j]).sum())
+        .collect();
+
+    // Margin priors implied by the cell prior: ξ_i· = a_i· - (J - 1), ξ_·j = a_·j - (I - 1)
+    let xi_row = a * cols - (cols - 1.0);
+    let xi_col = a * rows - (rows - 1.0);
+    if a <= 0.0
+        || xi_col <= 0.0
+        || (sampling_plan == SamplingPlan::JointMultinomial && xi_row <= 0.0)
+    {
+        return Err(StatError::ComputeError(
+            "Prior concentration is too small for the table dimensions".into(),
+        ));
+    }
+
+    let shifted = |totals: &[f64], xi: f64| -> f64 {
+        let posterior: Vec<f64> = totals.iter().map(|y| y + xi).collect();
+        ln_multi_beta(&posterior) - ln_multi_beta(&vec![xi; totals.len()])
+    };
+    let col_evidence = shifted(&col_totals, xi_col);
+
+    let log_bf10 = match sampling_plan {
+        SamplingPlan::JointMultinomial => {
+            let cells: Vec<f64> = table.iter().flatten().map(|y| y + a).collect();
+            let alternative = ln_multi_beta(&cells) - ln_multi_beta(&vec![a; cells.len()]);
+            alternative - shifted(&row_totals, xi_row) - col_evidence
+        }
+        SamplingPlan::IndependentRows | SamplingPlan::IndependentColumns => {
+            let alternative: f64 = table
+                .iter()
+                .map(|row| {
+                    let posterior: Vec<f64> = row.iter().map(|y| y + a).collect();
+                    ln_multi_beta(&posterior) - ln_multi_beta(&vec![a; row.len()])
+                })
+                .sum();
+            alternative - col_evidence
+        }
+    };
+
+    Ok(log_bf10.exp())
+}
@@ -0,0 +1,64 @@
+//! # Bayesian A/B Testing
+//!
+//! The `bayes` module provides Bayesian comparisons of two variants, reporting the probability
+//! that variant B beats variant A, the expected loss of choosing either, and credible
+//! intervals, rather than a reject/accept decision.
+//!
+//! ## Models
+//!
+//! - **Beta-Binomial**: Conversion rates with a Beta prior; `P(B > A)` is exact.
+//! - **Normal-Inverse-Gamma**: Continuous metrics with unknown mean and variance; each mean
+//!   has a Student's t posterior.
+//!
+//! Both functions take the same input shapes as `proportion::z_test_ind` and `t::t_test_ind`.
+//!
+//! ## Bayes Factors
+//!
+//! - **JZS**: Default Bayes factors for one-sample, paired and independent t-tests, with a
+//!   Cauchy prior of configurable scale on the standardized effect size.
+//! - **Gunel-Dickey**: Bayes factors for independence in contingency tables under joint or
+//!   independent multinomial sampling.
+//!
+//! ## Submodules
+//!
+//! - `beta_binomial`: Contains the conjugate analysis of conversion rates.
+//! - `factor`: Contains the JZS and Gunel-Dickey Bayes factors.
+//! - `normal`: Contains the conjugate analysis of means.
+//! - `posterior`: Contains the posterior distributions and their comparison.
+//! - `types`: Defines the prior and result types.
+//!
+//! ## Exports
+//!
+//! The following functions and types are made available for use:
+//!
+//! - `beta_binomial_ab`: Compares two conversion rates from 0/1 outcomes.
+//! - `beta_binomial_ab_counts`: Compares two conversion rates from success and trial counts.
+//! - `normal_ab`: Compares two means.
+//! - `jzs_bf`: Calculates the JZS Bayes factor for a one-sample t-test.
+//! - `jzs_bf_paired`: Calculates the JZS Bayes factor for a paired t-test.
+//! - `jzs_bf_ind`: Calculates the JZS Bayes factor for an independent t-test.
+//! - `jzs_bf_t`: Calculates the JZS Bayes factor from a t-statistic.
+//! - `gunel_dickey_bf`: Calculates the Gunel-Dickey Bayes factor for a contingency table.
+//! - `BetaPrior`: The Beta prior on a rate (uniform by default).
+//! - `NormalInverseGammaPrior`: The prior on a mean and variance (reference prior by default).
+//! - `BayesResult`: The probability of B beating A, expected losses and credible intervals.
+//! - `SamplingPlan`: How a contingency table was sampled.
+//!
+//! ## Example
+//! ```rust
+//! use hypors::bayes::{
+//!     beta_binomial_ab, beta_binomial_ab_counts, gunel_dickey_bf, jzs_bf, jzs_bf_ind, jzs_bf_paired,
+//!     jzs_bf_t, normal_ab, BayesResult, BetaPrior, NormalInverseGammaPrior, SamplingPlan,
+//! };
+//! ```
+
+pub mod beta_binomial;
+pub mod factor;
+pub mod normal;
+pub(crate) mod posterior;
+pub mod types;
+
+pub use beta_binomial::{beta_binomial_ab, beta_binomial_ab_counts};
+pub use factor::{gunel_dickey_bf, jzs_bf, jzs_bf_ind, jzs_bf_paired, jzs_bf_t};
+pub use normal::normal_ab;
+pub use types::{BayesResult, BetaPrior, NormalInverseGammaPrior, SamplingPlan};
@@ -0,0 +1,97 @@
+use super::posterior::{TPosterior, compare, prob_greater};
+use super::types::{BayesResult, NormalInverseGammaPrior};
+use crate::common::StatError;
+
+/// Updates a Normal-Inverse-Gamma prior with a sample and returns the marginal posterior of
+/// the mean, a t-distribution.
+fn mean_posterior(
+    sample: &[f64],
+    prior: &NormalInverseGammaPrior,
+) -> Result<TPosterior, StatError> {
+    let n = sample.len() as f64;
+    let mean = sample.iter().sum::<f64>() / n;
+    let ss = sample.iter().map(|x| (x - mean).powi(2)).sum::<f64>();
+
+    let kappa = prior.kappa + n;
+    let mu = (prior.kappa * prior.mu + n * mean) / kappa;
+    let shape = prior.alpha + n / 2.0;
+    let scale = prior.beta + ss / 2.0 + prior.kappa * n * (mean - prior.mu).powi(2) / (2.0 * kappa);
+
+    // µ | data ~ t_{2α} (µ_n, √(β_n / (α_n κ_n)))
+    if shape <= 0.5 || scale <= 0.0 {
+        return Err(StatError::InsufficientData);
+    }
+    TPosterior::new(mu, (scale / (shape * kappa)).sqrt(), 2.0 * shape)
+        .ok_or_else(|| StatError::ComputeError("Failed to create t posterior".to_string()))
+}
+
+/// Performs a Bayesian A/B comparison of two means with Normal-Inverse-Gamma conjugate
+/// updating.
+///
+/// Each variant's mean and variance are updated independently from the shared prior, giving a
+/// Student's t marginal posterior for each mean. `P(B > A)`, the expected losses and the
+/// credible interval for `B - A` are computed by numerical integration.
+///
+/// # Arguments
+///
+/// * `data_a` - An iterator over the observations of variant A (the control).
+/// * `data_b` - An iterator over the observations of variant B (the treatment).
+/// * `prior` - The Normal-Inverse-Gamma prior shared by both variants.
+/// * `alpha` - One minus the credible level (e.g., 0.05 for 95% credible intervals).
+///
+/// # Returns
+///
+/// A `BayesResult` struct containing `P(B > A)`, expected losses, posterior means and
+/// credible intervals for the means.
+///
+/// # Errors
+///
+/// Returns a `StatError` if either group is empty, the prior parameters are negative, the
+/// posterior is improper (e.g. fewer than two observations under the reference prior), or
+/// alpha lies outside `(0, 1)`.
+///
+/// # Example
+/// ```rust
+/// use hypors::bayes::{normal_ab, NormalInverseGammaPrior};
+///
+/// let a = vec![5.1, 4.9, 5.3, 5.0, 4.8, 5.2];
+/// let b = vec![5.6, 5.4, 5.9, 5.5, 5.7, 5.3];
+///
+/// let result = normal_ab(a, b, &NormalInverseGammaPrior::default(), 0.05).unwrap();
+/// assert!(result.prob_b_beats_a > 0.99);
+/// assert!(result.credible_interval_diff.0 > 0.0);
+/// ```
+pub fn normal_ab<I1, I2, T1, T2>(
+    data_a: I1,
+    data_b: I2,
+    prior: &NormalInverseGammaPrior,
+    alpha: f64,
+) -> Result<BayesResult, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    let sample_a: Vec<f64> = data_a.into_iter().map(|x| x.into()).collect();
+    let sample_b: Vec<f64> = data_b.into_iter().map(|x| x.into()).collect();
+
+    if sample_a.is_empty() || sample_b.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    if prior.kappa < 0.0 || prior.alpha < 0.0 || prior.beta < 0.0 {
+        return Err(StatError::ComputeError(
+            "Normal-Inverse-Gamma prior parameters must be non-negative".to_string(),
+        ));
+    }
+    if alpha <= 0.0 || alpha >= 1.0 {
+        return Err(StatError::ComputeError(
+            "Alpha must be between 0 and 1".to_string(),
+        ));
+    }
+
+    let a = mean_posterior(&sample_a, prior)?;
+    let b = mean_posterior(&sample_b, prior)?;
+
+    Ok(compare(&a, &b, prob_greater(&a, &b), alpha))
+}
@@ -0,0 +1,240 @@
+//! Posterior distributions and the comparisons between two of them.
+
+use super::types::BayesResult;
+use statrs::distribution::{Beta, Continuous, ContinuousCDF, StudentsT};
+use statrs::function::beta::ln_beta;
+
+/// Number of Simpson intervals used to integrate over a Student's t posterior.
+const INTERVALS: usize = 2000;
+/// Tail probability left outside the integration range.
+const TAIL: f64 = 1e-10;
+/// Half-width and step of the trapezoidal rule in `t` for a Beta posterior.
+const SINH_RANGE: f64 = 8.0;
+const SINH_STEP: f64 = 1.0 / 32.0;
+
+/// A univariate posterior distribution of a variant's parameter.
+pub(crate) trait Posterior {
+    /// Quadrature nodes `(x, weight)` with `Σ weight g(x) ≈ E[g(Y)]`.
+    fn nodes(&self) -> Vec<(f64, f64)>;
+    /// A measure of the width of the posterior.
+    fn spread(&self) -> f64;
+    fn cdf(&self, x: f64) -> f64;
+    fn quantile(&self, p: f64) -> f64;
+    fn mean(&self) -> f64;
+    /// The partial expectation `E[max(x - Y, 0)]`.
+    fn shortfall(&self, x: f64) -> f64;
+}
+
+/// The Beta posterior of a conversion rate.
+pub(crate) struct BetaPosterior {
+    pub(crate) a: f64,
+    pub(crate) b: f64,
+    dist: Beta,
+    shifted: Beta,
+}
+
+impl BetaPosterior {
+    pub(crate) fn new(a: f64, b: f64) -> Option<Self> {
+        Some(BetaPosterior {
+            a,
+            b,
+            dist: Beta::new(a, b).ok()?,
+            shifted: Beta::new(a + 1.0, b).ok()?,
+        })
+    }
+}
+
+impl Posterior for BetaPosterior {
+    fn nodes(&self) -> Vec<(f64, f64)> {
+        // x = logistic(z) with z = m + w sinh(t), centred and scaled on the approximately normal
+        // logit of the posterior. Then f(x) dx = w cosh(t) x^a (1 - x)^b / B(a, b) dt, which is
+        // bounded even when a shape parameter is below 1 and the density is not.
+        let m = (self.a / self.b).ln();
+        let w = (1.0 / self.a + 1.0 / self.b).sqrt();
+        let ln_norm = ln_beta(self.a, self.b);
+        let softplus = |z: f64| z.max(0.0) + (-z.abs()).exp().ln_1p();
+        let steps = (SINH_RANGE / SINH_STEP) as i64;
+        (-steps..=steps)
+            .map(|k| {
+                let t = k as f64 * SINH_STEP;
+                let z = m + w * t.sinh();
+                let (ln_x, ln_1mx) = (-softplus(-z), -softplus(z));
+                let weight =
+                    SINH_STEP * w * t.cosh() * (self.a * ln_x + self.b * ln_1mx - ln_norm).exp();
+                (ln_x.exp(), weight)
+            })
+            .collect()
+    }
+    fn spread(&self) -> f64 {
+        let n = self.a + self.b;
+        (self.a * self.b / (n * n * (n + 1.0))).sqrt()
+    }
+    fn cdf(&self, x: f64) -> f64 {
+        self.dist.cdf(x)
+    }
+    fn quantile(&self, p: f64) -> f64 {
+        self.dist.inverse_cdf(p)
+    }
+    fn mean(&self) -> f64 {
+        self.a / (self.a + self.b)
+    }
+    fn shortfall(&self, x: f64) -> f64 {
+        // E[(x - Y)⁺] = x F(x; a, b) - a / (a + b) F(x; a + 1, b)
+        x * self.dist.cdf(x) - self.mean() * self.shifted.cdf(x)
+    }
+}
+
+/// The Student's t posterior of a mean.
+pub(crate) struct TPosterior {
+    location: f64,
+    scale: f64,
+    freedom: f64,
+    dist: StudentsT,
+    standard: StudentsT,
+}
+
+impl TPosterior {
+    pub(crate) fn new(location: f64, scale: f64, freedom: f64) -> Option<Self> {
+        Some(TPosterior {
+            location,
+            scale,
+            freedom,
+            dist: StudentsT::new(location, scale, freedom).ok()?,
+            standard: StudentsT::new(0.0, 1.0, freedom).ok()?,
+        })
+    }
+}
+
+impl Posterior for TPosterior {
+    fn nodes(&self) -> Vec<(f64, f64)> {
+        // Simpson's rule over the central mass
+        let low = self.quantile(TAIL);
+        let high = self.quantile(1.0 - TAIL);
+        let h = (high - low) / INTERVALS as f64;
+        (0..=INTERVALS)
+            .map(|i| {
+                let x = low + i as f64 * h;
+                let weight = if i == 0 || i == INTERVALS {
+                    1.0
+                } else if i % 2 == 1 {
+                    4.0
+                } else {
+                    2.0
+                };
+                (x, weight * h / 3.0 * self.dist.pdf(x))
+            })
+            .collect()
+    }
+    fn spread(&self) -> f64 {
+        self.scale
+    }
+    fn cdf(&self, x: f64) -> f64 {
+        self.dist.cdf(x)
+    }
+    fn quantile(&self, p: f64) -> f64 {
+        self.dist.inverse_cdf(p)
+    }
+    fn mean(&self) -> f64 {
+        self.location
+    }
+    fn shortfall(&self, x: f64) -> f64 {
+        // E[(z - τ)⁺] = z F(z) + (ν + z²) / (ν - 1) f(z) for a standard t variable τ
+        let z = (x - self.location) / self.scale;
+        let nu = self.freedom;
+        self.scale * (z * self.standard.cdf(z) + (nu + z * z) / (nu - 1.0) * self.standard.pdf(z))
+    }
+}
+
+/// Integrates `g` against a posterior given its quadrature nodes, normalising the weights so
+/// that constants integrate exactly.
+fn integrate<G: Fn(f64) -> f64>(nodes: &[(f64, f64)], g: G) -> f64 {
+    let total: f64 = nodes.iter().map(|&(_, weight)| weight).sum();
+    nodes.iter().map(|&(x, weight)| weight * g(x)).sum::<f64>() / total
+}
+
+/// Calculates `P(B > A)` by numerical integration over the narrower posterior, against which
+/// the CDF of the other is smooth.
+pub(crate) fn prob_greater<P: Posterior, Q: Posterior>(a: &P, b: &Q) -> f64 {
+    let p = if a.spread() <= b.spread() {
+        integrate(&a.nodes(), |x| 1.0 - b.cdf(x))
+    } else {
+        integrate(&b.nodes(), |y| a.cdf(y))
+    };
+    p.clamp(0.0, 1.0)
+}
+
+/// Calculates `P(B > A)` for two Beta posteriors exactly, provided one of the first shape
+/// parameters is a whole number (Evan Miller's closed form).
+pub(crate) fn beta_prob_greater(a: &BetaPosterior, b: &BetaPosterior) -> f64 {
+    let exact = |x: &BetaPosterior, y: &BetaPosterior| -> f64 {
+        // P(Y > X) = Σ_{i<a_Y} B(a_X + i, b_X + b_Y) / ((b_Y + i) B(1 + i, b_Y) B(a_X, b_X))
+        (0..y.a as usize)
+            .map(|i| {
+                let i = i as f64;
+                (ln_beta(x.a + i, x.b + y.b)
+                    - (y.b + i).ln()
+                    - ln_beta(1.0 + i, y.b)
+                    - ln_beta(x.a, x.b))
+                .exp()
+            })
+            .sum()
+    };
+
+    if b.a.fract() == 0.0 {
+        exact(a, b).clamp(0.0, 1.0)
+    } else if a.a.fract() == 0.0 {
+        (1.0 - exact(b, a)).clamp(0.0, 1.0)
+    } else {
+        prob_greater(a, b)
+    }
+}
+
+/// Calculates the equal-tailed credible interval for `B - A`.
+fn diff_interval<P: Posterior, Q: Posterior>(a: &P, b: &Q, alpha: f64) -> (f64, f64) {
+    // P(B - A <= d), integrated over the narrower posterior
+    let cdf: Box<dyn Fn(f64) -> f64> = if a.spread() <= b.spread() {
+        let nodes = a.nodes();
+        Box::new(move |d| integrate(&nodes, |x| b.cdf(x + d)))
+    } else {
+        let nodes = b.nodes();
+        Box::new(move |d| integrate(&nodes, |y| 1.0 - a.cdf(y - d)))
+    };
+    let low = b.quantile(TAIL) - a.quantile(1.0 - TAIL);
+    let high = b.quantile(1.0 - TAIL) - a.quantile(TAIL);
+
+    let invert = |p: f64| {
+        let (mut lo, mut hi) = (low, high);
+        for _ in 0..100 {
+            let mid = (lo + hi) / 2.0;
+            if cdf(mid) < p {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+            if hi - lo < 1e-10 * (high - low) {
+                break;
+            }
+        }
+        (lo + hi) / 2.0
+    };
+    (invert(alpha / 2.0), invert(1.0 - alpha / 2.0))
+}
+
+/// Compares two posteriors, given `P(B > A)`.
+pub(crate) fn compare<P: Posterior, Q: Posterior>(
+    a: &P,
+    b: &Q,
+    prob_b_beats_a: f64,
+    alpha: f64,
+) -> BayesResult {
+    BayesResult {
+        prob_b_beats_a,
+        expected_loss_a: integrate(&b.nodes(), |y| a.shortfall(y)),
+        expected_loss_b: integrate(&a.nodes(), |x| b.shortfall(x)),
+        posterior_mean_a: a.mean(),
+        posterior_mean_b: b.mean(),
+        credible_interval_a: (a.quantile(alpha / 2.0), a.quantile(1.0 - alpha / 2.0)),
+        credible_interval_b: (b.quantile(alpha / 2.0), b.quantile(1.0 - alpha / 2.0)),
+        credible_interval_diff: diff_interval(a, b, alpha),
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a Beta prior on a conversion rate.
+///
+/// # Fields
+///
+/// * `alpha` - The prior number of pseudo-successes (must be positive).
+/// * `beta` - The prior number of pseudo-failures (must be positive).
+#[derive(Debug, Clone, PartialEq)]
+pub struct BetaPrior {
+    pub alpha: f64,
+    pub beta: f64,
+}
+
+impl Default for BetaPrior {
+    /// The uniform prior, `Beta(1, 1)`.
+    fn default() -> Self {
+        BetaPrior {
+            alpha: 1.0,
+            beta: 1.0,
+        }
+    }
+}
+
+/// Represents a Normal-Inverse-Gamma prior on the mean and variance of a continuous metric:
+/// `σ² ~ InvGamma(alpha, beta)` and `µ | σ² ~ N(mu, σ² / kappa)`.
+///
+/// # Fields
+///
+/// * `mu` - The prior mean.
+/// * `kappa` - The prior number of pseudo-observations behind `mu` (0 for a flat prior).
+/// * `alpha` - The shape of the inverse-gamma prior on the variance.
+/// * `beta` - The scale of the inverse-gamma prior on the variance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalInverseGammaPrior {
+    pub mu: f64,
+    pub kappa: f64,
+    pub alpha: f64,
+    pub beta: f64,
+}
+
+impl Default for NormalInverseGammaPrior {
+    /// The improper reference prior with all parameters 0, under which the posterior of each
+    /// mean is a t-distribution centred on the sample mean; it requires at least two
+    /// observations per group.
+    fn default() -> Self {
+        NormalInverseGammaPrior {
+            mu: 0.0,
+            kappa: 0.0,
+            alpha: 0.0,
+            beta: 0.0,
+        }
+    }
+}
+
+/// Stores the result of a Bayesian A/B comparison.
+///
+/// # Fields
+///
+/// * `prob_b_beats_a` - The posterior probability that variant B has the larger parameter.
+/// * `expected_loss_a` - The expected loss of choosing A, `E[max(B - A, 0)]`.
+/// * `expected_loss_b` - The expected loss of choosing B, `E[max(A - B, 0)]`.
+/// * `posterior_mean_a` - The posterior mean of variant A's parameter.
+/// * `posterior_mean_b` - The posterior mean of variant B's parameter.
+/// * `credible_interval_a` - The equal-tailed credible interval for variant A's parameter.
+/// * `credible_interval_b` - The equal-tailed credible interval for variant B's parameter.
+/// * `credible_interval_diff` - The equal-tailed credible interval for the difference `B - A`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BayesResult {
+    pub prob_b_beats_a: f64,
+    pub expected_loss_a: f64,
+    pub expected_loss_b: f64,
+    pub posterior_mean_a: f64,
+    pub posterior_mean_b: f64,
+    pub credible_interval_a: (f64, f64),
+    pub credible_interval_b: (f64, f64),
+    pub credible_interval_diff: (f64, f64),
+}
+
+/// Represents how a contingency table was sampled, which determines the Gunel-Dickey Bayes
+/// factor.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SamplingPlan {
+    /// Only the grand total was fixed in advance.
+    JointMultinomial,
+    /// The row totals were fixed in advance (e.g. a set number of units per variant).
+    IndependentRows,
+    /// The column totals were fixed in advance.
+    IndependentColumns,
+}
@@ -0,0 +1,112 @@
+use crate::common::StatError;
+use statrs::distribution::{ContinuousCDF, Normal};
+
+/// Calculates the quantile of a sorted sample using linear interpolation between order statistics.
+///
+/// # Arguments
+///
+/// * `sorted` - The sample, sorted in ascending order.
+/// * `prob` - The probability of the quantile (between 0 and 1).
+///
+/// # Returns
+///
+/// The interpolated quantile, or `NaN` if the sample is empty.
+pub(crate) fn quantile(sorted: &[f64], prob: f64) -> f64 {
+    if sorted.is_empty() {
+        return f64::NAN;
+    }
+    let h = (sorted.len() - 1) as f64 * prob.clamp(0.0, 1.0);
+    let lower = h.floor() as usize;
+    let upper = h.ceil() as usize;
+    sorted[lower] + (h - lower as f64) * (sorted[upper] - sorted[lower])
+}
+
+/// Calculates the sample standard deviation (n - 1 denominator) of the values.
+pub(crate) fn std_dev(values: &[f64]) -> f64 {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    (values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+}
+
+/// Builds a percentile interval from the sorted bootstrap replicates.
+pub(crate) fn percentile_interval(sorted: &[f64], alpha: f64) -> (f64, f64) {
+    (
+        quantile(sorted, alpha / 2.0),
+        quantile(sorted, 1.0 - alpha / 2.0),
+    )
+}
+
+/// Builds a basic (reverse percentile) interval from the sorted bootstrap replicates.
+pub(crate) fn basic_interval(estimate: f64, sorted: &[f64], alpha: f64) -> (f64, f64) {
+    (
+        2.0 * estimate - quantile(sorted, 1.0 - alpha / 2.0),
+        2.0 * estimate - quantile(sorted, alpha / 2.0),
+    )
+}
+
+/// Builds a studentized (bootstrap-t) interval from the sorted bootstrap t-statistics.
+pub(crate) fn studentized_interval(
+    estimate: f64,
+    std_error: f64,
+    sorted_t: &[f64],
+    alpha: f64,
+) -> (f64, f64) {
+    (
+        estimate - quantile(sorted_t, 1.0 - alpha / 2.0) * std_error,
+        estimate - quantile(sorted_t, alpha / 2.0) * std_error,
+    )
+}
+
+/// Builds a bias-corrected and accelerated (BCa) interval.
+///
+/// The bias correction is taken from the share of replicates below the estimate and the
+/// acceleration from the skewness of the jackknife values.
+///
+/// # Errors
+///
+/// Returns a `StatError` if every replicate lies on one side of the estimate, in which case
+/// the bias correction is undefined.
+pub(crate) fn bca_interval(
+    estimate: f64,
+    sorted: &[f64],
+    jackknife: &[f64],
+    alpha: f64,
+) -> Result<(f64, f64), StatError> {
+    let z_dist = Normal::new(0.0, 1.0).map_err(|e| {
+        StatError::ComputeError(format!("Failed to create Normal distribution: {e}"))
+    })?;
+
+    let below = sorted.iter().filter(|&&x| x < estimate).count() as f64;
+    let equal = sorted.iter().filter(|&&x| x == estimate).count() as f64;
+    let share = (below + 0.5 * equal) / sorted.len() as f64;
+    if share <= 0.0 || share >= 1.0 {
+        return Err(StatError::ComputeError(
+            "BCa bias correction is undefined when all replicates lie on one side of the estimate"
+                .to_string(),
+        ));
+    }
+    let z0 = z_dist.inverse_cdf(share);
+
+    let jack_mean = jackknife.iter().sum::<f64>() / jackknife.len() as f64;
+    let num = jackknife
+        .iter()
+        .map(|x| (jack_mean - x).powi(3))
+        .sum::<f64>();
+    let den = 6.0
+        * jackknife
+            .iter()
+            .map(|x| (jack_mean - x).powi(2))
+            .sum::<f64>()
+            .powf(1.5);
+    let acceleration = if den == 0.0 { 0.0 } else { num / den };
+
+    let adjust = |prob: f64| {
+        let z = z0 + z_dist.inverse_cdf(prob);
+        z_dist.cdf(z0 + z / (1.0 - acceleration * z))
+    };
+
+    Ok((
+        quantile(sorted, adjust(alpha / 2.0)),
+        quantile(sorted, adjust(1.0 - alpha / 2.0)),
+    ))
+}
@@ -0,0 +1,46 @@
+//! # Bootstrap Confidence Intervals
+//!
+//! The `bootstrap` module provides resampling-based confidence intervals for arbitrary,
+//! user-supplied statistics.
+//!
+//! Many statistics of interest (medians, percentiles, ratios) have no analytic confidence
+//! interval, and tests such as `mann_whitney::u_test` and `anova::anova` report `(NaN, NaN)`.
+//! The bootstrap approximates the sampling distribution of the statistic by recomputing it
+//! on resamples of the data.
+//!
+//! ## Interval Methods
+//!
+//! - `Percentile`: Quantiles of the bootstrap replicates.
+//! - `Basic`: Replicate quantiles reflected around the estimate.
+//! - `Studentized`: Bootstrap-t interval with nested standard errors.
+//! - `BCa`: Bias-corrected and accelerated interval.
+//!
+//! All functions take a `seed`, so repeated calls with the same inputs return identical results.
+//!
+//! ## Submodules
+//!
+//! - `interval`: Contains the interval calculations applied to the bootstrap replicates.
+//! - `resample`: Contains the ordinary, stratified and block bootstrap functions.
+//! - `types`: Defines the `BootstrapMethod` and `BootstrapResult` types.
+//!
+//! ## Exports
+//!
+//! The following functions and types are made available for use:
+//!
+//! - `bootstrap`: Bootstraps a statistic of a single sample.
+//! - `bootstrap_stratified`: Bootstraps a statistic of several independently resampled groups.
+//! - `bootstrap_block`: Bootstraps a statistic of a dependent series with the moving block bootstrap.
+//! - `BootstrapMethod`: The interval method to use.
+//! - `BootstrapResult`: The estimate, standard error, bias and confidence interval.
+//!
+//! ## Example
+//! ```rust
+//! use hypors::bootstrap::{bootstrap, bootstrap_block, bootstrap_stratified, BootstrapMethod, BootstrapResult};
+//! ```
+
+pub(crate) mod interval;
+pub mod resample;
+pub mod types;
+
+pub use resample::{bootstrap, bootstrap_block, bootstrap_stratified};
+pub use types::{BootstrapMethod, BootstrapResult};
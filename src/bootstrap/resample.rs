@@ -0,0 +1,401 @@
+use crate::bootstrap::interval::{
+    basic_interval, bca_interval, percentile_interval, std_dev, studentized_interval,
+};
+use crate::bootstrap::{BootstrapMethod, BootstrapResult};
+use crate::common::StatError;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The resampling scheme applied within each stratum.
+#[derive(Debug, Clone, Copy)]
+enum Scheme {
+    /// Independent draws of single observations.
+    Iid,
+    /// Moving blocks of consecutive observations of the given length.
+    Block(usize),
+}
+
+/// Performs a nonparametric bootstrap of a statistic computed on a single sample.
+///
+/// Observations are resampled with replacement and the statistic is recomputed on every
+/// resample. The spread of the replicates is used to build a confidence interval with the
+/// requested method, which makes this suitable for statistics without an analytic interval
+/// (medians, percentiles, ratios, ...).
+///
+/// # Arguments
+///
+/// * `data` - An iterator containing the sample data (any type that can be converted to f64).
+/// * `statistic` - The statistic to evaluate on the original data and on every resample.
+/// * `method` - The method used to build the confidence interval.
+/// * `n_resamples` - The number of bootstrap replicates to draw (e.g., 2000).
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `seed` - The seed of the random number generator, making results reproducible.
+///
+/// # Returns
+///
+/// A `BootstrapResult` struct containing the estimate, bootstrap standard error, bias and
+/// confidence interval.
+///
+/// # Errors
+///
+/// Returns a `StatError` if:
+/// - The data is empty (`EmptyData`)
+/// - Fewer than two resamples are requested or alpha is not between 0 and 1 (`ComputeError`)
+/// - The statistic is not finite on the original data (`ComputeError`)
+/// - The interval cannot be computed for the chosen method (`ComputeError`)
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::bootstrap::{bootstrap, BootstrapMethod};
+///
+/// let data = vec![2.1, 3.4, 1.9, 5.6, 4.2, 3.3, 2.8, 4.9, 3.7, 2.5];
+/// let median = |x: &[f64]| {
+///     let mut v = x.to_vec();
+///     v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+///     let m = v.len() / 2;
+///     if v.len() % 2 == 0 { (v[m - 1] + v[m]) / 2.0 } else { v[m] }
+/// };
+///
+/// let result = bootstrap(data, median, BootstrapMethod::Percentile, 2000, 0.05, 42).unwrap();
+///
+/// assert!(result.confidence_interval.0 <= result.estimate);
+/// assert!(result.confidence_interval.1 >= result.estimate);
+/// ```
+pub fn bootstrap<I, T, F>(
+    data: I,
+    statistic: F,
+    method: BootstrapMethod,
+    n_resamples: usize,
+    alpha: f64,
+    seed: u64,
+) -> Result<BootstrapResult, StatError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<f64>,
+    F: Fn(&[f64]) -> f64,
+{
+    let sample: Vec<f64> = data.into_iter().map(|x| x.into()).collect();
+
+    if sample.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+
+    run(
+        vec![sample],
+        Scheme::Iid,
+        |strata: &[Vec<f64>]| statistic(&strata[0]),
+        method,
+        n_resamples,
+        alpha,
+        seed,
+    )
+}
+
+/// Performs a stratified bootstrap of a statistic computed on several groups.
+///
+/// Each stratum is resampled independently with replacement, so the group sizes are preserved
+/// in every replicate. The statistic receives the resampled strata in their original order,
+/// which allows multi-sample statistics such as a difference in medians between two groups.
+///
+/// # Arguments
+///
+/// * `strata` - A slice of groups, each holding the observations of one stratum.
+/// * `statistic` - The statistic to evaluate on the strata.
+/// * `method` - The method used to build the confidence interval.
+/// * `n_resamples` - The number of bootstrap replicates to draw.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `seed` - The seed of the random number generator, making results reproducible.
+///
+/// # Returns
+///
+/// A `BootstrapResult` struct containing the estimate, bootstrap standard error, bias and
+/// confidence interval.
+///
+/// # Errors
+///
+/// Returns a `StatError` if no strata are given or any stratum is empty (`EmptyData`), or if the
+/// interval cannot be computed (`ComputeError`).
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::bootstrap::{bootstrap_stratified, BootstrapMethod};
+///
+/// let control = vec![12.0, 15.0, 14.0, 16.0, 13.0, 18.0];
+/// let treatment = vec![20.0, 22.0, 19.0, 24.0, 21.0];
+/// let mean = |x: &[f64]| x.iter().sum::<f64>() / x.len() as f64;
+///
+/// let result = bootstrap_stratified(
+///     &[control, treatment],
+///     |s: &[Vec<f64>]| mean(&s[1]) - mean(&s[0]),
+///     BootstrapMethod::BCa,
+///     2000,
+///     0.05,
+///     7,
+/// )
+/// .unwrap();
+///
+/// assert!(result.confidence_interval.0 > 0.0);
+/// ```
+pub fn bootstrap_stratified<S, T, F>(
+    strata: &[S],
+    statistic: F,
+    method: BootstrapMethod,
+    n_resamples: usize,
+    alpha: f64,
+    seed: u64,
+) -> Result<BootstrapResult, StatError>
+where
+    S: AsRef<[T]>,
+    T: Into<f64> + Copy,
+    F: Fn(&[Vec<f64>]) -> f64,
+{
+    let groups: Vec<Vec<f64>> = strata
+        .iter()
+        .map(|s| s.as_ref().iter().copied().map(Into::into).collect())
+        .collect();
+
+    if groups.is_empty() || groups.iter().any(|g: &Vec<f64>| g.is_empty()) {
+        return Err(StatError::EmptyData);
+    }
+
+    run(
+        groups,
+        Scheme::Iid,
+        statistic,
+        method,
+        n_resamples,
+        alpha,
+        seed,
+    )
+}
+
+/// Performs a moving block bootstrap of a statistic computed on a dependent series.
+///
+/// Overlapping blocks of `block_len` consecutive observations are drawn with replacement and
+/// concatenated until the original length is reached, which preserves short-range dependence
+/// (e.g. daily metrics with autocorrelation). For BCa intervals the acceleration is estimated by
+/// deleting one non-overlapping block at a time.
+///
+/// # Arguments
+///
+/// * `data` - An iterator containing the ordered series.
+/// * `block_len` - The length of each block (between 1 and the series length).
+/// * `statistic` - The statistic to evaluate on the original series and on every resample.
+/// * `method` - The method used to build the confidence interval.
+/// * `n_resamples` - The number of bootstrap replicates to draw.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `seed` - The seed of the random number generator, making results reproducible.
+///
+/// # Returns
+///
+/// A `BootstrapResult` struct containing the estimate, bootstrap standard error, bias and
+/// confidence interval.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the data is empty (`EmptyData`), the block length is invalid or the
+/// interval cannot be computed (`ComputeError`).
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::bootstrap::{bootstrap_block, BootstrapMethod};
+///
+/// let series = vec![1.0, 1.2, 1.1, 1.5, 1.7, 1.6, 1.4, 1.3, 1.8, 2.0, 1.9, 1.7];
+/// let mean = |x: &[f64]| x.iter().sum::<f64>() / x.len() as f64;
+///
+/// let result = bootstrap_block(series, 3, mean, BootstrapMethod::Basic, 1000, 0.05, 1).unwrap();
+/// assert!(result.std_error > 0.0);
+/// ```
+pub fn bootstrap_block<I, T, F>(
+    data: I,
+    block_len: usize,
+    statistic: F,
+    method: BootstrapMethod,
+    n_resamples: usize,
+    alpha: f64,
+    seed: u64,
+) -> Result<BootstrapResult, StatError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<f64>,
+    F: Fn(&[f64]) -> f64,
+{
+    let sample: Vec<f64> = data.into_iter().map(|x| x.into()).collect();
+
+    if sample.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+
+    if block_len == 0 || block_len > sample.len() {
+        return Err(StatError::ComputeError(format!(
+            "Block length must be between 1 and {}, got: {block_len}",
+            sample.len()
+        )));
+    }
+
+    run(
+        vec![sample],
+        Scheme::Block(block_len),
+        |strata: &[Vec<f64>]| statistic(&strata[0]),
+        method,
+        n_resamples,
+        alpha,
+        seed,
+    )
+}
+
+/// Draws one bootstrap resample of every stratum.
+fn draw(strata: &[Vec<f64>], scheme: Scheme, rng: &mut StdRng) -> Vec<Vec<f64>> {
+    strata
+        .iter()
+        .map(|s| {
+            let n = s.len();
+            match scheme {
+                Scheme::Iid => (0..n).map(|_| s[rng.gen_range(0..n)]).collect(),
+                Scheme::Block(len) => {
+                    let mut out = Vec::with_capacity(n + len);
+                    while out.len() < n {
+                        let start = rng.gen_range(0..=n - len);
+                        out.extend_from_slice(&s[start..start + len]);
+                    }
+                    out.truncate(n);
+                    out
+                }
+            }
+        })
+        .collect()
+}
+
+/// Calculates the jackknife values of the statistic, deleting one unit (an observation, or a
+/// non-overlapping block) from one stratum at a time.
+fn jackknife<F>(strata: &[Vec<f64>], scheme: Scheme, statistic: &F) -> Vec<f64>
+where
+    F: Fn(&[Vec<f64>]) -> f64,
+{
+    let unit = match scheme {
+        Scheme::Iid => 1,
+        Scheme::Block(len) => len,
+    };
+
+    let mut values = Vec::new();
+    for (i, stratum) in strata.iter().enumerate() {
+        // Deleting the only unit of a stratum would leave it empty
+        if stratum.len() <= unit {
+            continue;
+        }
+        for start in (0..stratum.len()).step_by(unit) {
+            let end = (start + unit).min(stratum.len());
+            let mut reduced = strata.to_vec();
+            reduced[i] = stratum[..start]
+                .iter()
+                .chain(&stratum[end..])
+                .copied()
+                .collect();
+            values.push(statistic(&reduced));
+        }
+    }
+    values.retain(|x| x.is_finite());
+    values
+}
+
+/// Runs the bootstrap for any scheme and builds the requested interval.
+fn run<F>(
+    strata: Vec<Vec<f64>>,
+    scheme: Scheme,
+    statistic: F,
+    method: BootstrapMethod,
+    n_resamples: usize,
+    alpha: f64,
+    seed: u64,
+) -> Result<BootstrapResult, StatError>
+where
+    F: Fn(&[Vec<f64>]) -> f64,
+{
+    if n_resamples < 2 {
+        return Err(StatError::ComputeError(format!(
+            "At least two resamples are required, got: {n_resamples}"
+        )));
+    }
+    if !(0.0 < alpha && alpha < 1.0) {
+        return Err(StatError::ComputeError(format!(
+            "Alpha must be between 0 and 1, got: {alpha}"
+        )));
+    }
+
+    let estimate = statistic(&strata);
+    if !estimate.is_finite() {
+        return Err(StatError::ComputeError(
+            "Statistic is not finite on the original data".to_string(),
+        ));
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut replicates = Vec::with_capacity(n_resamples);
+    let mut t_values = Vec::new();
+
+    for _ in 0..n_resamples {
+        let resample = draw(&strata, scheme, &mut rng);
+        let value = statistic(&resample);
+        if !value.is_finite() {
+            continue;
+        }
+
+        if let BootstrapMethod::Studentized { inner_resamples } = method {
+            let inner: Vec<f64> = (0..inner_resamples)
+                .map(|_| statistic(&draw(&resample, scheme, &mut rng)))
+                .filter(|x| x.is_finite())
+                .collect();
+            if inner.len() >= 2 {
+                let inner_se = std_dev(&inner);
+                if inner_se > 0.0 {
+                    t_values.push((value - estimate) / inner_se);
+                }
+            }
+        }
+
+        replicates.push(value);
+    }
+
+    if replicates.len() < 2 {
+        return Err(StatError::ComputeError(
+            "Statistic was not finite on enough bootstrap resamples".to_string(),
+        ));
+    }
+
+    let std_error = std_dev(&replicates);
+    let bias = replicates.iter().sum::<f64>() / replicates.len() as f64 - estimate;
+
+    replicates.sort_by(|a, b| a.total_cmp(b));
+
+    let confidence_interval = match method {
+        BootstrapMethod::Percentile => percentile_interval(&replicates, alpha),
+        BootstrapMethod::Basic => basic_interval(estimate, &replicates, alpha),
+        BootstrapMethod::Studentized { .. } => {
+            if t_values.len() < 2 {
+                return Err(StatError::ComputeError(
+                    "Studentized interval requires positive inner standard errors".to_string(),
+                ));
+            }
+            t_values.sort_by(|a, b| a.total_cmp(b));
+            studentized_interval(estimate, std_error, &t_values, alpha)
+        }
+        BootstrapMethod::BCa => {
+            let jack = jackknife(&strata, scheme, &statistic);
+            if jack.len() < 2 {
+                return Err(StatError::InsufficientData);
+            }
+            bca_interval(estimate, &replicates, &jack, alpha)?
+        }
+    };
+
+    Ok(BootstrapResult {
+        estimate,
+        std_error,
+        bias,
+        confidence_interval,
+        n_resamples: replicates.len(),
+    })
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the method used to build a bootstrap confidence interval.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BootstrapMethod {
+    /// Percentile interval, taken directly from the quantiles of the bootstrap replicates.
+    Percentile,
+    /// Basic (reverse percentile) interval, reflecting the replicate quantiles around the estimate.
+    Basic,
+    /// Studentized (bootstrap-t) interval. The standard error of every replicate is estimated
+    /// with a nested bootstrap of `inner_resamples` draws.
+    Studentized { inner_resamples: usize },
+    /// Bias-corrected and accelerated interval, using a jackknife estimate of the acceleration.
+    BCa,
+}
+
+/// Stores the result of a bootstrap analysis of a statistic.
+///
+/// # Fields
+///
+/// * `estimate` - The statistic evaluated on the original data.
+/// * `std_error` - The standard deviation of the bootstrap replicates.
+/// * `bias` - The mean of the bootstrap replicates minus the original estimate.
+/// * `confidence_interval` - The confidence interval for the statistic (lower, upper bounds).
+/// * `n_resamples` - The number of bootstrap replicates drawn.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::bootstrap::BootstrapResult;
+///
+/// let result = BootstrapResult {
+///     estimate: 2.0,
+///     std_error: 0.4,
+///     bias: 0.01,
+///     confidence_interval: (1.2, 2.8),
+///     n_resamples: 1000,
+/// };
+///
+/// assert!(result.confidence_interval.0 < result.estimate);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BootstrapResult {
+    pub estimate: f64,
+    pub std_error: f64,
+    pub bias: f64,
+    pub confidence_interval: (f64, f64),
+    pub n_resamples: usize,
+}
@@ -1,5 +1,8 @@
+use super::types::{AssociationMeasure, IndependenceResult, IndependenceStatistic};
 use crate::common::{StatError, TailType, TestResult, calculate_p};
-use statrs::distribution::ChiSquared;
+use crate::noncentral::NoncentralChiSquared;
+use crate::noncentral::mixture::invert_cdf;
+use statrs::distribution::{ChiSquared, ContinuousCDF};
 use std::f64;
 
 /// Perform a Chi-Square Test for Independence using a contingency table.
@@ -45,6 +48,26 @@ use std::f64;
 /// println!("Reject null: {}", result.reject_null);
 /// ```
 pub fn independence(contingency_table: &[Vec<f64>], alpha: f64) -> Result<TestResult, StatError> {
+    fit_independence(contingency_table, IndependenceStatistic::Pearson, alpha).map(|fit| fit.result)
+}
+
+/// The margins, expected counts and test of a contingency table under independence.
+struct IndependenceFit {
+    row_totals: Vec<f64>,
+    col_totals: Vec<f64>,
+    total: f64,
+    expected: Vec<Vec<f64>>,
+    df: usize,
+    pearson: f64,
+    result: TestResult,
+}
+
+/// Validates a contingency table and tests it for independence with the given statistic.
+fn fit_independence(
+    contingency_table: &[Vec<f64>],
+    statistic: IndependenceStatistic,
+    alpha: f64,
+) -> Result<IndependenceFit, StatError> {
     let num_rows = contingency_table.len();
     if num_rows < 2 {
         return Err(StatError::ComputeError("At least two rows required".into()));
@@ -56,6 +79,16 @@ pub fn independence(contingency_table: &[Vec<f64>], alpha: f64) -> Result<TestRe
             "All rows must have equal and ≥2 columns".into(),
         ));
     }
+    if contingency_table.iter().flatten().any(|&x| x < 0.0) {
+        return Err(StatError::ComputeError(
+            "Frequencies must be non-negative".into(),
+        ));
+    }
+    if statistic == IndependenceStatistic::Yates && (num_rows, num_cols) != (2, 2) {
+        return Err(StatError::ComputeError(
+            "Yates' correction requires a 2x2 table".into(),
+        ));
+    }
 
     let total: f64 = contingency_table.iter().flatten().sum();
     if total == 0.0 {
@@ -64,7 +97,6 @@ pub fn independence(contingency_table: &[Vec<f64>], alpha: f64) -> Result<TestRe
         ));
     }
 
-    let mut expected = vec![vec![0.0; num_cols]; num_rows];
     let row_totals: Vec<f64> = contingency_table
         .iter()
         .map(|row| row.iter().sum())
@@ -72,44 +104,230 @@ pub fn independence(contingency_table: &[Vec<f64>], alpha: f64) -> Result<TestRe
     let col_totals: Vec<f64> = (0..num_cols)
         .map(|j| contingency_table.iter().map(|row| row[j]).sum())
         .collect();
-
-    for i in 0..num_rows {
-        for j in 0..num_cols {
-            expected[i][j] = row_totals[i] * col_totals[j] / total;
-        }
-    }
-
-    let test_statistic = contingency_table
+    let expected: Vec<Vec<f64>> = row_totals
         .iter()
-        .enumerate()
-        .map(|(i, row)| {
-            row.iter()
-                .enumerate()
-                .map(|(j, &obs)| {
-                    let exp = expected[i][j];
-                    if exp == 0.0 {
-                        0.0
-                    } else {
-                        (obs - exp).powi(2) / exp
-                    }
-                })
-                .sum::<f64>()
+        .map(|&r| col_totals.iter().map(|&c| r * c / total).collect())
+        .collect();
+
+    // Sums a function of (observed, expected) over the cells with a positive expected count
+    let cell_sum = |f: &dyn Fn(f64, f64) -> f64| -> f64 {
+        contingency_table
+            .iter()
+            .flatten()
+            .zip(expected.iter().flatten())
+            .filter(|&(_, &exp)| exp > 0.0)
+            .map(|(&obs, &exp)| f(obs, exp))
+            .sum()
+    };
+    let pearson = cell_sum(&|obs, exp| (obs - exp).powi(2) / exp);
+    let g_statistic = || {
+        2.0 * cell_sum(&|obs, exp| {
+            if obs > 0.0 {
+                obs * (obs / exp).ln()
+            } else {
+                0.0
+            }
         })
-        .sum::<f64>();
+    };
 
     let df = (num_rows - 1) * (num_cols - 1);
+    let test_statistic = match statistic {
+        IndependenceStatistic::Pearson => pearson,
+        IndependenceStatistic::Yates => cell_sum(&|obs, exp| {
+            let deviation = (obs - exp).abs();
+            (deviation - deviation.min(0.5)).powi(2) / exp
+        }),
+        IndependenceStatistic::LikelihoodRatio => g_statistic(),
+        IndependenceStatistic::Williams => {
+            let inverse_sum = |totals: &[f64]| -> f64 { totals.iter().map(|&t| 1.0 / t).sum() };
+            let q = 1.0
+                + (total * inverse_sum(&row_totals) - 1.0)
+                    * (total * inverse_sum(&col_totals) - 1.0)
+                    / (6.0 * total * df as f64);
+            g_statistic() / q
+        }
+    };
+
     let chi_distribution = ChiSquared::new(df as f64)
         .map_err(|e| StatError::ComputeError(format!("Chi-squared distribution error: {e}")))?;
     let p_value = calculate_p(test_statistic, TailType::Right, &chi_distribution);
     let reject_null = p_value < alpha;
 
-    Ok(TestResult {
-        test_statistic,
-        p_value,
-        confidence_interval: (f64::NAN, f64::NAN),
-        null_hypothesis: "H0: Variables are independent".into(),
-        alt_hypothesis: "Ha: Variables are not independent".into(),
-        reject_null,
+    Ok(IndependenceFit {
+        row_totals,
+        col_totals,
+        total,
+        expected,
+        df,
+        pearson,
+        result: TestResult {
+            test_statistic,
+            p_value,
+            confidence_interval: (f64::NAN, f64::NAN),
+            null_hypothesis: "H0: Variables are independent".into(),
+            alt_hypothesis: "Ha: Variables are not independent".into(),
+            reject_null,
+        },
+    })
+}
+
+/// Perform a Chi-Square Test for Independence with a choice of statistic, reporting cell
+/// residuals, measures of association and warnings about small expected counts.
+///
+/// All statistics are compared with a chi-square distribution with `(r - 1)(c - 1)` degrees of
+/// freedom. The approximation is doubtful when expected counts are small, so a warning is
+/// added whenever any expected count is below 5.
+///
+/// Cramér's V, phi and the contingency coefficient are functions of Pearson's `X²`. Their
+/// confidence intervals invert the noncentral chi-square distribution of `X²` to obtain an
+/// interval for the noncentrality `λ`, and transform the bounds of `E[X²] = λ + df`. A lower
+/// bound of zero for `λ` gives a lower bound of zero, and each interval contains its estimate.
+///
+/// # Arguments
+///
+/// * `contingency_table` - A slice of row vectors (`Vec<Vec<f64>>`) representing the observed frequencies.
+/// * `statistic` - The statistic to test: Pearson, Yates (2x2 only), likelihood-ratio or Williams.
+/// * `alpha` - The significance level for the test and the confidence intervals (commonly 0.05).
+///
+/// # Returns
+///
+/// An `IndependenceResult` with the expected counts, residuals, measures of association,
+/// warnings and the `TestResult`.
+///
+/// # Errors
+/// Returns `StatError` if:
+/// - Input rows are unequal or contain fewer than 2 rows/columns.
+/// - Frequencies are negative or the total is zero.
+/// - Yates' correction is requested for a table that is not 2x2.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::chi_square::{independence_test, IndependenceStatistic};
+///
+/// let table = vec![
+///     vec![20.0, 30.0],
+///     vec![50.0, 10.0],
+/// ];
+///
+/// let result = independence_test(&table, IndependenceStatistic::Yates, 0.05).unwrap();
+/// assert!((result.result.test_statistic - 20.2979).abs() < 1e-4);
+/// assert!(result.phi.unwrap().estimate < 0.0);
+/// assert!(result.warnings.is_empty());
+/// ```
+pub fn independence_test(
+    contingency_table: &[Vec<f64>],
+    statistic: IndependenceStatistic,
+    alpha: f64,
+) -> Result<IndependenceResult, StatError> {
+    let IndependenceFit {
+        row_totals,
+        col_totals,
+        total,
+        expected,
+        df,
+        pearson,
+        result,
+    } = fit_independence(contingency_table, statistic, alpha)?;
+    let num_rows = row_totals.len();
+    let num_cols = col_totals.len();
+
+    let residuals = |adjusted: bool| -> Vec<Vec<f64>> {
+        contingency_table
+            .iter()
+            .zip(&expected)
+            .zip(&row_totals)
+            .map(|((row, exp_row), &r)| {
+                row.iter()
+                    .zip(exp_row)
+                    .zip(&col_totals)
+                    .map(|((&obs, &exp), &c)| {
+                        let variance = if adjusted {
+                            exp * (1.0 - r / total) * (1.0 - c / total)
+                        } else {
+                            exp
+                        };
+                        if variance > 0.0 {
+                            (obs - exp) / variance.sqrt()
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    };
+
+    // Interval for the noncentrality of X², bounded below by zero
+    let noncentrality = |p: f64| -> f64 {
+        let tail = |lambda: f64| {
+            NoncentralChiSquared::new(df as f64, lambda).map_or(f64::NAN, |dist| dist.sf(pearson))
+        };
+        if tail(0.0) >= p {
+            0.0
+        } else {
+            invert_cdf(tail, p, 0.0, pearson + df as f64)
+        }
+    };
+    let lambda_bounds = (noncentrality(alpha / 2.0), noncentrality(1.0 - alpha / 2.0));
+    let measure = |f: &dyn Fn(f64) -> f64| {
+        let estimate = f(pearson);
+        let low = if lambda_bounds.0 > 0.0 {
+            f(lambda_bounds.0 + df as f64)
+        } else {
+            0.0
+        };
+        let high = f(lambda_bounds.1 + df as f64);
+        AssociationMeasure {
+            estimate,
+            confidence_interval: (low.min(estimate), high.max(estimate)),
+        }
+    };
+
+    let min_dim = num_rows.min(num_cols) as f64;
+    let cramers_v = measure(&|x| (x / (total * (min_dim - 1.0))).sqrt());
+    let contingency_coefficient = measure(&|x| (x / (x + total)).sqrt());
+    let phi = ((num_rows, num_cols) == (2, 2)).then(|| {
+        let t = contingency_table;
+        let sign = if t[0][0] * t[1][1] >= t[0][1] * t[1][0] {
+            1.0
+        } else {
+            -1.0
+        };
+        let AssociationMeasure {
+            estimate,
+            confidence_interval: (low, high),
+        } = measure(&|x| (x / total).sqrt());
+        let product: f64 = row_totals.iter().chain(&col_totals).product();
+        AssociationMeasure {
+            estimate: if product > 0.0 { sign * estimate } else { 0.0 },
+            confidence_interval: if sign > 0.0 {
+                (low, high)
+            } else {
+                (-high, -low)
+            },
+        }
+    });
+
+    let mut warnings = Vec::new();
+    let cells = num_rows * num_cols;
+    let low_expected = expected.iter().flatten().filter(|&&exp| exp < 5.0).count();
+    if low_expected > 0 {
+        warnings.push(format!(
+            "{low_expected} of {cells} expected counts ({:.1}%) are below 5; the chi-square approximation may be inaccurate",
+            100.0 * low_expected as f64 / cells as f64
+        ));
+    }
+
+    Ok(IndependenceResult {
+        pearson_residuals: residuals(false),
+        adjusted_residuals: residuals(true),
+        expected,
+        cramers_v,
+        phi,
+        contingency_coefficient,
+        warnings,
+        result,
     })
 }
 
@@ -158,6 +376,57 @@ pub fn goodness_of_fit<O, E, T, U>(
     expected: E,
     alpha: f64,
 ) -> Result<TestResult, StatError>
+where
+    O: IntoIterator<Item = T>,
+    E: IntoIterator<Item = U>,
+    T: Into<f64>,
+    U: Into<f64>,
+{
+    goodness_of_fit_ddof(observed, expected, 0, alpha)
+}
+
+/// Perform a Chi-Square Goodness of Fit Test with a degrees-of-freedom adjustment.
+///
+/// When the expected frequencies come from a distribution whose parameters were estimated from
+/// the same data, each estimated parameter removes a degree of freedom: the statistic is
+/// compared with a chi-square distribution with `k - 1 - ddof` degrees of freedom for `k`
+/// categories.
+///
+/// # Arguments
+///
+/// * `observed` - An iterator of observed frequencies.
+/// * `expected` - An iterator of expected frequencies (must be same length as `observed`).
+/// * `ddof` - The number of parameters estimated from the data.
+/// * `alpha` - Significance level (commonly 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` with the chi-square statistic and p-value; the confidence interval is not
+/// applicable and returns `(NaN, NaN)`.
+///
+/// # Errors
+/// Returns `StatError` if:
+/// - Inputs have different lengths or contain fewer than two categories.
+/// - `ddof` leaves no degrees of freedom.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::chi_square::goodness_of_fit_ddof;
+///
+/// let observed = vec![30.0, 10.0, 20.0, 15.0];
+/// let expected = vec![25.0, 15.0, 20.0, 15.0];
+///
+/// // One parameter was estimated, leaving 4 - 1 - 1 = 2 degrees of freedom
+/// let result = goodness_of_fit_ddof(observed, expected, 1, 0.05).unwrap();
+/// assert!((result.p_value - (-result.test_statistic / 2.0).exp()).abs() < 1e-9);
+/// ```
+pub fn goodness_of_fit_ddof<O, E, T, U>(
+    observed: O,
+    expected: E,
+    ddof: usize,
+    alpha: f64,
+) -> Result<TestResult, StatError>
 where
     O: IntoIterator<Item = T>,
     E: IntoIterator<Item = U>,
@@ -177,6 +446,12 @@ where
             "At least two categories required".into(),
         ));
     }
+    if observed.len() <= 1 + ddof {
+        return Err(StatError::ComputeError(format!(
+            "{} categories leave no degrees of freedom with ddof = {ddof}",
+            observed.len()
+        )));
+    }
 
     let test_statistic: f64 = observed
         .iter()
@@ -190,7 +465,7 @@ where
         })
         .sum();
 
-    let df = (observed.len() - 1) as f64;
+    let df = (observed.len() - 1 - ddof) as f64;
     let chi_distribution = ChiSquared::new(df)
         .map_err(|e| StatError::ComputeError(format!("Chi-squared distribution error: {e}")))?;
     let p_value = calculate_p(test_statistic, TailType::Right, &chi_distribution);
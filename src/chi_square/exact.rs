@@ -0,0 +1,210 @@
+use crate::common::{StatError, TestResult};
+use statrs::function::gamma::ln_gamma;
+
+/// The largest number of outcomes the exact multinomial test will enumerate.
+const MAX_OUTCOMES: f64 = 2e7;
+
+/// Relative tolerance for outcomes as likely as the observed one.
+const TIE_TOLERANCE: f64 = 1e-7;
+
+/// Perform an exact multinomial Goodness of Fit Test.
+///
+/// The chi-square approximation is unreliable when some expected counts are small. This test
+/// instead enumerates every outcome with the observed total and sums the probabilities, under
+/// the hypothesized distribution, of the outcomes no more likely than the one observed. It is
+/// practical for small totals and few categories.
+///
+/// # Arguments
+///
+/// * `observed` - An iterator of observed frequencies, which must be non-negative whole numbers.
+/// * `expected` - An iterator of expected frequencies (must be same length as `observed`), which
+///   are rescaled to the observed total.
+/// * `alpha` - Significance level (commonly 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` whose test statistic is Pearson's chi-square statistic and whose p-value is
+/// exact; the confidence interval is not applicable and returns `(NaN, NaN)`.
+///
+/// # Errors
+/// Returns `StatError` if:
+/// - Inputs have different lengths or contain fewer than two categories.
+/// - Expected frequencies are not positive, or observed counts are not whole numbers.
+/// - The number of outcomes to enumerate is too large.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::chi_square::goodness_of_fit_exact;
+///
+/// let observed = vec![1.0, 1.0, 4.0];
+/// let expected = vec![2.0, 2.0, 2.0];
+///
+/// let result = goodness_of_fit_exact(observed, expected, 0.05).unwrap();
+/// assert!((result.p_value - 0.3827).abs() < 1e-4);
+/// ```
+pub fn goodness_of_fit_exact<O, E, T, U>(
+    observed: O,
+    expected: E,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    O: IntoIterator<Item = T>,
+    E: IntoIterator<Item = U>,
+    T: Into<f64>,
+    U: Into<f64>,
+{
+    let (observed, probs) = fit_inputs(observed, expected)?;
+    let test_statistic = pearson_statistic(&observed, &probs);
+    let p_value = multinomial_exact_p(&observed, &probs)?;
+
+    Ok(TestResult {
+        test_statistic,
+        p_value,
+        confidence_interval: (f64::NAN, f64::NAN),
+        null_hypothesis: "H0: Observed distribution matches expected distribution".into(),
+        alt_hypothesis: "Ha: Observed distribution does not match expected distribution".into(),
+        reject_null: p_value < alpha,
+    })
+}
+
+/// Validates goodness-of-fit inputs and converts the expected frequencies to probabilities.
+pub(crate) fn fit_inputs<O, E, T, U>(
+    observed: O,
+    expected: E,
+) -> Result<(Vec<f64>, Vec<f64>), StatError>
+where
+    O: IntoIterator<Item = T>,
+    E: IntoIterator<Item = U>,
+    T: Into<f64>,
+    U: Into<f64>,
+{
+    let observed: Vec<f64> = observed.into_iter().map(|x| x.into()).collect();
+    let expected: Vec<f64> = expected.into_iter().map(|x| x.into()).collect();
+
+    if observed.len() != expected.len() {
+        return Err(StatError::ComputeError(
+            "Observed and expected lengths must match".into(),
+        ));
+    }
+    if observed.len() < 2 {
+        return Err(StatError::ComputeError(
+            "At least two categories required".into(),
+        ));
+    }
+    if expected.iter().any(|&e| !(e > 0.0 && e.is_finite())) {
+        return Err(StatError::ComputeError(
+            "Expected frequencies must be positive".into(),
+        ));
+    }
+    if observed.iter().any(|&x| !(x >= 0.0 && x.is_finite())) {
+        return Err(StatError::ComputeError(
+            "Observed frequencies must be non-negative".into(),
+        ));
+    }
+    if observed.iter().sum::<f64>() == 0.0 {
+        return Err(StatError::ComputeError(
+            "Total frequency must be greater than zero".into(),
+        ));
+    }
+
+    let total: f64 = expected.iter().sum();
+    let probs = expected.iter().map(|e| e / total).collect();
+    Ok((observed, probs))
+}
+
+/// Calculates Pearson's chi-square statistic of counts against category probabilities.
+pub(crate) fn pearson_statistic(observed: &[f64], probs: &[f64]) -> f64 {
+    let n: f64 = observed.iter().sum();
+    observed
+        .iter()
+        .zip(probs)
+        .map(|(&obs, &p)| (obs - n * p).powi(2) / (n * p))
+        .sum()
+}
+
+/// Calculates the exact multinomial goodness-of-fit p-value: the total probability, under the
+/// hypothesized category probabilities, of every outcome with the same total that is no more
+/// likely than the observed one.
+///
+/// # Arguments
+///
+/// * `observed` - The observed counts, which must be non-negative whole numbers.
+/// * `probs` - The hypothesized category probabilities, which must be positive and sum to 1.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the counts are not whole numbers or the number of outcomes to
+/// enumerate is too large.
+pub(crate) fn multinomial_exact_p(observed: &[f64], probs: &[f64]) -> Result<f64, StatError> {
+    if observed.iter().any(|&x| x < 0.0 || x.fract() != 0.0) {
+        return Err(StatError::ComputeError(
+            "Exact test requires non-negative whole counts".into(),
+        ));
+    }
+
+    let n = observed.iter().sum::<f64>() as usize;
+    let k = observed.len();
+    let ln_outcomes = ln_gamma((n + k) as f64) - ln_gamma(k as f64) - ln_gamma((n + 1) as f64);
+    if ln_outcomes > MAX_OUTCOMES.ln() {
+        return Err(StatError::ComputeError(
+            "Too many outcomes for the exact test; use the chi-square approximation".into(),
+        ));
+    }
+
+    let ln_factorial: Vec<f64> = (0..=n).map(|x| ln_gamma(x as f64 + 1.0)).collect();
+    let ln_probs: Vec<f64> = probs.iter().map(|p| p.ln()).collect();
+    let log_pmf = |counts: &[f64]| -> f64 {
+        ln_factorial[n]
+            + counts
+                .iter()
+                .zip(&ln_probs)
+                .map(|(&x, lp)| x * lp - ln_factorial[x as usize])
+                .sum::<f64>()
+    };
+    let threshold = log_pmf(observed) + TIE_TOLERANCE;
+
+    let mut p_value = 0.0;
+    enumerate(
+        0,
+        n,
+        ln_factorial[n],
+        &ln_probs,
+        &ln_factorial,
+        threshold,
+        &mut p_value,
+    );
+    Ok(p_value.min(1.0))
+}
+
+/// Adds the probabilities of all completions of a partial outcome that are no more likely than
+/// the observed outcome, assigning `remaining` counts to categories from `category` onwards.
+fn enumerate(
+    category: usize,
+    remaining: usize,
+    partial: f64,
+    ln_probs: &[f64],
+    ln_factorial: &[f64],
+    threshold: f64,
+    total: &mut f64,
+) {
+    if category == ln_probs.len() - 1 {
+        let log_p = partial + remaining as f64 * ln_probs[category] - ln_factorial[remaining];
+        if log_p <= threshold {
+            *total += log_p.exp();
+        }
+        return;
+    }
+    for x in 0..=remaining {
+        let next = partial + x as f64 * ln_probs[category] - ln_factorial[x];
+        enumerate(
+            category + 1,
+            remaining - x,
+            next,
+            ln_probs,
+            ln_factorial,
+            threshold,
+            total,
+        );
+    }
+}
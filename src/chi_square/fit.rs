@@ -0,0 +1,314 @@
+use super::categorical::goodness_of_fit_ddof;
+use super::types::{FitResult, FittedDistribution};
+use crate::common::StatError;
+use statrs::distribution::{Binomial, ContinuousCDF, DiscreteCDF, Normal, Poisson};
+use std::ops::Range;
+
+/// Fitted probability below which a discrete bin, or the upper tail after it, is not kept apart.
+const NEGLIGIBLE: f64 = 1e-12;
+
+/// The parameters, bins, observed counts and expected counts of a fitted distribution.
+type DiscreteBins = (Vec<f64>, Vec<(f64, f64)>, Vec<f64>, Vec<f64>);
+
+/// Perform a Chi-Square Goodness of Fit Test against a distribution fitted to the same data.
+///
+/// The parameters of the distribution are estimated by maximum likelihood, the data are binned
+/// (one bin per observed count for discrete distributions, with an open upper tail, or
+/// equiprobable bins for the normal distribution), and the expected count of each bin is
+/// computed under the fitted distribution. Adjacent bins are merged until every expected count
+/// reaches `min_expected`, and the test is run with one degree of freedom removed per
+/// estimated parameter. Discrete values beyond a negligible fitted upper tail share the open
+/// bin, so an outlier does not create a bin for every count up to it.
+///
+/// # Arguments
+///
+/// * `data` - An iterator of observations; discrete distributions require non-negative whole
+///   numbers.
+/// * `distribution` - The distribution to fit.
+/// * `min_expected` - The smallest expected count allowed in a bin (commonly 5).
+/// * `alpha` - Significance level (commonly 0.05).
+///
+/// # Returns
+///
+/// A `FitResult` with the estimated parameters, the merged bins with their observed and
+/// expected counts, and the `TestResult`.
+///
+/// # Errors
+/// Returns `StatError` if:
+/// - The data is empty (`EmptyData`).
+/// - Discrete data are not non-negative whole numbers, or binomial data exceed the trials.
+/// - The fitted distribution is degenerate (e.g. all observations are zero).
+/// - Too few bins remain after merging to leave any degrees of freedom.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::chi_square::{goodness_of_fit_fitted, FittedDistribution};
+///
+/// let counts = [(0, 20), (1, 30), (2, 25), (3, 15), (4, 7), (5, 3)];
+/// let data: Vec<f64> = counts
+///     .iter()
+///     .flat_map(|&(value, n)| std::iter::repeat(value as f64).take(n))
+///     .collect();
+///
+/// let fit = goodness_of_fit_fitted(data, FittedDistribution::Poisson, 5.0, 0.05).unwrap();
+/// assert!((fit.parameters[0] - 1.68).abs() < 1e-9);
+/// assert_eq!(fit.ddof, 1);
+/// assert!(!fit.result.reject_null);
+/// ```
+pub fn goodness_of_fit_fitted<I, T>(
+    data: I,
+    distribution: FittedDistribution,
+    min_expected: f64,
+    alpha: f64,
+) -> Result<FitResult, StatError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    let data: Vec<f64> = data.into_iter().map(|x| x.into()).collect();
+    if data.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    let n = data.len() as f64;
+    let mean = data.iter().sum::<f64>() / n;
+    let degenerate = || StatError::ComputeError("Fitted distribution is degenerate".into());
+
+    let (parameters, bins, observed, expected) = match &distribution {
+        FittedDistribution::Normal { bins } => {
+            if *bins < 2 {
+                return Err(StatError::ComputeError("At least two bins required".into()));
+            }
+            let std_dev = (data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt();
+            let normal = Normal::new(mean, std_dev).map_err(|_| degenerate())?;
+
+            let mut edges = vec![f64::NEG_INFINITY];
+            edges.extend((1..*bins).map(|i| normal.inverse_cdf(i as f64 / *bins as f64)));
+            edges.push(f64::INFINITY);
+
+            let mut observed = vec![0.0; *bins];
+            for &x in &data {
+                observed[edges.partition_point(|&e| e <= x) - 1] += 1.0;
+            }
+            let bounds: Vec<(f64, f64)> = edges.windows(2).map(|w| (w[0], w[1])).collect();
+            (
+                vec![mean, std_dev],
+                bounds,
+                observed,
+                vec![n / *bins as f64; *bins],
+            )
+        }
+        FittedDistribution::Poisson => {
+            check_whole_numbers(&data)?;
+            let poisson = Poisson::new(mean).map_err(|_| degenerate())?;
+            discrete_bins(
+                &data,
+                mean,
+                min_expected / n,
+                |k| poisson.cdf(k),
+                |k| poisson.sf(k),
+            )
+        }
+        FittedDistribution::Binomial { trials } => {
+            check_whole_numbers(&data)?;
+            if data.iter().any(|&x| x > *trials as f64) {
+                return Err(StatError::ComputeError(
+                    "Binomial counts cannot exceed the number of trials".into(),
+                ));
+            }
+            let p = mean / *trials as f64;
+            if !(p > 0.0 && p < 1.0) {
+                return Err(degenerate());
+            }
+            let binomial = Binomial::new(p, *trials).map_err(|_| degenerate())?;
+            discrete_bins(
+                &data,
+                p,
+                min_expected / n,
+                |k| binomial.cdf(k),
+                |k| binomial.sf(k),
+            )
+        }
+        FittedDistribution::Geometric => {
+            check_whole_numbers(&data)?;
+            if mean <= 0.0 {
+                return Err(degenerate());
+            }
+            let p = 1.0 / (1.0 + mean);
+            let sf = |k: u64| (1.0 - p).powf(k as f64 + 1.0);
+            discrete_bins(&data, p, min_expected / n, |k| 1.0 - sf(k), sf)
+        }
+    };
+
+    let groups = merge_groups(&expected, min_expected);
+    let merge = |values: &[f64]| -> Vec<f64> {
+        groups
+            .iter()
+            .map(|g| values[g.clone()].iter().sum())
+            .collect()
+    };
+    let bins: Vec<(f64, f64)> = groups
+        .iter()
+        .map(|g| (bins[g.start].0, bins[g.end - 1].1))
+        .collect();
+    let (observed, expected) = (merge(&observed), merge(&expected));
+
+    let ddof = parameters.len();
+    let result = goodness_of_fit_ddof(observed.clone(), expected.clone(), ddof, alpha)?;
+
+    Ok(FitResult {
+        distribution,
+        parameters,
+        bins,
+        observed,
+        expected,
+        ddof,
+        result,
+    })
+}
+
+/// Merge adjacent categories until every expected count reaches a minimum.
+///
+/// Categories are merged from left to right until the running expected count reaches
+/// `min_expected`; a sparse remainder at the end is merged into the last category. This keeps
+/// ordered categories (such as counts or intervals) contiguous.
+///
+/// # Arguments
+///
+/// * `observed` - The observed frequencies of ordered categories.
+/// * `expected` - The expected frequencies of the same categories.
+/// * `min_expected` - The smallest expected count allowed in a category (commonly 5).
+///
+/// # Returns
+///
+/// A tuple `(observed, expected)` of the merged frequencies.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the inputs have different lengths or are empty.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::chi_square::merge_sparse_bins;
+///
+/// let observed = vec![2.0, 9.0, 12.0, 4.0, 1.0];
+/// let expected = vec![3.0, 8.0, 10.0, 5.0, 2.0];
+///
+/// let (observed, expected) = merge_sparse_bins(&observed, &expected, 5.0).unwrap();
+/// assert_eq!(observed, vec![11.0, 12.0, 5.0]);
+/// assert_eq!(expected, vec![11.0, 10.0, 7.0]);
+/// ```
+pub fn merge_sparse_bins(
+    observed: &[f64],
+    expected: &[f64],
+    min_expected: f64,
+) -> Result<(Vec<f64>, Vec<f64>), StatError> {
+    if observed.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    if observed.len() != expected.len() {
+        return Err(StatError::ComputeError(
+            "Observed and expected lengths must match".into(),
+        ));
+    }
+    let groups = merge_groups(expected, min_expected);
+    let merge = |values: &[f64]| -> Vec<f64> {
+        groups
+            .iter()
+            .map(|g| values[g.clone()].iter().sum())
+            .collect()
+    };
+    Ok((merge(observed), merge(expected)))
+}
+
+/// Groups adjacent categories so that each group's expected count reaches `min_expected`.
+fn merge_groups(expected: &[f64], min_expected: f64) -> Vec<Range<usize>> {
+    let mut groups: Vec<Range<usize>> = Vec::new();
+    let (mut start, mut sum) = (0, 0.0);
+    for (i, &e) in expected.iter().enumerate() {
+        sum += e;
+        if sum >= min_expected {
+            groups.push(start..i + 1);
+            start = i + 1;
+            sum = 0.0;
+        }
+    }
+    if start < expected.len() {
+        match groups.last_mut() {
+            Some(last) => last.end = expected.len(),
+            None => groups.push(0..expected.len()),
+        }
+    }
+    groups
+}
+
+/// Checks that discrete data are non-negative whole numbers.
+fn check_whole_numbers(data: &[f64]) -> Result<(), StatError> {
+    if data.iter().any(|&x| !(x >= 0.0 && x.fract() == 0.0)) {
+        return Err(StatError::ComputeError(
+            "Discrete distributions require non-negative whole numbers".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Bins whole-number data under a fitted discrete distribution, given its `cdf` (`P(X <= k)`)
+/// and `sf` (`P(X > k)`).
+///
+/// Consecutive values are grouped from zero until each bin has a fitted probability of at least
+/// `min_prob`, and the bin reaching the largest observation, or leaving only a negligible upper
+/// tail, is open above. These are the bins that merging a bin per value would give, found by
+/// bisection so that an outlier does not create a bin for every value up to it.
+fn discrete_bins<C, S>(data: &[f64], parameter: f64, min_prob: f64, cdf: C, sf: S) -> DiscreteBins
+where
+    C: Fn(u64) -> f64,
+    S: Fn(u64) -> f64,
+{
+    let max = data.iter().fold(0.0_f64, |m, &x| m.max(x)) as u64;
+    let threshold = min_prob.max(NEGLIGIBLE);
+    let below = |k: u64| if k == 0 { 0.0 } else { cdf(k - 1) };
+    let above = |k: u64| if k == 0 { 1.0 } else { sf(k - 1) };
+    // P(start <= X < end), differencing whichever tail is more precise
+    let mass = |start: u64, end: u64| {
+        if below(end) < 0.5 {
+            below(end) - below(start)
+        } else {
+            above(start) - above(end)
+        }
+    };
+
+    let mut starts = vec![0];
+    let mut start = 0;
+    while start < max {
+        let (mut low, mut high) = (start + 1, max);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if mass(start, mid) >= threshold {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        if mass(start, low) < threshold || above(low) < NEGLIGIBLE {
+            break;
+        }
+        starts.push(low);
+        start = low;
+    }
+
+    let mut observed = vec![0.0; starts.len()];
+    for &x in data {
+        observed[starts.partition_point(|&k| k as f64 <= x) - 1] += 1.0;
+    }
+    let n = data.len() as f64;
+    let (bins, expected) = starts
+        .iter()
+        .enumerate()
+        .map(|(i, &k)| match starts.get(i + 1) {
+            Some(&end) => ((k as f64, end as f64), n * mass(k, end)),
+            None => ((k as f64, f64::INFINITY), n * above(k)),
+        })
+        .unzip();
+    (vec![parameter], bins, observed, expected)
+}
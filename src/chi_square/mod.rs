@@ -6,9 +6,28 @@
 //! The Chi-Square Test for Independence evaluates whether two categorical variables
 //! are independent of each other based on a contingency table.
 //!
+//! Beyond Pearson's statistic, `independence_test` offers Yates' continuity correction, the
+//! likelihood-ratio G statistic and Williams' correction, and reports Pearson and adjusted
+//! standardized residuals, Cramér's V, phi and the contingency coefficient with confidence
+//! intervals, and a warning when expected counts are below 5.
+//!
 //! The Chi-Square Goodness
 //! of Fit Test assesses whether observed frequencies match expected frequencies.
 //!
+//! The asymptotic chi-square p-values are unreliable for sparse categories. Monte Carlo
+//! p-values from seeded simulations (`goodness_of_fit_monte_carlo`, `independence_monte_carlo`)
+//! and the exact multinomial test (`goodness_of_fit_exact`) avoid the approximation.
+//!
+//! When the expected frequencies come from parameters estimated on the same data, the degrees
+//! of freedom must be reduced: `goodness_of_fit_ddof` takes the number of estimated parameters,
+//! and `goodness_of_fit_fitted` fits a Poisson, binomial, geometric or normal distribution by
+//! maximum likelihood, bins the data, merges sparse bins and runs the adjusted test.
+//!
+//! For ordered categories, the Cochran-Armitage test (`cochran_armitage`) looks for a linear
+//! trend of proportions across ordered groups of a 2×k table, and the linear-by-linear
+//! association test (`linear_by_linear`) looks for a linear association in an ordered r×c
+//! table, both with user-specified scores.
+//!
 //! The Chi-Square Test for Variance tests whether the variance of a sample differs
 //! significantly from a specified population variance.
 //!
@@ -19,32 +38,68 @@
 //! - `chi2_sample_size_ind`: Calculates the required sample size for the Chi-Square Test for Independence.
 //! - `chi2_sample_size_variance`: Calculates the required sample size for the Chi-Square Test for Variance.
 //!
+//! Each sample size is the smallest whose exact power reaches the desired power; the matching
+//! `chi2_power_gof`, `chi2_power_ind` and `chi2_power_variance` functions evaluate that power.
+//!
 //! ## Submodules
 //!
 //! - `goodness_of_fit`: Performs a Chi-Square Goodness of Fit Test.
 //! - `independence`: Performs a Chi-Square Test for Independence.
+//! - `exact`: Performs the exact multinomial Goodness of Fit Test.
+//! - `fit`: Fits distributions and merges sparse bins for adjusted Goodness of Fit Tests.
+//! - `monte_carlo`: Performs Goodness of Fit and Independence tests with Monte Carlo p-values.
+//! - `trend`: Performs the Cochran-Armitage and linear-by-linear tests for ordered categories.
 //! - `variance`: Performs a Chi-Square Test for Variance.
+//! - `types`: Defines the `IndependenceStatistic` and `FittedDistribution` options and their results.
 //!
 //! ## Exports
 //!
 //! The following functions are made available for use:
 //! - `goodness_of_fit`: Performs a Chi-Square Goodness of Fit Test.
 //! - `independence`: Performs a Chi-Square Test for Independence.
+//! - `independence_test`: Performs a test for independence with a choice of statistic, residuals and effect sizes.
+//! - `goodness_of_fit_ddof`: Performs a Goodness of Fit Test with a degrees-of-freedom adjustment.
+//! - `goodness_of_fit_fitted`: Performs a Goodness of Fit Test against a fitted distribution.
+//! - `merge_sparse_bins`: Merges adjacent categories with small expected counts.
+//! - `goodness_of_fit_exact`: Performs the exact multinomial Goodness of Fit Test.
+//! - `goodness_of_fit_monte_carlo`: Performs a Goodness of Fit Test with a Monte Carlo p-value.
+//! - `independence_monte_carlo`: Performs a Test for Independence with a Monte Carlo p-value.
+//! - `cochran_armitage`: Performs the Cochran-Armitage Test for Trend in proportions.
+//! - `linear_by_linear`: Performs the Linear-by-Linear Association Test.
 //! - `variance`: Performs a Chi-Square Test for Variance.
+//! - `IndependenceStatistic`: Pearson, Yates, likelihood-ratio or Williams.
+//! - `IndependenceResult`, `AssociationMeasure`: The detailed result of `independence_test`.
+//! - `FittedDistribution`, `FitResult`: The distribution and result of `goodness_of_fit_fitted`.
 //! - `chi2_sample_size_gof`: Calculates the required sample size for the Chi-Square Goodness of Fit Test.
 //! - `chi2_sample_size_ind`: Calculates the required sample size for the Chi-Square Test for Independence.
 //! - `chi2_sample_size_variance`: Calculates the required sample size for the Chi-Square Test for Variance.
+//! - `chi2_power_gof`, `chi2_power_ind`, `chi2_power_variance`: Calculate the exact power of the respective tests.
 //!
 //! ## Example
 //!
 //! ```rust
-//! use hypors::chi_square::{goodness_of_fit, independence, variance, chi2_sample_size_gof, chi2_sample_size_ind, chi2_sample_size_variance};
+//! use hypors::chi_square::{goodness_of_fit, goodness_of_fit_ddof, goodness_of_fit_fitted, merge_sparse_bins, FittedDistribution, independence, independence_test, goodness_of_fit_exact, goodness_of_fit_monte_carlo, independence_monte_carlo, cochran_armitage, linear_by_linear, variance, IndependenceStatistic, chi2_sample_size_gof, chi2_sample_size_ind, chi2_sample_size_variance};
 //! ```
 
 pub mod categorical;
+pub mod exact;
+pub mod fit;
+pub mod monte_carlo;
 pub mod sample_size;
+pub mod trend;
+pub mod types;
 pub mod variance;
 
-pub use categorical::{goodness_of_fit, independence};
-pub use sample_size::{chi2_sample_size_gof, chi2_sample_size_ind, chi2_sample_size_variance};
+pub use categorical::{goodness_of_fit, goodness_of_fit_ddof, independence, independence_test};
+pub use exact::goodness_of_fit_exact;
+pub use fit::{goodness_of_fit_fitted, merge_sparse_bins};
+pub use monte_carlo::{goodness_of_fit_monte_carlo, independence_monte_carlo};
+pub use sample_size::{
+    chi2_power_gof, chi2_power_ind, chi2_power_variance, chi2_sample_size_gof,
+    chi2_sample_size_ind, chi2_sample_size_variance,
+};
+pub use trend::{cochran_armitage, linear_by_linear};
+pub use types::{
+    AssociationMeasure, FitResult, FittedDistribution, IndependenceResult, IndependenceStatistic,
+};
 pub use variance::variance;
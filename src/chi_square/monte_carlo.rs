@@ -0,0 +1,313 @@
+use super::exact::{fit_inputs, pearson_statistic};
+use crate::common::{StatError, TestResult};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use statrs::function::gamma::ln_gamma;
+
+/// Relative tolerance for simulated statistics as extreme as the observed one.
+const TIE_TOLERANCE: f64 = 1e-7;
+
+/// Perform a Chi-Square Goodness of Fit Test with a Monte Carlo p-value.
+///
+/// Samples with the observed total are drawn from the hypothesized multinomial distribution,
+/// each as a chain of binomial draws for the categories conditional on the ones before, and the
+/// p-value is the proportion of simulated Pearson statistics at least as large as the observed
+/// one, `(1 + #{X²_sim >= X²}) / (B + 1)`. Unlike the asymptotic p-value, it remains valid when
+/// some expected counts are small.
+///
+/// # Arguments
+///
+/// * `observed` - An iterator of observed frequencies, which must be non-negative whole numbers.
+/// * `expected` - An iterator of expected frequencies (must be same length as `observed`), which
+///   are rescaled to the observed total.
+/// * `n_simulations` - The number of simulated samples `B` (e.g., 10000).
+/// * `alpha` - Significance level (commonly 0.05).
+/// * `seed` - The seed of the random number generator, making results reproducible.
+///
+/// # Returns
+///
+/// A `TestResult` with Pearson's chi-square statistic and the Monte Carlo p-value; the
+/// confidence interval is not applicable and returns `(NaN, NaN)`.
+///
+/// # Errors
+/// Returns `StatError` if:
+/// - Inputs have different lengths or contain fewer than two categories.
+/// - Expected frequencies are not positive, or observed counts are not whole numbers.
+/// - No simulations are requested.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::chi_square::goodness_of_fit_monte_carlo;
+///
+/// let observed = vec![30.0, 10.0, 20.0];
+/// let expected = vec![25.0, 15.0, 20.0];
+///
+/// let result = goodness_of_fit_monte_carlo(observed, expected, 10000, 0.05, 42).unwrap();
+/// assert!((result.p_value - 0.26).abs() < 0.02);
+/// ```
+pub fn goodness_of_fit_monte_carlo<O, E, T, U>(
+    observed: O,
+    expected: E,
+    n_simulations: usize,
+    alpha: f64,
+    seed: u64,
+) -> Result<TestResult, StatError>
+where
+    O: IntoIterator<Item = T>,
+    E: IntoIterator<Item = U>,
+    T: Into<f64>,
+    U: Into<f64>,
+{
+    let (observed, probs) = fit_inputs(observed, expected)?;
+    check_counts(observed.iter(), n_simulations)?;
+
+    let n = observed.iter().sum::<f64>() as u64;
+    let test_statistic = pearson_statistic(&observed, &probs);
+
+    // Probability of each category given that the sample is not in any earlier one
+    let conditional: Vec<f64> = probs
+        .iter()
+        .scan(1.0, |remaining: &mut f64, &p| {
+            let q = if *remaining > 0.0 {
+                (p / *remaining).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            *remaining -= p;
+            Some(q)
+        })
+        .collect();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut counts = vec![0.0; probs.len()];
+    let p_value = monte_carlo_p(test_statistic, n_simulations, || {
+        let mut remaining = n;
+        let last = counts.len() - 1;
+        for (count, &q) in counts[..last].iter_mut().zip(&conditional) {
+            let k = binomial_sample(&mut rng, remaining, q);
+            *count = k as f64;
+            remaining -= k;
+        }
+        counts[last] = remaining as f64;
+        pearson_statistic(&counts, &probs)
+    });
+
+    Ok(TestResult {
+        test_statistic,
+        p_value,
+        confidence_interval: (f64::NAN, f64::NAN),
+        null_hypothesis: "H0: Observed distribution matches expected distribution".into(),
+        alt_hypothesis: "Ha: Observed distribution does not match expected distribution".into(),
+        reject_null: p_value < alpha,
+    })
+}
+
+/// Draws from a binomial distribution with `n` trials and success probability `p`, by inversion
+/// when the mean is small and otherwise by the transformed rejection method of Hörmann (1993),
+/// so that the cost does not grow with `n`.
+fn binomial_sample<R: Rng>(rng: &mut R, n: u64, p: f64) -> u64 {
+    if n == 0 || p <= 0.0 {
+        return 0;
+    }
+    if p >= 1.0 {
+        return n;
+    }
+    if p > 0.5 {
+        return n - binomial_sample(rng, n, 1.0 - p);
+    }
+
+    let nf = n as f64;
+    let q = 1.0 - p;
+    if nf * p < 10.0 {
+        // Inversion, walking up the probabilities from zero
+        let s = p / q;
+        let a = (nf + 1.0) * s;
+        let mut r = q.powf(nf);
+        let mut u: f64 = rng.r#gen();
+        let mut k = 0;
+        while u > r && k < n {
+            u -= r;
+            k += 1;
+            r *= a / k as f64 - s;
+        }
+        return k;
+    }
+
+    let spq = (nf * p * q).sqrt();
+    let b = 1.15 + 2.53 * spq;
+    let a = -0.0873 + 0.0248 * b + 0.01 * p;
+    let c = nf * p + 0.5;
+    let v_r = 0.92 - 4.2 / b;
+    let alpha = (2.83 + 5.1 / b) * spq;
+    let lpq = (p / q).ln();
+    let m = ((nf + 1.0) * p).floor();
+    let h = ln_gamma(m + 1.0) + ln_gamma(nf - m + 1.0);
+    loop {
+        let u = rng.r#gen::<f64>() - 0.5;
+        let v: f64 = rng.r#gen();
+        let us = 0.5 - u.abs();
+        let k = ((2.0 * a / us + b) * u + c).floor();
+        if k < 0.0 || k > nf {
+            continue;
+        }
+        if us >= 0.07 && v <= v_r {
+            return k as u64;
+        }
+        let v = (v * alpha / (a / (us * us) + b)).ln();
+        if v <= h - ln_gamma(k + 1.0) - ln_gamma(nf - k + 1.0) + (k - m) * lpq {
+            return k as u64;
+        }
+    }
+}
+
+/// Perform a Chi-Square Test for Independence with a Monte Carlo p-value.
+///
+/// Tables with the observed row and column totals are simulated under independence by randomly
+/// permuting the column categories of the units across the rows, and the p-value is the
+/// proportion of simulated Pearson statistics at least as large as the observed one,
+/// `(1 + #{X²_sim >= X²}) / (B + 1)`. Unlike the asymptotic p-value, it remains valid for sparse
+/// tables.
+///
+/// # Arguments
+///
+/// * `contingency_table` - A slice of row vectors (`Vec<Vec<f64>>`) of non-negative whole counts.
+/// * `n_simulations` - The number of simulated tables `B` (e.g., 10000).
+/// * `alpha` - The significance level for the test (commonly 0.05).
+/// * `seed` - The seed of the random number generator, making results reproducible.
+///
+/// # Returns
+///
+/// A `TestResult` with Pearson's chi-square statistic and the Monte Carlo p-value; the
+/// confidence interval is not applicable and returns `(NaN, NaN)`.
+///
+/// # Errors
+/// Returns `StatError` if:
+/// - Input rows are unequal or contain fewer than 2 rows/columns.
+/// - Counts are not non-negative whole numbers, or the total is zero.
+/// - No simulations are requested.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::chi_square::independence_monte_carlo;
+///
+/// let table = vec![
+///     vec![3.0, 1.0, 0.0],
+///     vec![0.0, 2.0, 4.0],
+/// ];
+///
+/// let result = independence_monte_carlo(&table, 10000, 0.05, 42).unwrap();
+/// assert!(result.p_value < 0.1);
+/// ```
+pub fn independence_monte_carlo(
+    contingency_table: &[Vec<f64>],
+    n_simulations: usize,
+    alpha: f64,
+    seed: u64,
+) -> Result<TestResult, StatError> {
+    let num_rows = contingency_table.len();
+    if num_rows < 2 {
+        return Err(StatError::ComputeError("At least two rows required".into()));
+    }
+    let num_cols = contingency_table[0].len();
+    if num_cols < 2 || !contingency_table.iter().all(|row| row.len() == num_cols) {
+        return Err(StatError::ComputeError(
+            "All rows must have equal and ≥2 columns".into(),
+        ));
+    }
+    check_counts(contingency_table.iter().flatten(), n_simulations)?;
+
+    let total: f64 = contingency_table.iter().flatten().sum();
+    if total == 0.0 {
+        return Err(StatError::ComputeError(
+            "Total frequency must be greater than zero".into(),
+        ));
+    }
+
+    let row_totals: Vec<f64> = contingency_table
+        .iter()
+        .map(|row| row.iter().sum())
+        .collect();
+    let col_totals: Vec<f64> = (0..num_cols)
+        .map(|j| contingency_table.iter().map(|row| row[j]).sum())
+        .collect();
+    let statistic = |table: &[f64]| -> f64 {
+        table
+            .iter()
+            .enumerate()
+            .map(|(cell, &obs)| {
+                let exp = row_totals[cell / num_cols] * col_totals[cell % num_cols] / total;
+                if exp == 0.0 {
+                    0.0
+                } else {
+                    (obs - exp).powi(2) / exp
+                }
+            })
+            .sum()
+    };
+    let observed: Vec<f64> = contingency_table.iter().flatten().copied().collect();
+    let test_statistic = statistic(&observed);
+
+    // One entry per unit: its row, and its column to be permuted
+    let rows: Vec<usize> = row_totals
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &r)| std::iter::repeat_n(i, r as usize))
+        .collect();
+    let mut cols: Vec<usize> = col_totals
+        .iter()
+        .enumerate()
+        .flat_map(|(j, &c)| std::iter::repeat_n(j, c as usize))
+        .collect();
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut table = vec![0.0; num_rows * num_cols];
+    let p_value = monte_carlo_p(test_statistic, n_simulations, || {
+        cols.shuffle(&mut rng);
+        table.iter_mut().for_each(|c| *c = 0.0);
+        for (&i, &j) in rows.iter().zip(&cols) {
+            table[i * num_cols + j] += 1.0;
+        }
+        statistic(&table)
+    });
+
+    Ok(TestResult {
+        test_statistic,
+        p_value,
+        confidence_interval: (f64::NAN, f64::NAN),
+        null_hypothesis: "H0: Variables are independent".into(),
+        alt_hypothesis: "Ha: Variables are not independent".into(),
+        reject_null: p_value < alpha,
+    })
+}
+
+/// Checks that counts are non-negative whole numbers and that simulations are requested.
+fn check_counts<'a>(
+    mut counts: impl Iterator<Item = &'a f64>,
+    n_simulations: usize,
+) -> Result<(), StatError> {
+    if counts.any(|&x| !(x >= 0.0 && x.is_finite() && x.fract() == 0.0)) {
+        return Err(StatError::ComputeError(
+            "Monte Carlo test requires non-negative whole counts".into(),
+        ));
+    }
+    if n_simulations == 0 {
+        return Err(StatError::ComputeError(
+            "At least one simulation is required".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Calculates the Monte Carlo p-value `(1 + #{simulated >= observed}) / (B + 1)`.
+fn monte_carlo_p<F>(observed: f64, n_simulations: usize, mut simulate: F) -> f64
+where
+    F: FnMut() -> f64,
+{
+    let threshold = observed * (1.0 - TIE_TOLERANCE);
+    let exceed = (0..n_simulations)
+        .filter(|_| simulate() >= threshold)
+        .count();
+    (1 + exceed) as f64 / (n_simulations + 1) as f64
+}
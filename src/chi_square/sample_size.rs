@@ -1,132 +1,236 @@
+use crate::common::calc::solve_sample_size;
+use crate::noncentral::NoncentralChiSquared;
 use statrs::distribution::{ChiSquared, ContinuousCDF};
 
+/// Calculates the exact power of a chi-square test with `df` degrees of freedom.
+///
+/// Under the alternative, the statistic follows a noncentral chi-squared distribution with
+/// noncentrality `λ = n * w²`, where `w` is Cohen's effect size.
+fn chi2_power(effect_size: f64, alpha: f64, n: f64, df: f64) -> f64 {
+    let lambda = n * effect_size.powi(2);
+    match (ChiSquared::new(df), NoncentralChiSquared::new(df, lambda)) {
+        (Ok(chi_dist), Ok(ncx2)) => 1.0 - ncx2.cdf(chi_dist.inverse_cdf(1.0 - alpha)),
+        _ => f64::NAN,
+    }
+}
+
+/// Calculates the exact power of a chi-square goodness-of-fit test.
+///
+/// # Arguments
+///
+/// * `effect_size` - The effect size (Cohen's w, e.g. 0.1 small, 0.3 medium, 0.5 large).
+/// * `alpha` - The significance level (e.g., 0.05).
+/// * `n` - The total sample size.
+/// * `num_categories` - The number of categories (at least 2).
+///
+/// # Returns
+///
+/// The probability of rejecting the null hypothesis, or `NaN` if the inputs are invalid.
+///
+/// # Example
+/// ```rust
+/// use hypors::chi_square::chi2_power_gof;
+///
+/// let power = chi2_power_gof(0.3, 0.05, 108.0, 3);
+/// assert!((power - 0.8037).abs() < 1e-3);
+/// ```
+pub fn chi2_power_gof(effect_size: f64, alpha: f64, n: f64, num_categories: usize) -> f64 {
+    chi2_power(effect_size, alpha, n, num_categories as f64 - 1.0)
+}
+
 /// Calculates the required sample size for a chi-square goodness-of-fit test.
 ///
-/// This function computes the necessary sample size to ensure each expected frequency is adequate
-/// for the goodness-of-fit test.
+/// This function finds the smallest total sample size whose exact power, computed from the
+/// noncentral chi-squared distribution with `num_categories - 1` degrees of freedom, reaches
+/// the desired power.
 ///
 /// # Arguments
 ///
-/// * `expected_counts` - A vector of expected counts for each category.
+/// * `effect_size` - The minimum detectable effect size (Cohen's w).
 /// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `power` - The desired statistical power (e.g., 0.80 for 80% power).
+/// * `num_categories` - The number of categories (at least 2).
 ///
 /// # Returns
 ///
-/// The estimated sample size required to achieve the specified power and significance level.
+/// The total sample size required to achieve the specified power and significance level, or
+/// `NaN` if the inputs are invalid.
 ///
 /// # Example
 /// ```rust
 /// use hypors::chi_square::chi2_sample_size_gof;
 ///
-/// let expected_counts = vec![20, 30, 50];
+/// let effect_size = 0.3; // Cohen's w
 /// let alpha = 0.05; // 5% significance level
+/// let power = 0.80; // 80% power
 ///
-/// let sample_size = chi2_sample_size_gof(&expected_counts, alpha);
-/// println!("Required sample size: {}", sample_size);
+/// let sample_size = chi2_sample_size_gof(effect_size, alpha, power, 3);
+/// assert_eq!(sample_size, 108.0);
 /// ```
-pub fn chi2_sample_size_gof(expected_counts: &[usize], alpha: f64) -> f64 {
-    let total_expected: f64 = expected_counts.iter().map(|&count| count as f64).sum();
-    let expected_proportions: Vec<f64> = expected_counts
-        .iter()
-        .map(|&count| count as f64 / total_expected)
-        .collect();
-
-    let chi_squared_dist = ChiSquared::new(expected_counts.len() as f64 - 1.0)
-        .expect("Failed to create Chi-squared distribution");
-
-    let chi_alpha = chi_squared_dist.inverse_cdf(1.0 - alpha);
-
-    // Using the formula n = (chi_alpha / expected_proportion)^2
-    let n = expected_proportions
-        .iter()
-        .map(|&prop| (chi_alpha / prop).powi(2))
-        .fold(0.0, |sum, x| sum + x)
-        / expected_counts.len() as f64;
+pub fn chi2_sample_size_gof(
+    effect_size: f64,
+    alpha: f64,
+    power: f64,
+    num_categories: usize,
+) -> f64 {
+    solve_sample_size(
+        |n| chi2_power_gof(effect_size, alpha, n, num_categories),
+        alpha,
+        power,
+        1.0,
+    )
+}
 
-    n.ceil() // Rounds up to the next whole sample size
+/// Calculates the exact power of a chi-square test for independence.
+///
+/// # Arguments
+///
+/// * `effect_size` - The effect size (Cohen's w).
+/// * `alpha` - The significance level (e.g., 0.05).
+/// * `n` - The total sample size.
+/// * `num_rows` - The number of rows of the contingency table (at least 2).
+/// * `num_cols` - The number of columns of the contingency table (at least 2).
+///
+/// # Returns
+///
+/// The probability of rejecting the null hypothesis, or `NaN` if the inputs are invalid.
+///
+/// # Example
+/// ```rust
+/// use hypors::chi_square::chi2_power_ind;
+///
+/// let power = chi2_power_ind(0.3, 0.05, 88.0, 2, 2);
+/// assert!((power - 0.8035).abs() < 1e-3);
+/// ```
+pub fn chi2_power_ind(
+    effect_size: f64,
+    alpha: f64,
+    n: f64,
+    num_rows: usize,
+    num_cols: usize,
+) -> f64 {
+    let df = (num_rows as f64 - 1.0) * (num_cols as f64 - 1.0);
+    chi2_power(effect_size, alpha, n, df)
 }
 
 /// Calculates the required sample size for a chi-square test for independence.
 ///
-/// This function computes the necessary sample size to detect a minimum detectable effect size
-/// for a given alpha, power, and expected proportions in a contingency table.
+/// This function finds the smallest total sample size whose exact power, computed from the
+/// noncentral chi-squared distribution with `(num_rows - 1) * (num_cols - 1)` degrees of
+/// freedom, reaches the desired power.
 ///
 /// # Arguments
 ///
-/// * `expected_counts` - A vector of expected counts for each cell in the contingency table.
+/// * `effect_size` - The minimum detectable effect size (Cohen's w).
 /// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `power` - The desired statistical power (e.g., 0.80 for 80% power).
+/// * `num_rows` - The number of rows of the contingency table (at least 2).
+/// * `num_cols` - The number of columns of the contingency table (at least 2).
 ///
 /// # Returns
 ///
-/// The estimated sample size required to achieve the specified power and significance level.
+/// The total sample size required to achieve the specified power and significance level, or
+/// `NaN` if the inputs are invalid.
 ///
 /// # Example
 /// ```rust
 /// use hypors::chi_square::chi2_sample_size_ind;
 ///
-/// let expected_counts = vec![20, 30, 50];
+/// let effect_size = 0.3; // Cohen's w
 /// let alpha = 0.05; // 5% significance level
+/// let power = 0.80; // 80% power
 ///
-/// let sample_size = chi2_sample_size_ind(&expected_counts, alpha);
-/// println!("Required sample size: {}", sample_size);
+/// let sample_size = chi2_sample_size_ind(effect_size, alpha, power, 2, 2);
+/// assert_eq!(sample_size, 88.0);
 /// ```
-pub fn chi2_sample_size_ind(expected_counts: &[usize], alpha: f64) -> f64 {
-    let total_expected: f64 = expected_counts.iter().map(|&count| count as f64).sum();
-    let expected_proportions: Vec<f64> = expected_counts
-        .iter()
-        .map(|&count| count as f64 / total_expected)
-        .collect();
-
-    let chi_squared_dist = ChiSquared::new(expected_counts.len() as f64 - 1.0)
-        .expect("Failed to create Chi-squared distribution");
-
-    let chi_alpha = chi_squared_dist.inverse_cdf(1.0 - alpha);
+pub fn chi2_sample_size_ind(
+    effect_size: f64,
+    alpha: f64,
+    power: f64,
+    num_rows: usize,
+    num_cols: usize,
+) -> f64 {
+    solve_sample_size(
+        |n| chi2_power_ind(effect_size, alpha, n, num_rows, num_cols),
+        alpha,
+        power,
+        1.0,
+    )
+}
 
-    // Using the formula n = (chi_alpha / expected_proportion)^2
-    let n = expected_proportions
-        .iter()
-        .map(|&prop| (chi_alpha / prop).powi(2))
-        .fold(0.0, |sum, x| sum + x)
-        / expected_counts.len() as f64;
+/// Calculates the exact power of a one-tailed chi-square test for variance.
+///
+/// The alternative variance is `variance + effect_size`. A positive effect is tested with a
+/// right-tailed test and a negative effect with a left-tailed test. Under the alternative,
+/// `(n - 1) s² / σ0²` is distributed as `σ1² / σ0²` times a chi-squared variable with `n - 1`
+/// degrees of freedom.
+///
+/// # Arguments
+///
+/// * `effect_size` - The difference between the alternative and hypothesized variance.
+/// * `alpha` - The significance level (e.g., 0.05).
+/// * `n` - The sample size (at least 2).
+/// * `variance` - The hypothesized population variance.
+///
+/// # Returns
+///
+/// The probability of rejecting the null hypothesis, or `NaN` if the inputs are invalid.
+///
+/// # Example
+/// ```rust
+/// use hypors::chi_square::chi2_power_variance;
+///
+/// let power = chi2_power_variance(1.0, 0.05, 26.0, 1.0);
+/// assert!(power > 0.8);
+/// ```
+pub fn chi2_power_variance(effect_size: f64, alpha: f64, n: f64, variance: f64) -> f64 {
+    let ratio = variance / (variance + effect_size);
+    let chi_dist = match ChiSquared::new(n - 1.0) {
+        Ok(chi_dist) if ratio > 0.0 => chi_dist,
+        _ => return f64::NAN,
+    };
 
-    n.ceil() // Rounds up to the next whole sample size
+    if effect_size >= 0.0 {
+        1.0 - chi_dist.cdf(chi_dist.inverse_cdf(1.0 - alpha) * ratio)
+    } else {
+        chi_dist.cdf(chi_dist.inverse_cdf(alpha) * ratio)
+    }
 }
 
 /// Calculates the required sample size for a chi-square test for variance.
 ///
-/// This function computes the necessary sample size to detect a minimum detectable effect size
-/// for a given alpha, power, and population variance.
+/// This function finds the smallest sample size whose exact power, evaluated with
+/// `chi2_power_variance`, reaches the desired power.
 ///
 /// # Arguments
 ///
-/// * `effect_size` - The minimum detectable effect size (in terms of variance).
+/// * `effect_size` - The minimum detectable difference between the alternative and hypothesized variance.
 /// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
 /// * `power` - The desired statistical power (e.g., 0.80 for 80% power).
-/// * `variance` - The population variance (or a reasonable estimate).
+/// * `variance` - The hypothesized population variance.
 ///
 /// # Returns
 ///
-/// The estimated sample size required to achieve the specified power and significance level.
+/// The sample size required to achieve the specified power and significance level, or `NaN`
+/// if the inputs are invalid.
 ///
 /// # Example
 /// ```rust
 /// use hypors::chi_square::chi2_sample_size_variance;
 ///
-/// let effect_size = 0.5; // Minimum detectable effect size
+/// let effect_size = 1.0; // Detect a doubling of the variance
 /// let alpha = 0.05; // 5% significance level
 /// let power = 0.80; // 80% power
-/// let variance = 1.0; // Population variance
+/// let variance = 1.0;
 ///
 /// let sample_size = chi2_sample_size_variance(effect_size, alpha, power, variance);
-/// println!("Required sample size: {}", sample_size);
+/// assert_eq!(sample_size, 26.0);
 /// ```
 pub fn chi2_sample_size_variance(effect_size: f64, alpha: f64, power: f64, variance: f64) -> f64 {
-    let chi_squared_dist = ChiSquared::new(1.0).expect("Failed to create Chi-squared distribution");
-
-    let chi_alpha = chi_squared_dist.inverse_cdf(1.0 - alpha);
-    let chi_beta = chi_squared_dist.inverse_cdf(power);
-
-    // Formula: n = ((chi_alpha + chi_beta) * variance / effect_size)^2
-    let n = ((chi_alpha + chi_beta) * variance / effect_size).powi(2);
-    n.ceil() // Rounds up to the next whole sample size
+    solve_sample_size(
+        |n| chi2_power_variance(effect_size, alpha, n, variance),
+        alpha,
+        power,
+        2.0,
+    )
 }
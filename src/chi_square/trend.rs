@@ -0,0 +1,250 @@
+use crate::common::{StatError, TailType, TestResult, calculate_p};
+use statrs::distribution::Normal;
+
+/// Perform the Cochran-Armitage Test for Trend in proportions across ordered groups.
+///
+/// The test for independence ignores the ordering of the groups (such as price tiers or doses)
+/// and spreads its power over every pattern of difference. This test looks only for a linear
+/// trend of the event proportion in the group scores, which makes it much more powerful for
+/// dose-response alternatives. The statistic is
+/// `Z = Σ s_j (x_j - n_j p̄) / √(p̄ (1 - p̄) (Σ n_j s_j² - (Σ n_j s_j)² / N))`, and `Z²` equals
+/// the chi-square statistic for trend.
+///
+/// # Arguments
+///
+/// * `contingency_table` - A 2×k table whose first row holds the events and second row the
+///   non-events of each ordered group.
+/// * `scores` - The score of each group (e.g. `1, 2, ..., k` or the doses), one per column.
+/// * `tail` - The type of tail: `Right` for a proportion increasing with the scores, `Left`
+///   for decreasing, `Two` for either.
+/// * `alpha` - The significance level for the test (commonly 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` with the Z-statistic and p-value; the confidence interval is not applicable
+/// and returns `(NaN, NaN)`.
+///
+/// # Errors
+/// Returns `StatError` if:
+/// - The table does not have two rows of equal length with at least two columns.
+/// - The number of scores differs from the number of columns.
+/// - Counts are negative, or the variance of the statistic is zero (e.g. no events, or all
+///   groups with the same score).
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::chi_square::cochran_armitage;
+/// use hypors::common::TailType;
+///
+/// // Smokers among lung cancer patients in four age groups
+/// let smokers = vec![83.0, 90.0, 129.0, 70.0];
+/// let patients = vec![86.0, 93.0, 136.0, 82.0];
+/// let non_smokers = patients.iter().zip(&smokers).map(|(n, x)| n - x).collect();
+///
+/// let table = vec![smokers, non_smokers];
+/// let result = cochran_armitage(&table, &[1.0, 2.0, 3.0, 4.0], TailType::Two, 0.05).unwrap();
+/// assert!((result.test_statistic.powi(2) - 8.2249).abs() < 1e-4);
+/// assert!(result.reject_null);
+/// ```
+pub fn cochran_armitage(
+    contingency_table: &[Vec<f64>],
+    scores: &[f64],
+    tail: TailType,
+    alpha: f64,
+) -> Result<TestResult, StatError> {
+    if contingency_table.len() != 2 {
+        return Err(StatError::ComputeError(
+            "Cochran-Armitage test requires a 2×k table".into(),
+        ));
+    }
+    check_table(contingency_table, None, scores)?;
+
+    let events = &contingency_table[0];
+    let sizes: Vec<f64> = events
+        .iter()
+        .zip(&contingency_table[1])
+        .map(|(x, y)| x + y)
+        .collect();
+    let total: f64 = sizes.iter().sum();
+    let p_bar = events.iter().sum::<f64>() / total;
+
+    let statistic: f64 = events
+        .iter()
+        .zip(&sizes)
+        .zip(scores)
+        .map(|((x, n), s)| s * (x - n * p_bar))
+        .sum();
+    let sum_ns: f64 = sizes.iter().zip(scores).map(|(n, s)| n * s).sum();
+    let sum_ns2: f64 = sizes.iter().zip(scores).map(|(n, s)| n * s * s).sum();
+    let variance = p_bar * (1.0 - p_bar) * (sum_ns2 - sum_ns.powi(2) / total);
+    if variance <= 0.0 || variance.is_nan() {
+        return Err(StatError::ComputeError(
+            "Variance of the trend statistic is zero".into(),
+        ));
+    }
+
+    trend_result(
+        statistic / variance.sqrt(),
+        tail,
+        alpha,
+        "H0: Proportions have no linear trend in the scores",
+        [
+            "Ha: Proportions decrease with the scores",
+            "Ha: Proportions increase with the scores",
+            "Ha: Proportions have a linear trend in the scores",
+        ],
+    )
+}
+
+/// Perform the Linear-by-Linear Association Test for ordered r×c tables.
+///
+/// Both variables are ordered and assigned scores, and the test looks for a linear association
+/// between them. The statistic is `M = √(N - 1) r`, where `r` is the Pearson correlation between
+/// the row and column scores of the `N` units; `M²` is compared with a chi-square distribution
+/// with one degree of freedom, which concentrates the power of the test for independence on a
+/// single trend. For 2×k tables, `M² = (N - 1) / N · Z²` with the Cochran-Armitage `Z`.
+///
+/// # Arguments
+///
+/// * `contingency_table` - A slice of row vectors (`Vec<Vec<f64>>`) with ordered rows and columns.
+/// * `row_scores` - The score of each row.
+/// * `col_scores` - The score of each column.
+/// * `tail` - The type of tail: `Right` for a positive association, `Left` for negative, `Two`
+///   for either.
+/// * `alpha` - The significance level for the test (commonly 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` with the statistic `M` and p-value; the confidence interval is not applicable
+/// and returns `(NaN, NaN)`.
+///
+/// # Errors
+/// Returns `StatError` if:
+/// - Input rows are unequal or contain fewer than 2 rows/columns.
+/// - The numbers of scores differ from the numbers of rows and columns.
+/// - Counts are negative, or either variable has no variation in its scores.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::chi_square::linear_by_linear;
+/// use hypors::common::TailType;
+///
+/// // Infant malformation by maternal alcohol consumption (Agresti, 2002)
+/// let table = vec![
+///     vec![17066.0, 48.0],
+///     vec![14464.0, 38.0],
+///     vec![788.0, 5.0],
+///     vec![126.0, 1.0],
+///     vec![37.0, 1.0],
+/// ];
+/// let alcohol = [0.0, 0.5, 1.5, 4.0, 7.0];
+///
+/// let result = linear_by_linear(&table, &alcohol, &[0.0, 1.0], TailType::Two, 0.05).unwrap();
+/// assert!((result.test_statistic.powi(2) - 6.57).abs() < 0.01);
+/// ```
+pub fn linear_by_linear(
+    contingency_table: &[Vec<f64>],
+    row_scores: &[f64],
+    col_scores: &[f64],
+    tail: TailType,
+    alpha: f64,
+) -> Result<TestResult, StatError> {
+    check_table(contingency_table, Some(row_scores), col_scores)?;
+
+    let total: f64 = contingency_table.iter().flatten().sum();
+    // Weighted sums over the units of each cell
+    let cell_sum = |f: &dyn Fn(f64, f64) -> f64| -> f64 {
+        contingency_table
+            .iter()
+            .zip(row_scores)
+            .flat_map(|(row, &u)| row.iter().zip(col_scores).map(move |(&n, &v)| (n, u, v)))
+            .map(|(n, u, v)| n * f(u, v))
+            .sum()
+    };
+    let mean_u = cell_sum(&|u, _| u) / total;
+    let mean_v = cell_sum(&|_, v| v) / total;
+    let covariance = cell_sum(&|u, v| (u - mean_u) * (v - mean_v));
+    let var_u = cell_sum(&|u, _| (u - mean_u).powi(2));
+    let var_v = cell_sum(&|_, v| (v - mean_v).powi(2));
+    if !(var_u > 0.0 && var_v > 0.0) {
+        return Err(StatError::ComputeError(
+            "Row and column scores must both vary".into(),
+        ));
+    }
+    let correlation = covariance / (var_u * var_v).sqrt();
+
+    trend_result(
+        (total - 1.0).sqrt() * correlation,
+        tail,
+        alpha,
+        "H0: No linear association between the scores",
+        [
+            "Ha: Negative linear association between the scores",
+            "Ha: Positive linear association between the scores",
+            "Ha: Linear association between the scores",
+        ],
+    )
+}
+
+/// Checks the table dimensions, counts and scores of a trend test.
+fn check_table(
+    contingency_table: &[Vec<f64>],
+    row_scores: Option<&[f64]>,
+    col_scores: &[f64],
+) -> Result<(), StatError> {
+    let num_rows = contingency_table.len();
+    if num_rows < 2 {
+        return Err(StatError::ComputeError("At least two rows required".into()));
+    }
+    let num_cols = contingency_table[0].len();
+    if num_cols < 2 || !contingency_table.iter().all(|row| row.len() == num_cols) {
+        return Err(StatError::ComputeError(
+            "All rows must have equal and ≥2 columns".into(),
+        ));
+    }
+    if col_scores.len() != num_cols || row_scores.is_some_and(|s| s.len() != num_rows) {
+        return Err(StatError::ComputeError(
+            "There must be one score per row and column".into(),
+        ));
+    }
+    if contingency_table
+        .iter()
+        .flatten()
+        .any(|&x| !(x >= 0.0 && x.is_finite()))
+    {
+        return Err(StatError::ComputeError(
+            "Frequencies must be non-negative".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Builds the result of a trend test from its standard normal statistic, with the alternative
+/// hypotheses of the left, right and two tails.
+fn trend_result(
+    z: f64,
+    tail: TailType,
+    alpha: f64,
+    null_hypothesis: &str,
+    [left, right, two]: [&str; 3],
+) -> Result<TestResult, StatError> {
+    let normal = Normal::new(0.0, 1.0)
+        .map_err(|e| StatError::ComputeError(format!("Normal distribution error: {e}")))?;
+    let alt_hypothesis = match tail {
+        TailType::Left => left,
+        TailType::Right => right,
+        TailType::Two => two,
+    };
+    let p_value = calculate_p(z, tail, &normal);
+
+    Ok(TestResult {
+        test_statistic: z,
+        p_value,
+        confidence_interval: (f64::NAN, f64::NAN),
+        null_hypothesis: null_hypothesis.into(),
+        alt_hypothesis: alt_hypothesis.into(),
+        reject_null: p_value < alpha,
+    })
+}
@@ -0,0 +1,102 @@
+use crate::common::TestResult;
+use serde::{Deserialize, Serialize};
+
+/// Represents the statistic used by the test for independence.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndependenceStatistic {
+    /// Pearson's statistic, `X² = Σ (O - E)² / E`.
+    Pearson,
+    /// Pearson's statistic with Yates' continuity correction, which shrinks each `|O - E|` by
+    /// up to 0.5. Only defined for 2x2 tables.
+    Yates,
+    /// The likelihood-ratio statistic, `G = 2 Σ O ln(O / E)`.
+    LikelihoodRatio,
+    /// The likelihood-ratio statistic divided by Williams' correction factor
+    /// `q = 1 + (n Σ 1/R_i - 1)(n Σ 1/C_j - 1) / (6n(r - 1)(c - 1))`, which improves the
+    /// chi-square approximation in small samples.
+    Williams,
+}
+
+/// Stores a measure of association with its confidence interval.
+///
+/// # Fields
+///
+/// * `estimate` - The point estimate of the measure.
+/// * `confidence_interval` - The confidence interval for the measure (lower, upper bounds).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssociationMeasure {
+    pub estimate: f64,
+    pub confidence_interval: (f64, f64),
+}
+
+/// Stores the result of a test for independence with its cell diagnostics and effect sizes.
+///
+/// The measures of association are derived from Pearson's statistic whichever statistic is
+/// tested, and their confidence intervals invert the noncentral chi-square distribution of
+/// that statistic.
+///
+/// # Fields
+///
+/// * `expected` - The expected count of each cell under independence.
+/// * `pearson_residuals` - The Pearson residual of each cell, `(O - E) / √E`.
+/// * `adjusted_residuals` - The adjusted standardized residual of each cell,
+///   `(O - E) / √(E (1 - R_i/n)(1 - C_j/n))`, which is approximately standard normal under
+///   independence.
+/// * `cramers_v` - Cramér's V, `√(X² / (n (min(r, c) - 1)))`.
+/// * `phi` - The signed phi coefficient, `(ad - bc) / √(R_1 R_2 C_1 C_2)`, for 2x2 tables only.
+/// * `contingency_coefficient` - Pearson's contingency coefficient, `√(X² / (X² + n))`.
+/// * `warnings` - Warnings about the reliability of the chi-square approximation, such as
+///   expected counts below 5.
+/// * `result` - The test result; the confidence interval is not applicable and returns
+///   `(NaN, NaN)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndependenceResult {
+    pub expected: Vec<Vec<f64>>,
+    pub pearson_residuals: Vec<Vec<f64>>,
+    pub adjusted_residuals: Vec<Vec<f64>>,
+    pub cramers_v: AssociationMeasure,
+    pub phi: Option<AssociationMeasure>,
+    pub contingency_coefficient: AssociationMeasure,
+    pub warnings: Vec<String>,
+    pub result: TestResult,
+}
+
+/// Represents a distribution fitted by maximum likelihood for a goodness-of-fit test.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FittedDistribution {
+    /// Poisson counts, with the rate estimated by the sample mean.
+    Poisson,
+    /// Binomial counts out of a known number of `trials`, with the success probability
+    /// estimated by `mean / trials`.
+    Binomial { trials: u64 },
+    /// Geometric counts of failures before the first success, with the success probability
+    /// estimated by `1 / (1 + mean)`.
+    Geometric,
+    /// Continuous normal data, with the mean and standard deviation estimated by the sample mean
+    /// and the maximum likelihood (divisor `n`) standard deviation, grouped into `bins` bins that
+    /// are equally likely under the fitted distribution.
+    Normal { bins: usize },
+}
+
+/// Stores the result of a goodness-of-fit test against a fitted distribution.
+///
+/// # Fields
+///
+/// * `distribution` - The fitted distribution.
+/// * `parameters` - The maximum likelihood estimates: the rate (Poisson), the success
+///   probability (binomial, geometric), or the mean and standard deviation (normal).
+/// * `bins` - The `[lower, upper)` bounds of each bin after merging sparse bins.
+/// * `observed` - The observed count in each bin.
+/// * `expected` - The expected count in each bin under the fitted distribution.
+/// * `ddof` - The number of estimated parameters removed from the degrees of freedom.
+/// * `result` - The goodness-of-fit test with `bins - 1 - ddof` degrees of freedom.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FitResult {
+    pub distribution: FittedDistribution,
+    pub parameters: Vec<f64>,
+    pub bins: Vec<(f64, f64)>,
+    pub observed: Vec<f64>,
+    pub expected: Vec<f64>,
+    pub ddof: usize,
+    pub result: TestResult,
+}
@@ -120,3 +120,116 @@ pub fn calculate_chi2_confidence_interval(
     let upper_bound = (df * sample_variance) / chi_square_lower;
     (lower_bound, upper_bound)
 }
+
+/// Finds the smallest whole sample size at which a power function reaches the target power.
+///
+/// The power function is evaluated exactly at candidate sample sizes; the search doubles the
+/// sample size until the target is reached and then bisects on whole numbers. Power is assumed
+/// to be non-decreasing in the sample size.
+///
+/// # Arguments
+///
+/// * `power_at` - The exact power of the test at a given sample size.
+/// * `alpha` - The significance level of the test.
+/// * `power` - The desired statistical power (e.g., 0.80 for 80% power).
+/// * `min_n` - The smallest sample size for which the test is defined.
+///
+/// # Returns
+///
+/// The minimum sample size, `f64::NAN` if `alpha` or `power` is not in (0, 1) or the power
+/// function is undefined for the inputs, or `f64::INFINITY` if the target power cannot be
+/// reached.
+pub(crate) fn solve_sample_size<F>(power_at: F, alpha: f64, power: f64, min_n: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    const MAX_N: f64 = 1e12;
+
+    if !(alpha > 0.0 && alpha < 1.0 && power > 0.0 && power < 1.0) {
+        return f64::NAN;
+    }
+    // Whether the target is reached at `n`, or `None` if the power is undefined
+    let reaches = |n: f64| {
+        let p = power_at(n);
+        (!p.is_nan()).then_some(p >= power)
+    };
+
+    let search = || -> Option<f64> {
+        if reaches(min_n)? {
+            return Some(min_n);
+        }
+
+        let mut low = min_n;
+        let mut high = (2.0 * min_n).max(min_n + 1.0);
+        while !reaches(high)? {
+            low = high;
+            high *= 2.0;
+            if high > MAX_N {
+                return Some(f64::INFINITY);
+            }
+        }
+
+        // Invariant: power_at(low) < power <= power_at(high)
+        while high - low > 1.0 {
+            let mid = ((low + high) / 2.0).floor();
+            if reaches(mid)? {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+        Some(high)
+    };
+    search().unwrap_or(f64::NAN)
+}
+
+/// Returns the whole second-group size, `ceil(ratio * n1)` and at least 1, allocated to a first
+/// group of `n1` units.
+pub(crate) fn allocated_size(n1: f64, ratio: f64) -> f64 {
+    (ratio * n1).ceil().max(1.0)
+}
+
+/// Finds the smallest whole first-group sample size at which a two-sample power function
+/// reaches the target power, with the second group allocated `ratio` times as many units.
+///
+/// The second group size is `ceil(ratio * n1)` (at least 1), so both sizes are whole numbers.
+///
+/// # Arguments
+///
+/// * `power_at` - The exact power of the test at given first and second group sizes.
+/// * `alpha` - The significance level of the test.
+/// * `power` - The desired statistical power (e.g., 0.80 for 80% power).
+/// * `ratio` - The allocation ratio `n2 / n1`.
+/// * `min_n` - The smallest first-group sample size for which the test is defined.
+///
+/// # Returns
+///
+/// A tuple `(n1, n2)` of per-group sample sizes, `(NaN, NaN)` if the ratio is not positive or
+/// the inputs are invalid as for `solve_sample_size`, or `(INFINITY, INFINITY)` if the target
+/// power cannot be reached.
+pub(crate) fn solve_allocated_sample_size<F>(
+    power_at: F,
+    alpha: f64,
+    power: f64,
+    ratio: f64,
+    min_n: f64,
+) -> (f64, f64)
+where
+    F: Fn(f64, f64) -> f64,
+{
+    if !(ratio > 0.0 && ratio.is_finite()) {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let n1 = solve_sample_size(
+        |n1| power_at(n1, allocated_size(n1, ratio)),
+        alpha,
+        power,
+        min_n,
+    );
+    if n1.is_finite() {
+        (n1, allocated_size(n1, ratio))
+    } else {
+        (n1, n1)
+    }
+}
@@ -0,0 +1,80 @@
+use statrs::function::gamma::ln_gamma;
+
+/// Fisher's noncentral hypergeometric distribution of the first cell of a 2x2 table with fixed
+/// margins, as a function of the odds ratio.
+pub(crate) struct NoncentralHypergeometric {
+    /// The smallest possible count of the first cell.
+    pub(crate) low: usize,
+    /// The log-probabilities of each count from `low` at an odds ratio of 1.
+    log_weights: Vec<f64>,
+}
+
+fn ln_binomial(n: f64, k: f64) -> f64 {
+    ln_gamma(n + 1.0) - ln_gamma(k + 1.0) - ln_gamma(n - k + 1.0)
+}
+
+impl NoncentralHypergeometric {
+    /// Creates the distribution for row totals `n1`, `n2` and first column total `m1`.
+    pub(crate) fn new(n1: usize, n2: usize, m1: usize) -> Self {
+        let low = m1.saturating_sub(n2);
+        let high = n1.min(m1);
+        let log_weights = (low..=high)
+            .map(|x| ln_binomial(n1 as f64, x as f64) + ln_binomial(n2 as f64, (m1 - x) as f64))
+            .collect();
+        NoncentralHypergeometric { low, log_weights }
+    }
+
+    /// The largest possible count of the first cell.
+    pub(crate) fn high(&self) -> usize {
+        self.low + self.log_weights.len() - 1
+    }
+
+    /// Calculates the probabilities of each count from `low` at log odds ratio `log_psi`.
+    pub(crate) fn probs(&self, log_psi: f64) -> Vec<f64> {
+        let logs: Vec<f64> = self
+            .log_weights
+            .iter()
+            .enumerate()
+            .map(|(i, w)| w + (self.low + i) as f64 * log_psi)
+            .collect();
+        let max = logs.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let weights: Vec<f64> = logs.iter().map(|l| (l - max).exp()).collect();
+        let total: f64 = weights.iter().sum();
+        weights.iter().map(|w| w / total).collect()
+    }
+
+    /// Calculates `P(X < x)`, `P(X = x)` and `P(X > x)` at log odds ratio `log_psi`.
+    pub(crate) fn split(&self, x: usize, log_psi: f64) -> (f64, f64, f64) {
+        let probs = self.probs(log_psi);
+        let i = x - self.low;
+        let below: f64 = probs[..i].iter().sum();
+        let above: f64 = probs[i + 1..].iter().sum();
+        (below, probs[i], above)
+    }
+
+    /// Calculates the mean count at log odds ratio `log_psi`.
+    pub(crate) fn mean(&self, log_psi: f64) -> f64 {
+        self.probs(log_psi)
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (self.low + i) as f64 * p)
+            .sum()
+    }
+}
+
+/// Finds the root of an increasing function of the log odds ratio by bisection.
+pub(crate) fn solve_log_psi<F>(f: F, target: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut low, mut high) = (-50.0, 50.0);
+    for _ in 0..200 {
+        let mid = (low + high) / 2.0;
+        if f(mid) < target {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    (low + high) / 2.0
+}
@@ -0,0 +1,440 @@
+use super::types::{ContingencyTable, LogLinearFit, LogLinearModel};
+use crate::common::{StatError, TailType, TestResult, calculate_p};
+use statrs::distribution::ChiSquared;
+
+/// The largest number of iterative proportional fitting cycles.
+const MAX_ITERATIONS: usize = 1000;
+
+/// Convergence tolerance on the largest change of a fitted count in one cycle.
+const TOLERANCE: f64 = 1e-8;
+
+impl ContingencyTable {
+    /// Creates a contingency table from its dimensions and row-major counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `dims` - The number of categories of each variable.
+    /// * `counts` - The counts in row-major order, with the last variable varying fastest.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StatError` if there are fewer than two variables, a variable has fewer than
+    /// two categories, the number of counts does not match the dimensions, or a count is
+    /// negative.
+    pub fn new(dims: Vec<usize>, counts: Vec<f64>) -> Result<Self, StatError> {
+        if dims.len() < 2 {
+            return Err(StatError::ComputeError(
+                "At least two variables required".into(),
+            ));
+        }
+        if dims.iter().any(|&d| d < 2) {
+            return Err(StatError::ComputeError(
+                "Each variable must have at least two categories".into(),
+            ));
+        }
+        if counts.len() != dims.iter().product::<usize>() {
+            return Err(StatError::ComputeError(
+                "Number of counts must match the dimensions".into(),
+            ));
+        }
+        if counts.iter().any(|&x| !(x >= 0.0 && x.is_finite())) {
+            return Err(StatError::ComputeError(
+                "Counts must be non-negative".into(),
+            ));
+        }
+        Ok(ContingencyTable { dims, counts })
+    }
+
+    /// Creates a three-way table from layers of two-way tables, such as one table per stratum.
+    ///
+    /// The layer is the first variable, and the rows and columns of each layer are the second
+    /// and third.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StatError` if the layers differ in dimensions or the table is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use hypors::contingency::ContingencyTable;
+    ///
+    /// let layers = vec![
+    ///     vec![vec![1.0, 2.0], vec![3.0, 4.0]],
+    ///     vec![vec![5.0, 6.0], vec![7.0, 8.0]],
+    /// ];
+    /// let table = ContingencyTable::from_layers(&layers).unwrap();
+    /// assert_eq!(table.dims(), &[2, 2, 2]);
+    /// assert_eq!(table.get(&[1, 0, 1]), 6.0);
+    /// ```
+    pub fn from_layers(layers: &[Vec<Vec<f64>>]) -> Result<Self, StatError> {
+        let rows = layers.first().map_or(0, |layer| layer.len());
+        let cols = layers
+            .first()
+            .and_then(|layer| layer.first())
+            .map_or(0, |row| row.len());
+        if layers
+            .iter()
+            .any(|layer| layer.len() != rows || layer.iter().any(|row| row.len() != cols))
+        {
+            return Err(StatError::ComputeError(
+                "All layers must have the same dimensions".into(),
+            ));
+        }
+        let counts = layers.iter().flatten().flatten().copied().collect();
+        ContingencyTable::new(vec![layers.len(), rows, cols], counts)
+    }
+
+    /// Returns the number of categories of each variable.
+    pub fn dims(&self) -> &[usize] {
+        &self.dims
+    }
+
+    /// Returns the counts in row-major order.
+    pub fn counts(&self) -> &[f64] {
+        &self.counts
+    }
+
+    /// Returns the count of the cell with the given category of each variable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index does not match the dimensions of the table.
+    pub fn get(&self, index: &[usize]) -> f64 {
+        assert!(
+            index.len() == self.dims.len() && index.iter().zip(&self.dims).all(|(i, d)| i < d),
+            "Index out of bounds"
+        );
+        let position = index
+            .iter()
+            .zip(&self.dims)
+            .fold(0, |position, (&i, &d)| position * d + i);
+        self.counts[position]
+    }
+
+    /// Maps every cell to its position in the margin over the given variables.
+    fn margin_index(&self, variables: &[usize]) -> Vec<usize> {
+        let mut index = vec![0; self.dims.len()];
+        (0..self.counts.len())
+            .map(|_| {
+                let position = variables
+                    .iter()
+                    .fold(0, |position, &v| position * self.dims[v] + index[v]);
+                // Advance the multi-index, last variable fastest
+                for (i, &d) in index.iter_mut().zip(&self.dims).rev() {
+                    *i += 1;
+                    if *i < d {
+                        break;
+                    }
+                    *i = 0;
+                }
+                position
+            })
+            .collect()
+    }
+}
+
+impl LogLinearModel {
+    /// Creates a hierarchical log-linear model from its generating class.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use hypors::contingency::LogLinearModel;
+    ///
+    /// // (AB, C): C is jointly independent of A and B
+    /// let model = LogLinearModel::new(vec![vec![0, 1], vec![2]]);
+    /// assert_eq!(model.terms.len(), 2);
+    /// ```
+    pub fn new(terms: Vec<Vec<usize>>) -> Self {
+        LogLinearModel { terms }
+    }
+
+    /// Creates the model of mutual independence of all variables, `(A, B, C, ...)`.
+    pub fn mutual_independence(num_variables: usize) -> Self {
+        LogLinearModel::new((0..num_variables).map(|v| vec![v]).collect())
+    }
+
+    /// Creates the model of conditional independence of variables `a` and `b` given all the
+    /// others; for three variables `A, B, C`, the independence of `A` and `B` given `C` is
+    /// `(AC, BC)`.
+    pub fn conditional_independence(num_variables: usize, a: usize, b: usize) -> Self {
+        let others: Vec<usize> = (0..num_variables).filter(|&v| v != a && v != b).collect();
+        let term = |v: usize| {
+            let mut term = vec![v];
+            term.extend(&others);
+            term.sort_unstable();
+            term
+        };
+        LogLinearModel::new(vec![term(a), term(b)])
+    }
+
+    /// Creates the model of homogeneous association, with every two-way interaction and no
+    /// higher ones; for three variables this is `(AB, AC, BC)`, in which the conditional odds
+    /// ratios between any two variables are the same at every level of the third.
+    pub fn homogeneous_association(num_variables: usize) -> Self {
+        LogLinearModel::new(
+            (0..num_variables)
+                .flat_map(|a| ((a + 1)..num_variables).map(move |b| vec![a, b]))
+                .collect(),
+        )
+    }
+
+    /// Counts the parameters of the model in a table with the given dimensions: one for each
+    /// combination of non-reference categories of every term in the hierarchy.
+    fn num_parameters(&self, dims: &[usize]) -> usize {
+        let mut subsets: Vec<u64> = self
+            .terms
+            .iter()
+            .flat_map(|term| {
+                let mask: u64 = term.iter().fold(0, |m, &v| m | 1 << v);
+                // Every subset of the term, including the empty one
+                let mut subsets = vec![];
+                let mut subset = mask;
+                loop {
+                    subsets.push(subset);
+                    if subset == 0 {
+                        break;
+                    }
+                    subset = (subset - 1) & mask;
+                }
+                subsets
+            })
+            .collect();
+        if subsets.is_empty() {
+            subsets.push(0);
+        }
+        subsets.sort_unstable();
+        subsets.dedup();
+        subsets
+            .iter()
+            .map(|&subset| {
+                (0..dims.len())
+                    .filter(|v| subset & (1 << v) != 0)
+                    .map(|v| dims[v] - 1)
+                    .product::<usize>()
+            })
+            .sum()
+    }
+}
+
+/// Fits a hierarchical log-linear model to a multi-way table by iterative proportional fitting.
+///
+/// Starting from a uniform table, the fitted counts are scaled in turn to reproduce each margin
+/// of the generating class, and the cycle is repeated until the fitted counts stop changing.
+/// The fitted counts are the maximum likelihood estimates, and the model is tested against the
+/// saturated model with its deviance `G²` on `cells - parameters` degrees of freedom. Models of
+/// mutual independence, conditional independence and homogeneous association are tested this
+/// way, and nested models are compared with `compare_loglinear`.
+///
+/// Degrees of freedom are not reduced for zero margins; sampling zeros in a fitted margin make
+/// the chi-square reference distribution approximate.
+///
+/// # Arguments
+///
+/// * `table` - The multi-way contingency table.
+/// * `model` - The log-linear model to fit.
+/// * `alpha` - The significance level (e.g., 0.05).
+///
+/// # Returns
+///
+/// A `LogLinearFit` with the fitted counts, deviance, Pearson statistic, degrees of freedom and
+/// the goodness-of-fit test of the model.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the table is empty, the model refers to a variable outside the
+/// table, a term repeats a variable, or the model is saturated and leaves no degrees of
+/// freedom.
+///
+/// # Example
+/// ```rust
+/// use hypors::contingency::{fit_loglinear, ContingencyTable, LogLinearModel};
+///
+/// // Alcohol, cigarette and marijuana use of high school seniors (Agresti, 2002)
+/// let table = ContingencyTable::new(
+///     vec![2, 2, 2],
+///     vec![911.0, 538.0, 44.0, 456.0, 3.0, 43.0, 2.0, 279.0],
+/// )
+/// .unwrap();
+///
+/// let fit = fit_loglinear(&table, &LogLinearModel::homogeneous_association(3), 0.05).unwrap();
+/// assert!((fit.deviance - 0.374).abs() < 1e-3);
+/// assert_eq!(fit.df, 1);
+/// assert!(!fit.result.reject_null);
+/// ```
+pub fn fit_loglinear(
+    table: &ContingencyTable,
+    model: &LogLinearModel,
+    alpha: f64,
+) -> Result<LogLinearFit, StatError> {
+    let num_variables = table.dims.len();
+    if model.terms.iter().flatten().any(|&v| v >= num_variables) {
+        return Err(StatError::ComputeError(
+            "Model refers to a variable outside the table".into(),
+        ));
+    }
+    if model
+        .terms
+        .iter()
+        .any(|term| (1..term.len()).any(|i| term[..i].contains(&term[i])))
+    {
+        return Err(StatError::ComputeError(
+            "Model terms cannot repeat a variable".into(),
+        ));
+    }
+    let total: f64 = table.counts.iter().sum();
+    if total == 0.0 {
+        return Err(StatError::EmptyData);
+    }
+    let num_parameters = model.num_parameters(&table.dims);
+    let df = table.counts.len().saturating_sub(num_parameters);
+    if df == 0 {
+        return Err(StatError::ComputeError(
+            "Saturated model leaves no degrees of freedom".into(),
+        ));
+    }
+
+    // Each term's margin index of every cell and the observed margin
+    let margins: Vec<(Vec<usize>, Vec<f64>)> = model
+        .terms
+        .iter()
+        .map(|term| {
+            let index = table.margin_index(term);
+            let size = term.iter().map(|&v| table.dims[v]).product();
+            let mut observed = vec![0.0; size];
+            for (&m, &count) in index.iter().zip(&table.counts) {
+                observed[m] += count;
+            }
+            (index, observed)
+        })
+        .collect();
+
+    // With no terms, only the total is fitted
+    let mut fitted = vec![total / table.counts.len() as f64; table.counts.len()];
+    let mut iterations = 0;
+    let mut converged = false;
+    while iterations < MAX_ITERATIONS {
+        iterations += 1;
+        let previous = fitted.clone();
+        for (index, observed) in &margins {
+            let mut current = vec![0.0; observed.len()];
+            for (&m, &f) in index.iter().zip(&fitted) {
+                current[m] += f;
+            }
+            for (&m, f) in index.iter().zip(fitted.iter_mut()) {
+                *f = if current[m] > 0.0 {
+                    *f * observed[m] / current[m]
+                } else {
+                    0.0
+                };
+            }
+        }
+        let change = fitted
+            .iter()
+            .zip(&previous)
+            .map(|(f, p)| (f - p).abs())
+            .fold(0.0, f64::max);
+        if change < TOLERANCE {
+            converged = true;
+            break;
+        }
+    }
+
+    let (mut deviance, mut pearson) = (0.0, 0.0);
+    for (&obs, &fit) in table.counts.iter().zip(&fitted) {
+        if fit > 0.0 {
+            pearson += (obs - fit).powi(2) / fit;
+            if obs > 0.0 {
+                deviance += 2.0 * obs * (obs / fit).ln();
+            }
+        }
+    }
+    let deviance = deviance.max(0.0);
+
+    let chi_distribution = ChiSquared::new(df as f64)
+        .map_err(|e| StatError::ComputeError(format!("Chi-squared distribution error: {e}")))?;
+    let p_value = calculate_p(deviance, TailType::Right, &chi_distribution);
+
+    Ok(LogLinearFit {
+        model: model.clone(),
+        fitted,
+        deviance,
+        pearson,
+        df,
+        iterations,
+        converged,
+        result: TestResult {
+            test_statistic: deviance,
+            p_value,
+            confidence_interval: (f64::NAN, f64::NAN),
+            null_hypothesis: "H0: The log-linear model fits the table".into(),
+            alt_hypothesis: "Ha: The log-linear model does not fit the table".into(),
+            reject_null: p_value < alpha,
+        },
+    })
+}
+
+/// Compares two nested log-linear models with a likelihood-ratio test.
+///
+/// The difference of the deviances of a reduced model and a fuller model containing it follows
+/// a chi-square distribution with the difference of their degrees of freedom when the reduced
+/// model holds. Comparing `(AC, BC)` with `(AB, AC, BC)`, for example, tests the conditional
+/// association of `A` and `B` given `C`.
+///
+/// # Arguments
+///
+/// * `reduced` - The fit of the smaller model.
+/// * `full` - The fit of the larger model, on the same table.
+/// * `alpha` - The significance level (e.g., 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` with the deviance difference and p-value; the confidence interval is not
+/// applicable and returns `(NaN, NaN)`.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the reduced model does not have more degrees of freedom than the
+/// full model.
+///
+/// # Example
+/// ```rust
+/// use hypors::contingency::{compare_loglinear, fit_loglinear, ContingencyTable, LogLinearModel};
+///
+/// let table = ContingencyTable::new(
+///     vec![2, 2, 2],
+///     vec![911.0, 538.0, 44.0, 456.0, 3.0, 43.0, 2.0, 279.0],
+/// )
+/// .unwrap();
+///
+/// // Is alcohol use associated with cigarette use given marijuana use?
+/// let reduced = fit_loglinear(&table, &LogLinearModel::conditional_independence(3, 0, 1), 0.05).unwrap();
+/// let full = fit_loglinear(&table, &LogLinearModel::homogeneous_association(3), 0.05).unwrap();
+/// let result = compare_loglinear(&reduced, &full, 0.05).unwrap();
+/// assert!(result.reject_null);
+/// ```
+pub fn compare_loglinear(
+    reduced: &LogLinearFit,
+    full: &LogLinearFit,
+    alpha: f64,
+) -> Result<TestResult, StatError> {
+    if reduced.df <= full.df {
+        return Err(StatError::ComputeError(
+            "Reduced model must have more degrees of freedom than the full model".into(),
+        ));
+    }
+    let test_statistic = (reduced.deviance - full.deviance).max(0.0);
+    let chi_distribution = ChiSquared::new((reduced.df - full.df) as f64)
+        .map_err(|e| StatError::ComputeError(format!("Chi-squared distribution error: {e}")))?;
+    let p_value = calculate_p(test_statistic, TailType::Right, &chi_distribution);
+
+    Ok(TestResult {
+        test_statistic,
+        p_value,
+        confidence_interval: (f64::NAN, f64::NAN),
+        null_hypothesis: "H0: The reduced model fits as well as the full model".into(),
+        alt_hypothesis: "Ha: The full model fits better than the reduced model".into(),
+        reject_null: p_value < alpha,
+    })
+}
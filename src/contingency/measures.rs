@@ -0,0 +1,344 @@
+use super::hypergeometric::{NoncentralHypergeometric, solve_log_psi};
+use super::types::{EffectEstimate, OddsRatioMethod, RiskDifferenceMethod};
+use crate::common::{StatError, TailType, TestResult, calculate_p};
+use statrs::distribution::{ContinuousCDF, Normal};
+
+/// Extracts the cells `(a, b, c, d)` of a 2x2 table `[[a, b], [c, d]]`, whose rows are the
+/// groups and whose columns are event and no event.
+pub(crate) fn two_by_two(table: &[Vec<f64>]) -> Result<(f64, f64, f64, f64), StatError> {
+    if table.len() != 2 || table.iter().any(|row| row.len() != 2) {
+        return Err(StatError::ComputeError("Table must be 2x2".into()));
+    }
+    let (a, b, c, d) = (table[0][0], table[0][1], table[1][0], table[1][1]);
+    if [a, b, c, d].iter().any(|&x| !(x >= 0.0 && x.is_finite())) {
+        return Err(StatError::ComputeError(
+            "Counts must be non-negative".into(),
+        ));
+    }
+    if a + b == 0.0 || c + d == 0.0 {
+        return Err(StatError::ComputeError(
+            "Both rows must have a positive total".into(),
+        ));
+    }
+    Ok((a, b, c, d))
+}
+
+fn standard_normal() -> Result<Normal, StatError> {
+    Normal::new(0.0, 1.0)
+        .map_err(|e| StatError::ComputeError(format!("Failed to create Normal distribution: {e}")))
+}
+
+/// Builds the result of a two-sided Wald test of a log-scale measure against zero.
+pub(crate) fn log_wald(
+    estimate: f64,
+    std_error: f64,
+    alpha: f64,
+    label: &str,
+) -> Result<EffectEstimate, StatError> {
+    let z_dist = standard_normal()?;
+    let z = z_dist.inverse_cdf(1.0 - alpha / 2.0);
+    let test_statistic = estimate.ln() / std_error;
+    let p_value = calculate_p(test_statistic, TailType::Two, &z_dist);
+    Ok(EffectEstimate {
+        estimate,
+        result: TestResult {
+            test_statistic,
+            p_value,
+            confidence_interval: (
+                estimate * (-z * std_error).exp(),
+                estimate * (z * std_error).exp(),
+            ),
+            null_hypothesis: format!("H0: {label} = 1"),
+            alt_hypothesis: format!("Ha: {label} ≠ 1"),
+            reject_null: p_value < alpha,
+        },
+    })
+}
+
+/// Calculates the Wilson score interval for a proportion.
+fn wilson(successes: f64, n: f64, z: f64) -> (f64, f64) {
+    let p = successes / n;
+    let denominator = 1.0 + z.powi(2) / n;
+    let center = p + z.powi(2) / (2.0 * n);
+    let half_width = z * (p * (1.0 - p) / n + z.powi(2) / (4.0 * n.powi(2))).sqrt();
+    (
+        (center - half_width) / denominator,
+        (center + half_width) / denominator,
+    )
+}
+
+/// Calculates the Miettinen-Nurminen score statistic for `H0: p1 - p2 = delta`, using the
+/// closed-form restricted maximum likelihood estimates of Farrington and Manning.
+fn mn_score(x1: f64, n1: f64, x2: f64, n2: f64, delta: f64) -> f64 {
+    let (p1, p2) = (x1 / n1, x2 / n2);
+    let theta = n2 / n1;
+    let a = 1.0 + theta;
+    let b = -(1.0 + theta + p1 + theta * p2 + delta * (theta + 2.0));
+    let c = delta.powi(2) + delta * (2.0 * p1 + theta + 1.0) + p1 + theta * p2;
+    let d = -p1 * delta * (1.0 + delta);
+
+    let v = b.powi(3) / (27.0 * a.powi(3)) - b * c / (6.0 * a.powi(2)) + d / (2.0 * a);
+    let u = v.signum()
+        * (b.powi(2) / (9.0 * a.powi(2)) - c / (3.0 * a))
+            .max(0.0)
+            .sqrt();
+    let w = if u == 0.0 {
+        std::f64::consts::PI / 3.0
+    } else {
+        (std::f64::consts::PI + (v / u.powi(3)).clamp(-1.0, 1.0).acos()) / 3.0
+    };
+    let restricted1 = (2.0 * u * w.cos() - b / (3.0 * a)).clamp(0.0, 1.0);
+    let restricted2 = (restricted1 - delta).clamp(0.0, 1.0);
+
+    let n = n1 + n2;
+    let variance =
+        (restricted1 * (1.0 - restricted1) / n1 + restricted2 * (1.0 - restricted2) / n2) * n
+            / (n - 1.0);
+    let difference = p1 - p2 - delta;
+    if difference == 0.0 {
+        0.0
+    } else {
+        difference / variance.sqrt()
+    }
+}
+
+/// Calculates the risk difference `p1 - p2` of a 2x2 table with a score confidence interval.
+///
+/// The table is `[[a, b], [c, d]]`, with the groups as rows and event and no event as columns,
+/// so `p1 = a / (a + b)` and `p2 = c / (c + d)`.
+///
+/// # Arguments
+///
+/// * `table` - The 2x2 table of counts.
+/// * `method` - The method used for the confidence interval and test.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+///
+/// # Returns
+///
+/// An `EffectEstimate` with the risk difference and a `TestResult` whose test statistic is the
+/// score statistic for `H0: p1 - p2 = 0`.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the table is not 2x2, a count is negative, or a row total is zero.
+///
+/// # Example
+/// ```rust
+/// use hypors::contingency::{risk_difference, RiskDifferenceMethod};
+///
+/// let table = vec![vec![56.0, 14.0], vec![48.0, 32.0]];
+/// let rd = risk_difference(&table, RiskDifferenceMethod::Newcombe, 0.05).unwrap();
+/// assert!((rd.estimate - 0.2).abs() < 1e-12);
+/// assert!((rd.result.confidence_interval.0 - 0.0524).abs() < 1e-4);
+/// ```
+pub fn risk_difference(
+    table: &[Vec<f64>],
+    method: RiskDifferenceMethod,
+    alpha: f64,
+) -> Result<EffectEstimate, StatError> {
+    let (a, b, c, d) = two_by_two(table)?;
+    let (n1, n2) = (a + b, c + d);
+    let (p1, p2) = (a / n1, c / n2);
+    let estimate = p1 - p2;
+
+    let z_dist = standard_normal()?;
+    let z = z_dist.inverse_cdf(1.0 - alpha / 2.0);
+
+    let (test_statistic, confidence_interval) = match method {
+        RiskDifferenceMethod::Newcombe => {
+            let (l1, u1) = wilson(a, n1, z);
+            let (l2, u2) = wilson(c, n2, z);
+            let lower = estimate - ((p1 - l1).powi(2) + (u2 - p2).powi(2)).sqrt();
+            let upper = estimate + ((u1 - p1).powi(2) + (p2 - l2).powi(2)).sqrt();
+
+            let pooled = (a + c) / (n1 + n2);
+            let std_error = (pooled * (1.0 - pooled) * (1.0 / n1 + 1.0 / n2)).sqrt();
+            let statistic = if std_error > 0.0 {
+                estimate / std_error
+            } else {
+                0.0
+            };
+            (statistic, (lower, upper))
+        }
+        RiskDifferenceMethod::MiettinenNurminen => {
+            // The score statistic decreases in delta; invert it on each side of the estimate
+            let bisect = |mut low: f64, mut high: f64, target: f64| {
+                for _ in 0..100 {
+                    let mid = (low + high) / 2.0;
+                    if mn_score(a, n1, c, n2, mid) > target {
+                        low = mid;
+                    } else {
+                        high = mid;
+                    }
+                }
+                (low + high) / 2.0
+            };
+            let lower = bisect(-1.0, estimate, z);
+            let upper = bisect(estimate, 1.0, -z);
+            (mn_score(a, n1, c, n2, 0.0), (lower, upper))
+        }
+    };
+
+    let p_value = calculate_p(test_statistic, TailType::Two, &z_dist);
+    Ok(EffectEstimate {
+        estimate,
+        result: TestResult {
+            test_statistic,
+            p_value,
+            confidence_interval,
+            null_hypothesis: "H0: p1 - p2 = 0".into(),
+            alt_hypothesis: "Ha: p1 - p2 ≠ 0".into(),
+            reject_null: p_value < alpha,
+        },
+    })
+}
+
+/// Calculates the risk ratio `p1 / p2` of a 2x2 table with Katz's log confidence interval.
+///
+/// The table is `[[a, b], [c, d]]`, with the groups as rows and event and no event as columns.
+/// The test is the Wald test of `ln(RR) = 0`. If either event count is zero, 0.5 is added to
+/// every cell.
+///
+/// # Arguments
+///
+/// * `table` - The 2x2 table of counts.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+///
+/// # Returns
+///
+/// An `EffectEstimate` with the risk ratio and a `TestResult` whose test statistic is the Wald
+/// Z-statistic of the log risk ratio.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the table is not 2x2, a count is negative, or a row total is zero.
+///
+/// # Example
+/// ```rust
+/// use hypors::contingency::risk_ratio;
+///
+/// let table = vec![vec![30.0, 70.0], vec![15.0, 85.0]];
+/// let rr = risk_ratio(&table, 0.05).unwrap();
+/// assert!((rr.estimate - 2.0).abs() < 1e-12);
+/// assert!(rr.result.reject_null);
+/// ```
+pub fn risk_ratio(table: &[Vec<f64>], alpha: f64) -> Result<EffectEstimate, StatError> {
+    let (mut a, mut b, mut c, mut d) = two_by_two(table)?;
+    if a == 0.0 || c == 0.0 {
+        (a, b, c, d) = (a + 0.5, b + 0.5, c + 0.5, d + 0.5);
+    }
+    let (n1, n2) = (a + b, c + d);
+    let estimate = (a / n1) / (c / n2);
+    let std_error = (1.0 / a - 1.0 / n1 + 1.0 / c - 1.0 / n2).sqrt();
+    log_wald(estimate, std_error, alpha, "RR")
+}
+
+/// Calculates the odds ratio `ad / bc` of a 2x2 table with a Woolf, exact or mid-p confidence
+/// interval and test.
+///
+/// The table is `[[a, b], [c, d]]`, with the groups as rows and event and no event as columns.
+///
+/// - **Woolf**: The sample odds ratio with the logit interval and Wald test; if any cell is
+///   zero, 0.5 is added to every cell.
+/// - **Exact**: The conditional maximum likelihood estimate, with the exact conditional interval
+///   and a two-sided p-value of twice the smaller one-sided p-value, so that the interval
+///   excludes 1 exactly when the test rejects. The counts must be whole numbers.
+/// - **MidP**: As exact, counting half the probability of the observed table.
+///
+/// # Arguments
+///
+/// * `table` - The 2x2 table of counts.
+/// * `method` - The method used for the confidence interval and test.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+///
+/// # Returns
+///
+/// An `EffectEstimate` with the odds ratio and a `TestResult` whose test statistic is the Wald
+/// Z-statistic of the log odds ratio for Woolf's method, or the count `a` for the exact methods.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the table is not 2x2, a count is negative, a row total is zero, or
+/// an exact method is given counts that are not whole numbers.
+///
+/// # Example
+/// ```rust
+/// use hypors::contingency::{odds_ratio, OddsRatioMethod};
+///
+/// let table = vec![vec![3.0, 1.0], vec![1.0, 3.0]];
+/// let or = odds_ratio(&table, OddsRatioMethod::Exact, 0.05).unwrap();
+/// assert!((or.estimate - 6.4083).abs() < 1e-4);
+/// assert!((or.result.p_value - 0.4857).abs() < 1e-4);
+/// ```
+pub fn odds_ratio(
+    table: &[Vec<f64>],
+    method: OddsRatioMethod,
+    alpha: f64,
+) -> Result<EffectEstimate, StatError> {
+    let (a, b, c, d) = two_by_two(table)?;
+
+    let mid_p = match method {
+        OddsRatioMethod::Woolf => {
+            let (a, b, c, d) = if [a, b, c, d].contains(&0.0) {
+                (a + 0.5, b + 0.5, c + 0.5, d + 0.5)
+            } else {
+                (a, b, c, d)
+            };
+            let estimate = a * d / (b * c);
+            let std_error = (1.0 / a + 1.0 / b + 1.0 / c + 1.0 / d).sqrt();
+            return log_wald(estimate, std_error, alpha, "OR");
+        }
+        OddsRatioMethod::Exact => false,
+        OddsRatioMethod::MidP => true,
+    };
+
+    if [a, b, c, d].iter().any(|x| x.fract() != 0.0) {
+        return Err(StatError::ComputeError(
+            "Exact methods require whole counts".into(),
+        ));
+    }
+    let dist = NoncentralHypergeometric::new((a + b) as usize, (c + d) as usize, (a + c) as usize);
+    let x = a as usize;
+    let weight = if mid_p { 0.5 } else { 1.0 };
+
+    // One-sided p-values P(X <= x) and P(X >= x), with the observed table weighted
+    let lower_tail = |log_psi: f64| {
+        let (below, at, _) = dist.split(x, log_psi);
+        below + weight * at
+    };
+    let upper_tail = |log_psi: f64| {
+        let (_, at, above) = dist.split(x, log_psi);
+        above + weight * at
+    };
+
+    let estimate = if x == dist.low {
+        0.0
+    } else if x == dist.high() {
+        f64::INFINITY
+    } else {
+        solve_log_psi(|log_psi| dist.mean(log_psi), a).exp()
+    };
+    let lower = if x == dist.low {
+        0.0
+    } else {
+        solve_log_psi(upper_tail, alpha / 2.0).exp()
+    };
+    let upper = if x == dist.high() {
+        f64::INFINITY
+    } else {
+        solve_log_psi(|log_psi| -lower_tail(log_psi), -alpha / 2.0).exp()
+    };
+
+    let p_value = (2.0 * lower_tail(0.0).min(upper_tail(0.0))).min(1.0);
+    Ok(EffectEstimate {
+        estimate,
+        result: TestResult {
+            test_statistic: a,
+            p_value,
+            confidence_interval: (lower, upper),
+            null_hypothesis: "H0: OR = 1".into(),
+            alt_hypothesis: "Ha: OR ≠ 1".into(),
+            reject_null: p_value < alpha,
+        },
+    })
+}
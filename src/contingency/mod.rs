@@ -0,0 +1,86 @@
+//! # Contingency Table Effect Measures
+//!
+//! The `contingency` module provides effect measures for 2x2 tables, each with a confidence
+//! interval and a matching test of no effect, together with analyses of stratified and
+//! multi-way tables.
+//!
+//! `proportion::z_test_ind` reports only the difference in proportions with a Wald interval,
+//! which undercovers for small samples and rare events. Tables are given as
+//! `[[a, b], [c, d]]`, with the groups as rows and event and no event as columns.
+//!
+//! ## Effect Measures
+//!
+//! - **Risk Difference**: `p1 - p2`, with Newcombe's hybrid score or the Miettinen-Nurminen
+//!   score interval.
+//! - **Risk Ratio**: `p1 / p2`, with Katz's log interval.
+//! - **Odds Ratio**: `ad / bc`, with Woolf's logit interval, or the exact conditional and mid-p
+//!   intervals.
+//!
+//! ## Stratified Tables
+//!
+//! Pooling strata (e.g. countries) into a single table can reverse an association (Simpson's
+//! paradox). The stratified analyses compare groups within each stratum:
+//!
+//! - **Cochran-Mantel-Haenszel**: Tests conditional independence for 2x2 or r×c strata.
+//! - **Mantel-Haenszel Odds Ratio**: The common odds ratio with the Robins-Breslow-Greenland
+//!   interval.
+//! - **Breslow-Day**: Tests whether the odds ratios are homogeneous across strata.
+//!
+//! ## Log-Linear Models
+//!
+//! Three- and four-way tables are analysed with hierarchical log-linear models fitted by
+//! iterative proportional fitting:
+//!
+//! - **Fitting**: `fit_loglinear` reports the fitted counts and tests the model on its
+//!   likelihood-ratio deviance.
+//! - **Models**: `LogLinearModel` builds mutual independence, conditional independence and
+//!   homogeneous association models, or any generating class.
+//! - **Comparison**: `compare_loglinear` tests nested models on their deviance difference.
+//!
+//! ## Submodules
+//!
+//! - `loglinear`: Contains log-linear model fitting by iterative proportional fitting.
+//! - `measures`: Contains the risk difference, risk ratio and odds ratio.
+//! - `stratified`: Contains the Cochran-Mantel-Haenszel and Breslow-Day tests.
+//! - `types`: Defines the interval methods, the `EffectEstimate` result and the log-linear types.
+//!
+//! ## Exports
+//!
+//! The following functions and types are made available for use:
+//!
+//! - `risk_difference`: Calculates the risk difference with a score interval.
+//! - `risk_ratio`: Calculates the risk ratio with Katz's interval.
+//! - `odds_ratio`: Calculates the odds ratio with a Woolf, exact or mid-p interval.
+//! - `cmh_test`: Performs the Cochran-Mantel-Haenszel test.
+//! - `mantel_haenszel_or`: Calculates the Mantel-Haenszel common odds ratio.
+//! - `breslow_day`: Performs the Breslow-Day test of homogeneity.
+//! - `fit_loglinear`: Fits a log-linear model and tests it against the saturated model.
+//! - `compare_loglinear`: Compares nested log-linear models.
+//! - `RiskDifferenceMethod`: Newcombe or Miettinen-Nurminen.
+//! - `OddsRatioMethod`: Woolf, exact or mid-p.
+//! - `EffectEstimate`: The estimate and its `TestResult`.
+//! - `ContingencyTable`, `LogLinearModel`, `LogLinearFit`: A multi-way table, a log-linear model
+//!   and its fit.
+//!
+//! ## Example
+//! ```rust
+//! use hypors::contingency::{
+//!     breslow_day, cmh_test, mantel_haenszel_or, odds_ratio, risk_difference, risk_ratio,
+//!     compare_loglinear, fit_loglinear, ContingencyTable, EffectEstimate, LogLinearFit,
+//!     LogLinearModel, OddsRatioMethod, RiskDifferenceMethod,
+//! };
+//! ```
+
+pub(crate) mod hypergeometric;
+pub mod loglinear;
+pub mod measures;
+pub mod stratified;
+pub mod types;
+
+pub use loglinear::{compare_loglinear, fit_loglinear};
+pub use measures::{odds_ratio, risk_difference, risk_ratio};
+pub use stratified::{breslow_day, cmh_test, mantel_haenszel_or};
+pub use types::{
+    ContingencyTable, EffectEstimate, LogLinearFit, LogLinearModel, OddsRatioMethod,
+    RiskDifferenceMethod,
+};
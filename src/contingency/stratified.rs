@@ -0,0 +1,370 @@
+use super::measures::log_wald;
+use super::types::EffectEstimate;
+use crate::common::{StatError, TailType, TestResult, calculate_p};
+use statrs::distribution::ChiSquared;
+
+/// Checks that all strata are tables of the same dimensions with non-negative counts.
+fn check_strata(tables: &[Vec<Vec<f64>>]) -> Result<(usize, usize), StatError> {
+    if tables.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    let rows = tables[0].len();
+    let cols = tables[0].first().map_or(0, |row| row.len());
+    if rows < 2 || cols < 2 {
+        return Err(StatError::ComputeError(
+            "Tables must have at least two rows and columns".into(),
+        ));
+    }
+    if tables
+        .iter()
+        .any(|table| table.len() != rows || table.iter().any(|row| row.len() != cols))
+    {
+        return Err(StatError::ComputeError(
+            "All tables must have the same dimensions".into(),
+        ));
+    }
+    if tables
+        .iter()
+        .flatten()
+        .flatten()
+        .any(|&x| !(x >= 0.0 && x.is_finite()))
+    {
+        return Err(StatError::ComputeError(
+            "Counts must be non-negative".into(),
+        ));
+    }
+    Ok((rows, cols))
+}
+
+/// Extracts the cells `(a, b, c, d)` of each 2x2 stratum with at least two units.
+fn two_by_two_strata(tables: &[Vec<Vec<f64>>]) -> Result<Vec<[f64; 4]>, StatError> {
+    if check_strata(tables)? != (2, 2) {
+        return Err(StatError::ComputeError("Tables must be 2x2".into()));
+    }
+    Ok(tables
+        .iter()
+        .map(|t| [t[0][0], t[0][1], t[1][0], t[1][1]])
+        .filter(|cells| cells.iter().sum::<f64>() >= 2.0)
+        .collect())
+}
+
+/// Solves the linear system `a x = b` by Gaussian elimination with partial pivoting.
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let pivot_row = a[col].clone();
+        for row in (col + 1)..n {
+            let factor = a[row][col] / pivot_row[col];
+            for (x, p) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *x -= factor * p;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = ((row + 1)..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+/// Calculates the Mantel-Haenszel common odds ratio and the Robins-Breslow-Greenland variance
+/// of its logarithm.
+fn mh_odds_ratio(strata: &[[f64; 4]]) -> (f64, f64) {
+    let (mut sum_r, mut sum_s) = (0.0, 0.0);
+    let (mut sum_pr, mut sum_ps_qr, mut sum_qs) = (0.0, 0.0, 0.0);
+    for &[a, b, c, d] in strata {
+        let n = a + b + c + d;
+        let (p, q) = ((a + d) / n, (b + c) / n);
+        let (r, s) = (a * d / n, b * c / n);
+        sum_r += r;
+        sum_s += s;
+        sum_pr += p * r;
+        sum_ps_qr += p * s + q * r;
+        sum_qs += q * s;
+    }
+    let variance = sum_pr / (2.0 * sum_r.powi(2))
+        + sum_ps_qr / (2.0 * sum_r * sum_s)
+        + sum_qs / (2.0 * sum_s.powi(2));
+    (sum_r / sum_s, variance)
+}
+
+/// Performs the Cochran-Mantel-Haenszel test of conditional independence across strata.
+///
+/// Each stratum (e.g. a country) is a table with the same rows and columns. The test compares
+/// the observed counts with their expectations under independence within each stratum, so an
+/// association that differs in size between strata, or a confounder that differs between
+/// strata, does not produce the spurious pooled association of Simpson's paradox.
+///
+/// For 2x2 tables this is the classic Mantel-Haenszel statistic, optionally with a continuity
+/// correction, and the confidence interval is the Robins-Breslow-Greenland interval for the
+/// common odds ratio. For r×c tables it is the generalized statistic for general association
+/// with `(r - 1)(c - 1)` degrees of freedom, and the confidence interval is not applicable.
+/// Strata with fewer than two units carry no information and are skipped.
+///
+/// # Arguments
+///
+/// * `tables` - One table per stratum, all of the same dimensions.
+/// * `correct` - Whether to apply the continuity correction (2x2 tables only).
+/// * `alpha` - The significance level (e.g., 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` with the chi-square statistic, p-value and confidence interval.
+///
+/// # Errors
+///
+/// Returns a `StatError` if there are no strata, the tables differ in dimensions or have
+/// negative counts, or the covariance of the statistic is singular.
+///
+/// # Example
+/// ```rust
+/// use hypors::contingency::cmh_test;
+///
+/// let tables = vec![
+///     vec![vec![0.0, 6.0], vec![0.0, 5.0]],
+///     vec![vec![3.0, 3.0], vec![0.0, 6.0]],
+///     vec![vec![6.0, 0.0], vec![2.0, 4.0]],
+///     vec![vec![5.0, 1.0], vec![6.0, 0.0]],
+///     vec![vec![2.0, 0.0], vec![5.0, 0.0]],
+/// ];
+/// let result = cmh_test(&tables, true, 0.05).unwrap();
+/// assert!((result.test_statistic - 3.9286).abs() < 1e-4);
+/// ```
+pub fn cmh_test(
+    tables: &[Vec<Vec<f64>>],
+    correct: bool,
+    alpha: f64,
+) -> Result<TestResult, StatError> {
+    let (rows, cols) = check_strata(tables)?;
+    let dim = (rows - 1) * (cols - 1);
+
+    // Sum of observed minus expected counts and their covariance over the first (r-1)(c-1) cells
+    let mut deviation = vec![0.0; dim];
+    let mut covariance = vec![vec![0.0; dim]; dim];
+    for table in tables {
+        let n: f64 = table.iter().flatten().sum();
+        if n < 2.0 {
+            continue;
+        }
+        let row_totals: Vec<f64> = table.iter().map(|row| row.iter().sum()).collect();
+        let col_totals: Vec<f64> = (0..cols)
+            .map(|j| table.iter().map(|row| row[j]).sum())
+            .collect();
+        let cell = |k: usize| (k / (cols - 1), k % (cols - 1));
+        for k in 0..dim {
+            let (i, j) = cell(k);
+            deviation[k] += table[i][j] - row_totals[i] * col_totals[j] / n;
+            for (l, cov) in covariance[k].iter_mut().enumerate() {
+                let (i2, j2) = cell(l);
+                let row_term = row_totals[i] * (if i == i2 { n } else { 0.0 } - row_totals[i2]);
+                let col_term = col_totals[j] * (if j == j2 { n } else { 0.0 } - col_totals[j2]);
+                *cov += row_term * col_term / (n.powi(2) * (n - 1.0));
+            }
+        }
+    }
+
+    let test_statistic = if dim == 1 {
+        if covariance[0][0] <= 0.0 {
+            return Err(StatError::ComputeError(
+                "Variance of the statistic is zero".into(),
+            ));
+        }
+        let correction = if correct { 0.5 } else { 0.0 };
+        (deviation[0].abs() - correction).max(0.0).powi(2) / covariance[0][0]
+    } else {
+        let weights = solve(covariance, deviation.clone()).ok_or_else(|| {
+            StatError::ComputeError("Covariance of the statistic is singular".into())
+        })?;
+        deviation.iter().zip(&weights).map(|(d, w)| d * w).sum()
+    };
+
+    let chi_distribution = ChiSquared::new(dim as f64)
+        .map_err(|e| StatError::ComputeError(format!("Chi-squared distribution error: {e}")))?;
+    let p_value = calculate_p(test_statistic, TailType::Right, &chi_distribution);
+
+    let confidence_interval = if dim == 1 {
+        mantel_haenszel_or(tables, alpha)
+            .map(|or| or.result.confidence_interval)
+            .unwrap_or((f64::NAN, f64::NAN))
+    } else {
+        (f64::NAN, f64::NAN)
+    };
+
+    Ok(TestResult {
+        test_statistic,
+        p_value,
+        confidence_interval,
+        null_hypothesis: "H0: Variables are conditionally independent given the strata".into(),
+        alt_hypothesis: "Ha: Variables are associated within strata".into(),
+        reject_null: p_value < alpha,
+    })
+}
+
+/// Calculates the Mantel-Haenszel common odds ratio of stratified 2x2 tables.
+///
+/// The estimate is `Σ(a_k d_k / n_k) / Σ(b_k c_k / n_k)`, and the confidence interval and Wald
+/// test of `ln(OR) = 0` use the Robins-Breslow-Greenland variance, which remains valid with many
+/// sparse strata. Strata with fewer than two units are skipped.
+///
+/// # Arguments
+///
+/// * `tables` - One 2x2 table `[[a, b], [c, d]]` per stratum, with the groups as rows and event
+///   and no event as columns.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+///
+/// # Returns
+///
+/// An `EffectEstimate` with the common odds ratio and a `TestResult` whose test statistic is the
+/// Wald Z-statistic of its logarithm.
+///
+/// # Errors
+///
+/// Returns a `StatError` if there are no strata, a table is not 2x2 or has negative counts, or
+/// the odds ratio is zero or infinite.
+///
+/// # Example
+/// ```rust
+/// use hypors::contingency::mantel_haenszel_or;
+///
+/// let tables = vec![
+///     vec![vec![0.0, 6.0], vec![0.0, 5.0]],
+///     vec![vec![3.0, 3.0], vec![0.0, 6.0]],
+///     vec![vec![6.0, 0.0], vec![2.0, 4.0]],
+///     vec![vec![5.0, 1.0], vec![6.0, 0.0]],
+///     vec![vec![2.0, 0.0], vec![5.0, 0.0]],
+/// ];
+/// let or = mantel_haenszel_or(&tables, 0.05).unwrap();
+/// assert!((or.estimate - 7.0).abs() < 1e-9);
+/// ```
+pub fn mantel_haenszel_or(
+    tables: &[Vec<Vec<f64>>],
+    alpha: f64,
+) -> Result<EffectEstimate, StatError> {
+    let strata = two_by_two_strata(tables)?;
+    let (estimate, variance) = mh_odds_ratio(&strata);
+    if !(estimate > 0.0 && estimate.is_finite()) {
+        return Err(StatError::ComputeError(
+            "Common odds ratio is zero or infinite".into(),
+        ));
+    }
+    let mut result = log_wald(estimate, variance.sqrt(), alpha, "OR")?;
+    result.result.null_hypothesis = "H0: Common OR = 1".into();
+    result.result.alt_hypothesis = "Ha: Common OR ≠ 1".into();
+    Ok(result)
+}
+
+/// Performs the Breslow-Day test of homogeneity of the odds ratios of stratified 2x2 tables.
+///
+/// A common odds ratio summarises the strata only if their odds ratios agree. In each stratum,
+/// the count `a` is compared with its expectation under the Mantel-Haenszel common odds ratio
+/// given the margins, and the squared deviations over their variances are summed. With `K`
+/// informative strata, the statistic is compared with a chi-square distribution with `K - 1`
+/// degrees of freedom. Tarone's correction subtracts `(Σ(a - E))² / ΣVar`, which makes the test
+/// valid with the Mantel-Haenszel rather than the maximum likelihood estimate.
+///
+/// # Arguments
+///
+/// * `tables` - One 2x2 table `[[a, b], [c, d]]` per stratum.
+/// * `tarone` - Whether to apply Tarone's correction.
+/// * `alpha` - The significance level (e.g., 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` with the chi-square statistic and p-value; the confidence interval is not
+/// applicable and returns `(NaN, NaN)`.
+///
+/// # Errors
+///
+/// Returns a `StatError` if there are fewer than two informative strata, a table is not 2x2
+/// or has negative counts, or the common odds ratio is zero or infinite.
+///
+/// # Example
+/// ```rust
+/// use hypors::contingency::breslow_day;
+///
+/// let tables = vec![
+///     vec![vec![20.0, 10.0], vec![10.0, 20.0]],
+///     vec![vec![10.0, 20.0], vec![20.0, 10.0]],
+/// ];
+/// let result = breslow_day(&tables, true, 0.05).unwrap();
+/// assert!(result.reject_null); // Odds ratios of 4 and 1/4 are not homogeneous
+/// ```
+pub fn breslow_day(
+    tables: &[Vec<Vec<f64>>],
+    tarone: bool,
+    alpha: f64,
+) -> Result<TestResult, StatError> {
+    let strata = two_by_two_strata(tables)?;
+    let (odds_ratio, _) = mh_odds_ratio(&strata);
+    if !(odds_ratio > 0.0 && odds_ratio.is_finite()) {
+        return Err(StatError::ComputeError(
+            "Common odds ratio is zero or infinite".into(),
+        ));
+    }
+
+    let (mut statistic, mut sum_deviation, mut sum_variance) = (0.0, 0.0, 0.0);
+    let mut informative = 0;
+    for &[a, b, c, d] in &strata {
+        let (n1, n2, m1) = (a + b, c + d, a + c);
+        let (low, high) = ((m1 - n2).max(0.0), n1.min(m1));
+        if high <= low {
+            continue; // A margin fixes the table
+        }
+
+        // Expected a given the margins: Â(n2 - m1 + Â) = OR (n1 - Â)(m1 - Â)
+        let expected = if (odds_ratio - 1.0).abs() < 1e-12 {
+            n1 * m1 / (n1 + n2)
+        } else {
+            let qa = 1.0 - odds_ratio;
+            let qb = n2 - m1 + odds_ratio * (n1 + m1);
+            let qc = -odds_ratio * n1 * m1;
+            let root = (qb.powi(2) - 4.0 * qa * qc).sqrt();
+            let first = (-qb + root) / (2.0 * qa);
+            if first >= low && first <= high {
+                first
+            } else {
+                (-qb - root) / (2.0 * qa)
+            }
+        };
+        let variance = 1.0
+            / (1.0 / expected
+                + 1.0 / (n1 - expected)
+                + 1.0 / (m1 - expected)
+                + 1.0 / (n2 - m1 + expected));
+
+        statistic += (a - expected).powi(2) / variance;
+        sum_deviation += a - expected;
+        sum_variance += variance;
+        informative += 1;
+    }
+
+    if informative < 2 {
+        return Err(StatError::ComputeError(
+            "At least two informative strata required".into(),
+        ));
+    }
+    if tarone {
+        statistic -= sum_deviation.powi(2) / sum_variance;
+    }
+
+    let chi_distribution = ChiSquared::new((informative - 1) as f64)
+        .map_err(|e| StatError::ComputeError(format!("Chi-squared distribution error: {e}")))?;
+    let p_value = calculate_p(statistic, TailType::Right, &chi_distribution);
+
+    Ok(TestResult {
+        test_statistic: statistic,
+        p_value,
+        confidence_interval: (f64::NAN, f64::NAN),
+        null_hypothesis: "H0: Odds ratios are equal across strata".into(),
+        alt_hypothesis: "Ha: Odds ratios differ across strata".into(),
+        reject_null: p_value < alpha,
+    })
+}
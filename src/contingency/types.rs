@@ -0,0 +1,99 @@
+use crate::common::TestResult;
+use serde::{Deserialize, Serialize};
+
+/// Represents the method used for the confidence interval of a risk difference.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RiskDifferenceMethod {
+    /// Newcombe's hybrid score interval, combining the Wilson intervals of each proportion; the
+    /// test is the pooled two-proportion score test.
+    Newcombe,
+    /// The Miettinen-Nurminen score interval, inverting the score test with restricted maximum
+    /// likelihood variances; the test is the same score test at a difference of zero.
+    MiettinenNurminen,
+}
+
+/// Represents the method used for the confidence interval and test of an odds ratio.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OddsRatioMethod {
+    /// Woolf's logit interval and Wald test for the sample odds ratio.
+    Woolf,
+    /// Exact conditional (Cornfield) interval and test based on the noncentral hypergeometric
+    /// distribution, with the conditional maximum likelihood estimate.
+    Exact,
+    /// The mid-p version of the exact conditional interval and test, which counts half the
+    /// probability of the observed table.
+    MidP,
+}
+
+/// Stores an effect measure of a 2x2 table together with its test.
+///
+/// # Fields
+///
+/// * `estimate` - The point estimate of the effect measure.
+/// * `result` - The test of no effect, with the confidence interval for the effect measure.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EffectEstimate {
+    pub estimate: f64,
+    pub result: TestResult,
+}
+
+/// Stores a multi-way contingency table.
+///
+/// Counts are stored in row-major order: the last variable varies fastest, so the cell
+/// `(i, j, k)` of an `I × J × K` table is at position `(i J + j) K + k`.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::contingency::ContingencyTable;
+///
+/// let table = ContingencyTable::new(vec![2, 2, 2], vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]).unwrap();
+/// assert_eq!(table.get(&[1, 0, 1]), 6.0);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContingencyTable {
+    pub(crate) dims: Vec<usize>,
+    pub(crate) counts: Vec<f64>,
+}
+
+/// Represents a hierarchical log-linear model by its generating class.
+///
+/// Each term lists the variables (by index) of a margin that the fitted counts must reproduce;
+/// the model contains these interactions and all lower-order terms below them. For a three-way
+/// table of variables `A, B, C`, `[[0, 2], [1, 2]]` is the model `(AC, BC)` of conditional
+/// independence of `A` and `B` given `C`.
+///
+/// # Fields
+///
+/// * `terms` - The generating class: the margins fitted by the model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogLinearModel {
+    pub terms: Vec<Vec<usize>>,
+}
+
+/// Stores a log-linear model fitted by iterative proportional fitting.
+///
+/// # Fields
+///
+/// * `model` - The fitted model.
+/// * `fitted` - The fitted count of each cell, in the layout of the table.
+/// * `deviance` - The likelihood-ratio statistic `G² = 2 Σ O ln(O / F)` against the saturated
+///   model.
+/// * `pearson` - Pearson's statistic `X² = Σ (O - F)² / F`.
+/// * `df` - The residual degrees of freedom: the number of cells minus the number of
+///   parameters.
+/// * `iterations` - The number of fitting cycles performed.
+/// * `converged` - Whether the fitted counts converged.
+/// * `result` - The goodness-of-fit test of the model on its deviance; the confidence interval
+///   is not applicable and returns `(NaN, NaN)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogLinearFit {
+    pub model: LogLinearModel,
+    pub fitted: Vec<f64>,
+    pub deviance: f64,
+    pub pearson: f64,
+    pub df: usize,
+    pub iterations: usize,
+    pub converged: bool,
+    pub result: TestResult,
+}
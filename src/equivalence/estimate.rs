@@ -0,0 +1,237 @@
+use crate::common::StatError;
+use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
+
+/// A point estimate with its standard error and reference distribution.
+///
+/// `df` holds the degrees of freedom of a Student's t reference distribution, or `None` when the
+/// standard normal distribution applies.
+pub(crate) struct Estimate {
+    pub estimate: f64,
+    pub std_error: f64,
+    pub df: Option<f64>,
+}
+
+fn collect<I, T>(data: I) -> Vec<f64>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    data.into_iter().map(|x| x.into()).collect()
+}
+
+fn mean_var(sample: &[f64]) -> (f64, f64) {
+    let n = sample.len() as f64;
+    let mean = sample.iter().sum::<f64>() / n;
+    let var = sample.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, var)
+}
+
+fn differences(sample1: &[f64], sample2: &[f64]) -> Result<Vec<f64>, StatError> {
+    if sample1.len() != sample2.len() {
+        return Err(StatError::ComputeError(format!(
+            "Sample sizes must be equal for paired test. Got {} and {}",
+            sample1.len(),
+            sample2.len()
+        )));
+    }
+    Ok(sample1
+        .iter()
+        .zip(sample2.iter())
+        .map(|(x1, x2)| x1 - x2)
+        .collect())
+}
+
+fn check_std(std: f64) -> Result<(), StatError> {
+    if std <= 0.0 {
+        return Err(StatError::ComputeError(format!(
+            "Population standard deviation must be positive, got: {std}"
+        )));
+    }
+    Ok(())
+}
+
+/// Estimates the mean of one sample with a t reference distribution.
+pub(crate) fn t_one_sample<I, T>(data: I) -> Result<Estimate, StatError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    let sample = collect(data);
+    if sample.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    if sample.len() < 2 {
+        return Err(StatError::InsufficientData);
+    }
+    let n = sample.len() as f64;
+    let (mean, var) = mean_var(&sample);
+    Ok(Estimate {
+        estimate: mean,
+        std_error: (var / n).sqrt(),
+        df: Some(n - 1.0),
+    })
+}
+
+/// Estimates the mean paired difference with a t reference distribution.
+pub(crate) fn t_paired<I1, I2, T1, T2>(data1: I1, data2: I2) -> Result<Estimate, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    let diffs = differences(&collect(data1), &collect(data2))?;
+    t_one_sample(diffs)
+}
+
+/// Estimates the difference in means of two independent samples, using either pooled
+/// variances or Welch's approximation.
+pub(crate) fn t_independent<I1, I2, T1, T2>(
+    data1: I1,
+    data2: I2,
+    pooled: bool,
+) -> Result<Estimate, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    let sample1 = collect(data1);
+    let sample2 = collect(data2);
+    if sample1.is_empty() || sample2.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    if sample1.len() < 2 || sample2.len() < 2 {
+        return Err(StatError::InsufficientData);
+    }
+
+    let n1 = sample1.len() as f64;
+    let n2 = sample2.len() as f64;
+    let (mean1, var1) = mean_var(&sample1);
+    let (mean2, var2) = mean_var(&sample2);
+
+    let (std_error, df) = if pooled {
+        let pooled_var = ((n1 - 1.0) * var1 + (n2 - 1.0) * var2) / (n1 + n2 - 2.0);
+        ((pooled_var * (1.0 / n1 + 1.0 / n2)).sqrt(), n1 + n2 - 2.0)
+    } else {
+        let std_error = (var1 / n1 + var2 / n2).sqrt();
+        let df = (var1 / n1 + var2 / n2).powi(2)
+            / ((var1 / n1).powi(2) / (n1 - 1.0) + (var2 / n2).powi(2) / (n2 - 1.0));
+        (std_error, df)
+    };
+
+    Ok(Estimate {
+        estimate: mean1 - mean2,
+        std_error,
+        df: Some(df),
+    })
+}
+
+/// Estimates the mean of one sample with a known population standard deviation.
+pub(crate) fn z_one_sample<I, T>(data: I, pop_std: f64) -> Result<Estimate, StatError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    check_std(pop_std)?;
+    let sample = collect(data);
+    if sample.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    let n = sample.len() as f64;
+    Ok(Estimate {
+        estimate: sample.iter().sum::<f64>() / n,
+        std_error: pop_std / n.sqrt(),
+        df: None,
+    })
+}
+
+/// Estimates the mean paired difference with a known population standard deviation of the
+/// differences.
+pub(crate) fn z_paired<I1, I2, T1, T2>(
+    data1: I1,
+    data2: I2,
+    pop_std_diff: f64,
+) -> Result<Estimate, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    check_std(pop_std_diff)?;
+    let diffs = differences(&collect(data1), &collect(data2))?;
+    z_one_sample(diffs, pop_std_diff)
+}
+
+/// Estimates the difference in means of two independent samples with known population
+/// standard deviations.
+pub(crate) fn z_independent<I1, I2, T1, T2>(
+    data1: I1,
+    data2: I2,
+    pop_std1: f64,
+    pop_std2: f64,
+) -> Result<Estimate, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    check_std(pop_std1)?;
+    check_std(pop_std2)?;
+    let sample1 = collect(data1);
+    let sample2 = collect(data2);
+    if sample1.is_empty() || sample2.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    let n1 = sample1.len() as f64;
+    let n2 = sample2.len() as f64;
+    Ok(Estimate {
+        estimate: sample1.iter().sum::<f64>() / n1 - sample2.iter().sum::<f64>() / n2,
+        std_error: (pop_std1.powi(2) / n1 + pop_std2.powi(2) / n2).sqrt(),
+        df: None,
+    })
+}
+
+/// Estimates the difference of two independent proportions with the unpooled standard error.
+pub(crate) fn prop_independent<I1, I2, T>(data1: I1, data2: I2) -> Result<Estimate, StatError>
+where
+    I1: IntoIterator<Item = T>,
+    I2: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    let sample1 = collect(data1);
+    let sample2 = collect(data2);
+    if sample1.is_empty() || sample2.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    let n1 = sample1.len() as f64;
+    let n2 = sample2.len() as f64;
+    let p1 = sample1.iter().sum::<f64>() / n1;
+    let p2 = sample2.iter().sum::<f64>() / n2;
+    Ok(Estimate {
+        estimate: p1 - p2,
+        std_error: (p1 * (1.0 - p1) / n1 + p2 * (1.0 - p2) / n2).sqrt(),
+        df: None,
+    })
+}
+
+/// Creates the reference distribution of a standardized estimate.
+pub(crate) fn reference_dist(
+    df: Option<f64>,
+) -> Result<Box<dyn ContinuousCDF<f64, f64>>, StatError> {
+    match df {
+        Some(df) => StudentsT::new(0.0, 1.0, df)
+            .map(|d| Box::new(d) as Box<dyn ContinuousCDF<f64, f64>>)
+            .map_err(|e| {
+                StatError::ComputeError(format!("Failed to create StudentsT distribution: {e}"))
+            }),
+        None => Normal::new(0.0, 1.0)
+            .map(|d| Box::new(d) as Box<dyn ContinuousCDF<f64, f64>>)
+            .map_err(|e| {
+                StatError::ComputeError(format!("Failed to create Normal distribution: {e}"))
+            }),
+    }
+}
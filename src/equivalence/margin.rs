@@ -0,0 +1,482 @@
+use crate::common::{StatError, TailType, TestResult, calculate_ci, calculate_p};
+use crate::equivalence::MarginType;
+use crate::equivalence::estimate::{
+    Estimate, prop_independent, reference_dist, t_independent, t_one_sample, t_paired,
+    z_independent, z_one_sample, z_paired,
+};
+
+/// Runs a one-sided test against the null boundary implied by the margin.
+///
+/// `tail` gives the direction in which larger effects are better: `Right` when higher values are
+/// better and `Left` when lower values are better. A non-inferiority test shifts the boundary
+/// by the margin in the unfavourable direction, a superiority test in the favourable one.
+fn margin_test(
+    est: Estimate,
+    center: f64,
+    margin: f64,
+    margin_type: MarginType,
+    tail: TailType,
+    alpha: f64,
+    label: &str,
+) -> Result<TestResult, StatError> {
+    if !(margin.is_finite() && margin >= 0.0) {
+        return Err(StatError::ComputeError(format!(
+            "Margin must be a non-negative finite number, got: {margin}"
+        )));
+    }
+    if est.std_error == 0.0 {
+        return Err(StatError::ComputeError(
+            "Standard error is zero; cannot compute test statistic".to_string(),
+        ));
+    }
+
+    let boundary = match (&margin_type, &tail) {
+        (MarginType::NonInferiority, TailType::Right)
+        | (MarginType::Superiority, TailType::Left) => center - margin,
+        (MarginType::NonInferiority, TailType::Left)
+        | (MarginType::Superiority, TailType::Right) => center + margin,
+        (_, TailType::Two) => {
+            return Err(StatError::ComputeError(
+                "Margin tests are one-sided; use a left or right tail".to_string(),
+            ));
+        }
+    };
+
+    let dist = reference_dist(est.df)?;
+    let test_statistic = (est.estimate - boundary) / est.std_error;
+    let p_value = calculate_p(test_statistic, tail.clone(), dist.as_ref());
+    let confidence_interval = calculate_ci(est.estimate, est.std_error, 2.0 * alpha, dist.as_ref());
+    let reject_null = p_value < alpha;
+
+    let (null_hypothesis, alt_hypothesis) = match tail {
+        TailType::Right => (
+            format!("H0: {label} <= {boundary}"),
+            format!("Ha: {label} > {boundary}"),
+        ),
+        _ => (
+            format!("H0: {label} >= {boundary}"),
+            format!("Ha: {label} < {boundary}"),
+        ),
+    };
+
+    Ok(TestResult {
+        test_statistic,
+        p_value,
+        confidence_interval,
+        null_hypothesis,
+        alt_hypothesis,
+        reject_null,
+    })
+}
+
+/// Performs a one-sample non-inferiority or superiority test using the t-distribution.
+///
+/// # Arguments
+///
+/// * `data` - An iterator containing the sample data (any type that can be converted to f64).
+/// * `pop_mean` - The reference mean.
+/// * `margin` - The non-negative margin, on the scale of the data.
+/// * `margin_type` - Whether to test non-inferiority or superiority.
+/// * `tail` - The direction of improvement: `Right` if higher is better, `Left` if lower is better.
+/// * `alpha` - The one-sided significance level (e.g., 0.025).
+///
+/// # Returns
+///
+/// A `TestResult` struct containing the t-statistic against the shifted boundary, its one-sided
+/// p-value, the `1 - 2 * alpha` confidence interval for the mean, the hypotheses, and whether the
+/// null hypothesis should be rejected.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the data has fewer than two points, the margin is negative, the tail
+/// is `Two`, or the standard error is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::{t_margin, MarginType};
+/// use hypors::common::TailType;
+///
+/// let scores = vec![0.81, 0.79, 0.80, 0.83, 0.78, 0.82, 0.80, 0.81];
+/// let result = t_margin(scores, 0.80, 0.02, MarginType::NonInferiority, TailType::Right, 0.025).unwrap();
+///
+/// assert_eq!(result.null_hypothesis, "H0: µ <= 0.78");
+/// ```
+pub fn t_margin<I, T>(
+    data: I,
+    pop_mean: f64,
+    margin: f64,
+    margin_type: MarginType,
+    tail: TailType,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    margin_test(
+        t_one_sample(data)?,
+        pop_mean,
+        margin,
+        margin_type,
+        tail,
+        alpha,
+        "µ",
+    )
+}
+
+/// Performs a paired non-inferiority or superiority test using the t-distribution.
+///
+/// # Arguments
+///
+/// * `data1` - An iterator containing the treatment measurements.
+/// * `data2` - An iterator containing the paired reference measurements.
+/// * `margin` - The non-negative margin for `µ1 - µ2`.
+/// * `margin_type` - Whether to test non-inferiority or superiority.
+/// * `tail` - The direction of improvement: `Right` if higher is better, `Left` if lower is better.
+/// * `alpha` - The one-sided significance level (e.g., 0.025).
+///
+/// # Returns
+///
+/// A `TestResult` struct, as for `t_margin`, with the confidence interval for the mean difference.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the samples differ in length, have fewer than two pairs, the margin is
+/// negative, the tail is `Two`, or the standard error is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::{t_margin_paired, MarginType};
+/// use hypors::common::TailType;
+///
+/// let new_model = vec![0.91, 0.88, 0.93, 0.90, 0.89, 0.92];
+/// let old_model = vec![0.90, 0.88, 0.92, 0.91, 0.88, 0.91];
+///
+/// let result = t_margin_paired(new_model, old_model, 0.02, MarginType::NonInferiority, TailType::Right, 0.025).unwrap();
+/// assert!(result.reject_null);
+/// ```
+pub fn t_margin_paired<I1, I2, T1, T2>(
+    data1: I1,
+    data2: I2,
+    margin: f64,
+    margin_type: MarginType,
+    tail: TailType,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    margin_test(
+        t_paired(data1, data2)?,
+        0.0,
+        margin,
+        margin_type,
+        tail,
+        alpha,
+        "µ1 - µ2",
+    )
+}
+
+/// Performs an independent two-sample non-inferiority or superiority test using the
+/// t-distribution.
+///
+/// # Arguments
+///
+/// * `data1` - An iterator containing the treatment sample.
+/// * `data2` - An iterator containing the reference sample.
+/// * `margin` - The non-negative margin for `µ1 - µ2`.
+/// * `margin_type` - Whether to test non-inferiority or superiority.
+/// * `tail` - The direction of improvement: `Right` if higher is better, `Left` if lower is better.
+/// * `alpha` - The one-sided significance level (e.g., 0.025).
+/// * `pooled` - Whether to pool variances (true for a standard t-test, false for Welch's t-test).
+///
+/// # Returns
+///
+/// A `TestResult` struct, as for `t_margin`, with the confidence interval for the difference in means.
+///
+/// # Errors
+///
+/// Returns a `StatError` if either sample has fewer than two points, the margin is negative, the
+/// tail is `Two`, or the standard error is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::{t_margin_ind, MarginType};
+/// use hypors::common::TailType;
+///
+/// // Latency in ms: lower is better
+/// let treatment = vec![101.0, 99.0, 100.5, 98.5, 100.0, 99.5, 101.5, 100.0];
+/// let control = vec![100.0, 100.5, 99.0, 101.0, 100.0, 99.5, 100.5, 100.0];
+///
+/// let result = t_margin_ind(treatment, control, 2.0, MarginType::NonInferiority, TailType::Left, 0.025, false).unwrap();
+/// assert_eq!(result.alt_hypothesis, "Ha: µ1 - µ2 < 2");
+/// ```
+pub fn t_margin_ind<I1, I2, T1, T2>(
+    data1: I1,
+    data2: I2,
+    margin: f64,
+    margin_type: MarginType,
+    tail: TailType,
+    alpha: f64,
+    pooled: bool,
+) -> Result<TestResult, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    margin_test(
+        t_independent(data1, data2, pooled)?,
+        0.0,
+        margin,
+        margin_type,
+        tail,
+        alpha,
+        "µ1 - µ2",
+    )
+}
+
+/// Performs a one-sample non-inferiority or superiority test with a known population
+/// standard deviation.
+///
+/// # Arguments
+///
+/// * `data` - An iterator containing the sample data.
+/// * `pop_mean` - The reference mean.
+/// * `pop_std` - The known population standard deviation (must be positive).
+/// * `margin` - The non-negative margin, on the scale of the data.
+/// * `margin_type` - Whether to test non-inferiority or superiority.
+/// * `tail` - The direction of improvement: `Right` if higher is better, `Left` if lower is better.
+/// * `alpha` - The one-sided significance level (e.g., 0.025).
+///
+/// # Returns
+///
+/// A `TestResult` struct, as for `t_margin`, using the standard normal distribution.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the data is empty, the standard deviation is not positive, the margin
+/// is negative, or the tail is `Two`.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::{z_margin, MarginType};
+/// use hypors::common::TailType;
+///
+/// let data = vec![10.4, 10.6, 10.5, 10.7, 10.3, 10.5];
+/// let result = z_margin(data, 10.0, 0.2, 0.2, MarginType::Superiority, TailType::Right, 0.025).unwrap();
+///
+/// assert!(result.reject_null);
+/// ```
+pub fn z_margin<I, T>(
+    data: I,
+    pop_mean: f64,
+    pop_std: f64,
+    margin: f64,
+    margin_type: MarginType,
+    tail: TailType,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    margin_test(
+        z_one_sample(data, pop_std)?,
+        pop_mean,
+        margin,
+        margin_type,
+        tail,
+        alpha,
+        "µ",
+    )
+}
+
+/// Performs a paired non-inferiority or superiority test with a known population standard
+/// deviation of the differences.
+///
+/// # Arguments
+///
+/// * `data1` - An iterator containing the treatment measurements.
+/// * `data2` - An iterator containing the paired reference measurements.
+/// * `pop_std_diff` - The known population standard deviation of the differences (must be positive).
+/// * `margin` - The non-negative margin for `µ1 - µ2`.
+/// * `margin_type` - Whether to test non-inferiority or superiority.
+/// * `tail` - The direction of improvement: `Right` if higher is better, `Left` if lower is better.
+/// * `alpha` - The one-sided significance level (e.g., 0.025).
+///
+/// # Returns
+///
+/// A `TestResult` struct, as for `t_margin`, using the standard normal distribution.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the samples are empty or differ in length, the standard deviation is
+/// not positive, the margin is negative, or the tail is `Two`.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::{z_margin_paired, MarginType};
+/// use hypors::common::TailType;
+///
+/// let after = vec![12.0, 11.5, 13.0, 12.5, 12.0, 11.0];
+/// let before = vec![12.2, 11.4, 13.1, 12.6, 11.9, 11.2];
+///
+/// let result = z_margin_paired(after, before, 0.3, 0.5, MarginType::NonInferiority, TailType::Right, 0.025).unwrap();
+/// assert!(result.reject_null);
+/// ```
+pub fn z_margin_paired<I1, I2, T1, T2>(
+    data1: I1,
+    data2: I2,
+    pop_std_diff: f64,
+    margin: f64,
+    margin_type: MarginType,
+    tail: TailType,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    margin_test(
+        z_paired(data1, data2, pop_std_diff)?,
+        0.0,
+        margin,
+        margin_type,
+        tail,
+        alpha,
+        "µ1 - µ2",
+    )
+}
+
+/// Performs an independent two-sample non-inferiority or superiority test with known
+/// population standard deviations.
+///
+/// # Arguments
+///
+/// * `data1` - An iterator containing the treatment sample.
+/// * `data2` - An iterator containing the reference sample.
+/// * `pop_std1` - The known population standard deviation of the first group (must be positive).
+/// * `pop_std2` - The known population standard deviation of the second group (must be positive).
+/// * `margin` - The non-negative margin for `µ1 - µ2`.
+/// * `margin_type` - Whether to test non-inferiority or superiority.
+/// * `tail` - The direction of improvement: `Right` if higher is better, `Left` if lower is better.
+/// * `alpha` - The one-sided significance level (e.g., 0.025).
+///
+/// # Returns
+///
+/// A `TestResult` struct, as for `t_margin`, using the standard normal distribution.
+///
+/// # Errors
+///
+/// Returns a `StatError` if either sample is empty, a standard deviation is not positive, the
+/// margin is negative, or the tail is `Two`.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::{z_margin_ind, MarginType};
+/// use hypors::common::TailType;
+///
+/// let treatment = vec![5.2, 5.4, 5.1, 5.3, 5.5, 5.2];
+/// let control = vec![5.0, 5.1, 4.9, 5.0, 5.2, 5.0];
+///
+/// let result = z_margin_ind(treatment, control, 0.2, 0.2, 0.1, MarginType::NonInferiority, TailType::Right, 0.025).unwrap();
+/// assert!(result.reject_null);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn z_margin_ind<I1, I2, T1, T2>(
+    data1: I1,
+    data2: I2,
+    pop_std1: f64,
+    pop_std2: f64,
+    margin: f64,
+    margin_type: MarginType,
+    tail: TailType,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    margin_test(
+        z_independent(data1, data2, pop_std1, pop_std2)?,
+        0.0,
+        margin,
+        margin_type,
+        tail,
+        alpha,
+        "µ1 - µ2",
+    )
+}
+
+/// Performs a non-inferiority or superiority test for the difference of two independent
+/// proportions, using the unpooled standard error.
+///
+/// # Arguments
+///
+/// * `data1` - Iterator of binary values for the treatment group (e.g., 0/1).
+/// * `data2` - Iterator of binary values for the reference group.
+/// * `margin` - The non-negative margin for `p1 - p2`, in absolute proportion units.
+/// * `margin_type` - Whether to test non-inferiority or superiority.
+/// * `tail` - The direction of improvement: `Right` if higher is better, `Left` if lower is better.
+/// * `alpha` - The one-sided significance level (e.g., 0.025).
+///
+/// # Returns
+///
+/// A `TestResult` struct, as for `t_margin`, with the confidence interval for the difference in
+/// proportions.
+///
+/// # Errors
+///
+/// Returns a `StatError` if either sample is empty, the margin is negative, the tail is `Two`,
+/// or the standard error is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::{prop_margin_ind, MarginType};
+/// use hypors::common::TailType;
+///
+/// let variant: Vec<u8> = (0..2000).map(|i| (i % 10 < 3) as u8).collect();
+/// let control: Vec<u8> = (0..2000).map(|i| (i % 100 < 31) as u8).collect();
+///
+/// let result = prop_margin_ind(variant, control, 0.05, MarginType::NonInferiority, TailType::Right, 0.025).unwrap();
+/// assert!(result.reject_null);
+/// ```
+pub fn prop_margin_ind<I1, I2, T>(
+    data1: I1,
+    data2: I2,
+    margin: f64,
+    margin_type: MarginType,
+    tail: TailType,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    I1: IntoIterator<Item = T>,
+    I2: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    margin_test(
+        prop_independent(data1, data2)?,
+        0.0,
+        margin,
+        margin_type,
+        tail,
+        alpha,
+        "p1 - p2",
+    )
+}
@@ -0,0 +1,56 @@
+//! # Equivalence and Non-Inferiority Tests
+//!
+//! The `equivalence` module provides two one-sided tests (TOST) for equivalence and
+//! margin-based non-inferiority and superiority tests.
+//!
+//! The tests in `t`, `z` and `proportion` test a point null hypothesis, so a non-significant
+//! result does not show that two means are the same. Equivalence tests reverse the hypotheses:
+//! the null hypothesis is that the difference lies outside the equivalence bounds, and rejecting
+//! it demonstrates that the difference is within them.
+//!
+//! ## Equivalence Bounds and Margins
+//!
+//! - TOST functions take a `lower` and `upper` bound, which may be asymmetric. Symmetric bounds are
+//!   given as `(-margin, margin)`.
+//! - Margin functions take a non-negative `margin`, a `MarginType` and a `TailType` giving the
+//!   direction of improvement (`Right` when higher is better, `Left` when lower is better).
+//!
+//! All functions report the `1 - 2 * alpha` confidence interval, matching the decision of the
+//! one-sided tests.
+//!
+//! ## Submodules
+//!
+//! - `tost`: Contains the two one-sided tests for equivalence.
+//! - `margin`: Contains the non-inferiority and superiority tests.
+//! - `types`: Defines the `MarginType` enumeration.
+//!
+//! ## Exports
+//!
+//! The following functions and types are made available for use:
+//!
+//! - `t_tost`, `t_tost_paired`, `t_tost_ind`: Equivalence tests for means using the t-distribution.
+//! - `z_tost`, `z_tost_paired`, `z_tost_ind`: Equivalence tests for means with known standard deviations.
+//! - `prop_tost_ind`: Equivalence test for two independent proportions.
+//! - `t_margin`, `t_margin_paired`, `t_margin_ind`: Margin tests for means using the t-distribution.
+//! - `z_margin`, `z_margin_paired`, `z_margin_ind`: Margin tests for means with known standard deviations.
+//! - `prop_margin_ind`: Margin test for two independent proportions.
+//! - `MarginType`: Non-inferiority or superiority.
+//!
+//! ## Example
+//! ```rust
+//! use hypors::equivalence::{t_tost, t_tost_ind, t_tost_paired, t_margin_ind, prop_tost_ind, MarginType};
+//! ```
+
+pub(crate) mod estimate;
+pub mod margin;
+pub mod tost;
+pub mod types;
+
+pub use margin::{
+    prop_margin_ind, t_margin, t_margin_ind, t_margin_paired, z_margin, z_margin_ind,
+    z_margin_paired,
+};
+pub use tost::{
+    prop_tost_ind, t_tost, t_tost_ind, t_tost_paired, z_tost, z_tost_ind, z_tost_paired,
+};
+pub use types::MarginType;
@@ -0,0 +1,431 @@
+use crate::common::{StatError, TailType, TestResult, calculate_ci, calculate_p};
+use crate::equivalence::estimate::{
+    Estimate, prop_independent, reference_dist, t_independent, t_one_sample, t_paired,
+    z_independent, z_one_sample, z_paired,
+};
+
+/// Runs the two one-sided tests against the equivalence bounds `center + lower` and `center + upper`.
+///
+/// The p-value is the larger of the two one-sided p-values, and the confidence interval is the
+/// `1 - 2 * alpha` interval, which lies within the bounds exactly when equivalence is concluded.
+fn tost(
+    est: Estimate,
+    center: f64,
+    lower: f64,
+    upper: f64,
+    alpha: f64,
+    label: &str,
+) -> Result<TestResult, StatError> {
+    if lower >= upper {
+        return Err(StatError::ComputeError(format!(
+            "Lower equivalence bound must be below the upper bound, got: ({lower}, {upper})"
+        )));
+    }
+    if est.std_error == 0.0 {
+        return Err(StatError::ComputeError(
+            "Standard error is zero; cannot compute test statistic".to_string(),
+        ));
+    }
+
+    let low = center + lower;
+    let high = center + upper;
+    let dist = reference_dist(est.df)?;
+
+    let t_lower = (est.estimate - low) / est.std_error;
+    let t_upper = (est.estimate - high) / est.std_error;
+    let p_lower = calculate_p(t_lower, TailType::Right, dist.as_ref());
+    let p_upper = calculate_p(t_upper, TailType::Left, dist.as_ref());
+
+    let (test_statistic, p_value) = if p_lower >= p_upper {
+        (t_lower, p_lower)
+    } else {
+        (t_upper, p_upper)
+    };
+
+    let confidence_interval = calculate_ci(est.estimate, est.std_error, 2.0 * alpha, dist.as_ref());
+    let reject_null = p_value < alpha;
+
+    Ok(TestResult {
+        test_statistic,
+        p_value,
+        confidence_interval,
+        null_hypothesis: format!("H0: {label} <= {low} or {label} >= {high}"),
+        alt_hypothesis: format!("Ha: {low} < {label} < {high}"),
+        reject_null,
+    })
+}
+
+/// Performs a one-sample equivalence test (TOST) using the t-distribution.
+///
+/// Equivalence is concluded when the mean lies within `(pop_mean + lower, pop_mean + upper)`.
+/// Symmetric margins are obtained with `lower = -margin` and `upper = margin`.
+///
+/// # Arguments
+///
+/// * `data` - An iterator containing the sample data (any type that can be converted to f64).
+/// * `pop_mean` - The reference mean the equivalence bounds are centred on.
+/// * `lower` - The lower equivalence bound, relative to `pop_mean` (negative).
+/// * `upper` - The upper equivalence bound, relative to `pop_mean` (positive).
+/// * `alpha` - The significance level of each one-sided test (e.g., 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` struct containing the less significant of the two one-sided statistics, its
+/// p-value, the `1 - 2 * alpha` confidence interval for the mean, the hypotheses, and whether
+/// non-equivalence is rejected.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the data is empty or has fewer than two points, the bounds are not
+/// ordered, or the standard error is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::t_tost;
+///
+/// let data = vec![10.1, 9.9, 10.0, 10.2, 9.8, 10.1, 9.9, 10.0];
+/// let result = t_tost(data, 10.0, -0.5, 0.5, 0.05).unwrap();
+///
+/// assert!(result.reject_null); // The mean is equivalent to 10 within ±0.5
+/// ```
+pub fn t_tost<I, T>(
+    data: I,
+    pop_mean: f64,
+    lower: f64,
+    upper: f64,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    tost(t_one_sample(data)?, pop_mean, lower, upper, alpha, "µ")
+}
+
+/// Performs a paired equivalence test (TOST) using the t-distribution.
+///
+/// Equivalence is concluded when the mean paired difference lies within `(lower, upper)`.
+///
+/// # Arguments
+///
+/// * `data1` - An iterator containing the first set of sample data.
+/// * `data2` - An iterator containing the second set of sample data.
+/// * `lower` - The lower equivalence bound for `µ1 - µ2` (negative).
+/// * `upper` - The upper equivalence bound for `µ1 - µ2` (positive).
+/// * `alpha` - The significance level of each one-sided test (e.g., 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` struct, as for `t_tost`, with the confidence interval for the mean difference.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the samples differ in length, have fewer than two pairs, the bounds
+/// are not ordered, or the standard error is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::t_tost_paired;
+///
+/// let before = vec![120.0, 135.0, 140.0, 125.0, 130.0, 128.0];
+/// let after = vec![121.0, 134.0, 141.0, 124.0, 131.0, 128.5];
+///
+/// let result = t_tost_paired(before, after, -2.0, 2.0, 0.05).unwrap();
+/// assert!(result.reject_null);
+/// ```
+pub fn t_tost_paired<I1, I2, T1, T2>(
+    data1: I1,
+    data2: I2,
+    lower: f64,
+    upper: f64,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    tost(t_paired(data1, data2)?, 0.0, lower, upper, alpha, "µ1 - µ2")
+}
+
+/// Performs an independent two-sample equivalence test (TOST) using the t-distribution.
+///
+/// Equivalence is concluded when the difference in means lies within `(lower, upper)`.
+///
+/// # Arguments
+///
+/// * `data1` - An iterator containing the first set of sample data.
+/// * `data2` - An iterator containing the second set of sample data.
+/// * `lower` - The lower equivalence bound for `µ1 - µ2` (negative).
+/// * `upper` - The upper equivalence bound for `µ1 - µ2` (positive).
+/// * `alpha` - The significance level of each one-sided test (e.g., 0.05).
+/// * `pooled` - Whether to pool variances (true for a standard t-test, false for Welch's t-test).
+///
+/// # Returns
+///
+/// A `TestResult` struct, as for `t_tost`, with the confidence interval for the difference in means.
+///
+/// # Errors
+///
+/// Returns a `StatError` if either sample has fewer than two points, the bounds are not
+/// ordered, or the standard error is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::t_tost_ind;
+///
+/// let before_refactor = vec![5.1, 4.9, 5.0, 5.2, 4.8, 5.1, 5.0, 4.9];
+/// let after_refactor = vec![5.0, 5.1, 4.9, 5.0, 5.1, 4.9, 5.0, 5.2];
+///
+/// let result = t_tost_ind(before_refactor, after_refactor, -0.3, 0.3, 0.05, false).unwrap();
+/// assert!(result.reject_null);
+/// ```
+pub fn t_tost_ind<I1, I2, T1, T2>(
+    data1: I1,
+    data2: I2,
+    lower: f64,
+    upper: f64,
+    alpha: f64,
+    pooled: bool,
+) -> Result<TestResult, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    tost(
+        t_independent(data1, data2, pooled)?,
+        0.0,
+        lower,
+        upper,
+        alpha,
+        "µ1 - µ2",
+    )
+}
+
+/// Performs a one-sample equivalence test (TOST) with a known population standard deviation.
+///
+/// # Arguments
+///
+/// * `data` - An iterator containing the sample data.
+/// * `pop_mean` - The reference mean the equivalence bounds are centred on.
+/// * `pop_std` - The known population standard deviation (must be positive).
+/// * `lower` - The lower equivalence bound, relative to `pop_mean` (negative).
+/// * `upper` - The upper equivalence bound, relative to `pop_mean` (positive).
+/// * `alpha` - The significance level of each one-sided test (e.g., 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` struct, as for `t_tost`, using the standard normal distribution.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the data is empty, the standard deviation is not positive, or the
+/// bounds are not ordered.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::z_tost;
+///
+/// let data = vec![99.8, 100.2, 100.1, 99.9, 100.0, 100.3, 99.7, 100.1];
+/// let result = z_tost(data, 100.0, 0.3, -0.5, 0.5, 0.05).unwrap();
+///
+/// assert!(result.reject_null);
+/// ```
+pub fn z_tost<I, T>(
+    data: I,
+    pop_mean: f64,
+    pop_std: f64,
+    lower: f64,
+    upper: f64,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    tost(
+        z_one_sample(data, pop_std)?,
+        pop_mean,
+        lower,
+        upper,
+        alpha,
+        "µ",
+    )
+}
+
+/// Performs a paired equivalence test (TOST) with a known population standard deviation of
+/// the differences.
+///
+/// # Arguments
+///
+/// * `data1` - An iterator containing the first set of sample data.
+/// * `data2` - An iterator containing the second set of sample data.
+/// * `pop_std_diff` - The known population standard deviation of the differences (must be positive).
+/// * `lower` - The lower equivalence bound for `µ1 - µ2` (negative).
+/// * `upper` - The upper equivalence bound for `µ1 - µ2` (positive).
+/// * `alpha` - The significance level of each one-sided test (e.g., 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` struct, as for `t_tost`, using the standard normal distribution.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the samples are empty or differ in length, the standard deviation is
+/// not positive, or the bounds are not ordered.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::z_tost_paired;
+///
+/// let before = vec![120.0, 118.0, 125.0, 122.0, 130.0, 128.0];
+/// let after = vec![119.5, 118.5, 124.0, 122.5, 130.5, 127.0];
+///
+/// let result = z_tost_paired(before, after, 1.0, -2.0, 2.0, 0.05).unwrap();
+/// assert!(result.reject_null);
+/// ```
+pub fn z_tost_paired<I1, I2, T1, T2>(
+    data1: I1,
+    data2: I2,
+    pop_std_diff: f64,
+    lower: f64,
+    upper: f64,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    tost(
+        z_paired(data1, data2, pop_std_diff)?,
+        0.0,
+        lower,
+        upper,
+        alpha,
+        "µ1 - µ2",
+    )
+}
+
+/// Performs an independent two-sample equivalence test (TOST) with known population
+/// standard deviations.
+///
+/// # Arguments
+///
+/// * `data1` - An iterator containing the first set of sample data.
+/// * `data2` - An iterator containing the second set of sample data.
+/// * `pop_std1` - The known population standard deviation of the first group (must be positive).
+/// * `pop_std2` - The known population standard deviation of the second group (must be positive).
+/// * `lower` - The lower equivalence bound for `µ1 - µ2` (negative).
+/// * `upper` - The upper equivalence bound for `µ1 - µ2` (positive).
+/// * `alpha` - The significance level of each one-sided test (e.g., 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` struct, as for `t_tost`, using the standard normal distribution.
+///
+/// # Errors
+///
+/// Returns a `StatError` if either sample is empty, a standard deviation is not positive, or the
+/// bounds are not ordered.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::z_tost_ind;
+///
+/// let group1 = vec![5.1, 4.9, 5.0, 5.2, 4.8, 5.1, 5.0, 4.9];
+/// let group2 = vec![5.0, 5.1, 4.9, 5.0, 5.1, 4.9, 5.0, 5.2];
+///
+/// let result = z_tost_ind(group1, group2, 0.15, 0.15, -0.3, 0.3, 0.05).unwrap();
+/// assert!(result.reject_null);
+/// ```
+pub fn z_tost_ind<I1, I2, T1, T2>(
+    data1: I1,
+    data2: I2,
+    pop_std1: f64,
+    pop_std2: f64,
+    lower: f64,
+    upper: f64,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    tost(
+        z_independent(data1, data2, pop_std1, pop_std2)?,
+        0.0,
+        lower,
+        upper,
+        alpha,
+        "µ1 - µ2",
+    )
+}
+
+/// Performs an equivalence test (TOST) for the difference of two independent proportions.
+///
+/// The unpooled standard error is used, since the null hypotheses do not assume equal
+/// proportions.
+///
+/// # Arguments
+///
+/// * `data1` - Iterator of binary values for the first group (e.g., 0/1).
+/// * `data2` - Iterator of binary values for the second group.
+/// * `lower` - The lower equivalence bound for `p1 - p2` (negative).
+/// * `upper` - The upper equivalence bound for `p1 - p2` (positive).
+/// * `alpha` - The significance level of each one-sided test (e.g., 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` struct, as for `t_tost`, with the confidence interval for the difference in
+/// proportions.
+///
+/// # Errors
+///
+/// Returns a `StatError` if either sample is empty, the bounds are not ordered, or the standard
+/// error is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::equivalence::prop_tost_ind;
+///
+/// let control: Vec<u8> = (0..1000).map(|i| (i % 10 < 3) as u8).collect();
+/// let variant: Vec<u8> = (0..1000).map(|i| (i % 100 < 31) as u8).collect();
+///
+/// let result = prop_tost_ind(control, variant, -0.05, 0.05, 0.05).unwrap();
+/// assert!(result.reject_null);
+/// ```
+pub fn prop_tost_ind<I1, I2, T>(
+    data1: I1,
+    data2: I2,
+    lower: f64,
+    upper: f64,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    I1: IntoIterator<Item = T>,
+    I2: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    tost(
+        prop_independent(data1, data2)?,
+        0.0,
+        lower,
+        upper,
+        alpha,
+        "p1 - p2",
+    )
+}
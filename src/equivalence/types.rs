@@ -0,0 +1,8 @@
+/// Represents the type of margin-based hypothesis being tested.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarginType {
+    /// Non-inferiority test (used for showing the treatment is not worse than the reference by more than the margin).
+    NonInferiority,
+    /// Superiority test (used for showing the treatment is better than the reference by more than the margin).
+    Superiority,
+}
@@ -0,0 +1,332 @@
+use super::correction::adjust_p_values;
+use super::lift::lift_interval;
+use super::ratio::ratio_test_ind;
+use super::types::{
+    Correction, Experiment, LiftMethod, Metric, MetricData, MetricKind, MetricOutcome,
+    MetricResult, MetricRole, Scorecard,
+};
+use crate::common::{StatError, TailType, TestResult};
+use crate::mann_whitney::u_test;
+use crate::proportion::z_test_ind;
+use crate::t::t_test_ind;
+
+/// The comparison of a variant with the control on one metric.
+struct Comparison {
+    control_value: f64,
+    variant_value: f64,
+    lift: f64,
+    lift_interval: (f64, f64),
+    test: TestResult,
+}
+
+/// Calculates the mean of a sample and the variance of that mean.
+fn mean_moments(values: &[f64], binary: bool) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = if binary {
+        mean * (1.0 - mean)
+    } else {
+        values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+    };
+    (mean, variance / n)
+}
+
+/// Compares two means, with the delta-method lift of the variant over the control.
+fn compare_means(
+    variant: &[f64],
+    control: &[f64],
+    test: TestResult,
+    binary: bool,
+    alpha: f64,
+) -> Comparison {
+    let (m1, v1) = mean_moments(variant, binary);
+    let (m2, v2) = mean_moments(control, binary);
+    let (lift, lift_interval) = lift_interval(m1, v1, m2, v2, &LiftMethod::Delta, alpha)
+        .unwrap_or((f64::NAN, (f64::NAN, f64::NAN)));
+    Comparison {
+        control_value: m2,
+        variant_value: m1,
+        lift,
+        lift_interval,
+        test,
+    }
+}
+
+fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Compares a variant with the control on one metric.
+///
+/// The relative lift uses the delta method; it is `NaN` for rank metrics and when the control
+/// estimate is zero.
+fn compare(
+    metric: &Metric,
+    variant: &MetricData,
+    control: &MetricData,
+    alpha: f64,
+) -> Result<Comparison, StatError> {
+    match (&metric.kind, variant, control) {
+        (
+            MetricKind::Ratio,
+            MetricData::Ratio {
+                numerator: num1,
+                denominator: den1,
+            },
+            MetricData::Ratio {
+                numerator: num2,
+                denominator: den2,
+            },
+        ) => {
+            let result = ratio_test_ind(
+                num1.iter().copied(),
+                den1.iter().copied(),
+                num2.iter().copied(),
+                den2.iter().copied(),
+                TailType::Two,
+                alpha,
+            )?;
+            Ok(Comparison {
+                control_value: result.ratio2,
+                variant_value: result.ratio1,
+                lift: result.relative_difference,
+                lift_interval: result.relative_confidence_interval,
+                test: result.result,
+            })
+        }
+        (MetricKind::Binary, MetricData::Values(data1), MetricData::Values(data2)) => {
+            let test = z_test_ind(
+                data1.iter().copied(),
+                data2.iter().copied(),
+                TailType::Two,
+                alpha,
+                true,
+            )?;
+            Ok(compare_means(data1, data2, test, true, alpha))
+        }
+        (MetricKind::Continuous, MetricData::Values(data1), MetricData::Values(data2)) => {
+            let test = t_test_ind(
+                data1.iter().copied(),
+                data2.iter().copied(),
+                TailType::Two,
+                alpha,
+                false,
+            )?;
+            Ok(compare_means(data1, data2, test, false, alpha))
+        }
+        (MetricKind::Rank, MetricData::Values(data1), MetricData::Values(data2)) => {
+            if data1.is_empty() || data2.is_empty() {
+                return Err(StatError::EmptyData);
+            }
+            let test = u_test(
+                data1.iter().copied(),
+                data2.iter().copied(),
+                alpha,
+                TailType::Two,
+            )
+            .map_err(StatError::ComputeError)?;
+            Ok(Comparison {
+                control_value: median(data2),
+                variant_value: median(data1),
+                lift: f64::NAN,
+                lift_interval: (f64::NAN, f64::NAN),
+                test,
+            })
+        }
+        _ => Err(StatError::ComputeError(format!(
+            "Data for metric '{}' does not match its kind",
+            metric.name
+        ))),
+    }
+}
+
+impl Experiment {
+    /// Creates an experiment with no metrics or data.
+    ///
+    /// # Arguments
+    ///
+    /// * `control` - The name of the control variant, which every other variant is compared
+    ///   with.
+    /// * `alpha` - The significance level (e.g., 0.05).
+    /// * `correction` - The multiple-comparison correction applied within each metric role.
+    pub fn new(control: &str, alpha: f64, correction: Correction) -> Self {
+        Experiment {
+            control: control.to_string(),
+            alpha,
+            correction,
+            variants: vec![control.to_string()],
+            metrics: Vec::new(),
+            data: Vec::new(),
+        }
+    }
+
+    /// Adds a metric, replacing any metric with the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the metric.
+    /// * `kind` - The kind of metric, which determines the test.
+    /// * `role` - The role of the metric in the decision.
+    /// * `higher_is_better` - Whether an increase is an improvement.
+    pub fn add_metric(
+        &mut self,
+        name: &str,
+        kind: MetricKind,
+        role: MetricRole,
+        higher_is_better: bool,
+    ) -> &mut Self {
+        let metric = Metric {
+            name: name.to_string(),
+            kind,
+            role,
+            higher_is_better,
+        };
+        match self.metrics.iter_mut().find(|m| m.name == name) {
+            Some(existing) => *existing = metric,
+            None => self.metrics.push(metric),
+        }
+        self
+    }
+
+    /// Adds the observations of a metric in a variant, replacing any earlier observations.
+    ///
+    /// Variants are created when their first data is added, and are reported in that order.
+    ///
+    /// # Arguments
+    ///
+    /// * `variant` - The name of the variant.
+    /// * `metric` - The name of the metric.
+    /// * `data` - The observations, matching the kind of the metric.
+    pub fn add_data(&mut self, variant: &str, metric: &str, data: MetricData) -> &mut Self {
+        if !self.variants.iter().any(|v| v == variant) {
+            self.variants.push(variant.to_string());
+        }
+        match self
+            .data
+            .iter_mut()
+            .find(|(v, m, _)| v == variant && m == metric)
+        {
+            Some(existing) => existing.2 = data,
+            None => self
+                .data
+                .push((variant.to_string(), metric.to_string(), data)),
+        }
+        self
+    }
+
+    fn data_for(&self, variant: &str, metric: &str) -> Result<&MetricData, StatError> {
+        self.data
+            .iter()
+            .find(|(v, m, _)| v == variant && m == metric)
+            .map(|(_, _, data)| data)
+            .ok_or_else(|| {
+                StatError::ComputeError(format!(
+                    "No data for metric '{metric}' in variant '{variant}'"
+                ))
+            })
+    }
+
+    /// Compares every variant with the control on every metric and builds the scorecard.
+    ///
+    /// Each comparison is two-sided, with the difference taken as variant minus control. The
+    /// p-values are corrected separately for the primary, guardrail and secondary metrics, each
+    /// family spanning all variants; a metric improved or degraded when its adjusted p-value is
+    /// below `alpha`.
+    ///
+    /// # Returns
+    ///
+    /// A `Scorecard` with one `MetricResult` per variant and metric.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StatError` if there are no metrics or no variants besides the control, data
+    /// is missing or does not match a metric's kind, or a test fails.
+    pub fn analyze(&self) -> Result<Scorecard, StatError> {
+        if self.metrics.is_empty() || self.variants.len() < 2 {
+            return Err(StatError::ComputeError(
+                "At least one metric and one variant besides the control required".into(),
+            ));
+        }
+
+        let mut results = Vec::new();
+        for variant in self.variants.iter().filter(|v| **v != self.control) {
+            for metric in &self.metrics {
+                let comparison = compare(
+                    metric,
+                    self.data_for(variant, &metric.name)?,
+                    self.data_for(&self.control, &metric.name)?,
+                    self.alpha,
+                )?;
+                let confidence_interval = match metric.kind {
+                    MetricKind::Rank => (f64::NAN, f64::NAN),
+                    _ => comparison.test.confidence_interval,
+                };
+                results.push(MetricResult {
+                    metric: metric.name.clone(),
+                    variant: variant.clone(),
+                    kind: metric.kind.clone(),
+                    role: metric.role.clone(),
+                    control_value: comparison.control_value,
+                    variant_value: comparison.variant_value,
+                    difference: comparison.variant_value - comparison.control_value,
+                    confidence_interval,
+                    relative_difference: comparison.lift,
+                    relative_confidence_interval: comparison.lift_interval,
+                    p_value: comparison.test.p_value,
+                    adjusted_p_value: comparison.test.p_value,
+                    outcome: MetricOutcome::Inconclusive,
+                });
+            }
+        }
+
+        for role in [
+            MetricRole::Primary,
+            MetricRole::Guardrail,
+            MetricRole::Secondary,
+        ] {
+            let family: Vec<usize> = (0..results.len())
+                .filter(|&i| results[i].role == role)
+                .collect();
+            let p_values: Vec<f64> = family.iter().map(|&i| results[i].p_value).collect();
+            let adjusted = adjust_p_values(&p_values, self.correction.clone());
+            for (&i, adjusted_p) in family.iter().zip(adjusted) {
+                results[i].adjusted_p_value = adjusted_p;
+            }
+        }
+
+        for result in results.iter_mut() {
+            let higher_is_better = self
+                .metrics
+                .iter()
+                .find(|m| m.name == result.metric)
+                .is_some_and(|m| m.higher_is_better);
+            if result.adjusted_p_value < self.alpha && result.difference != 0.0 {
+                let increased = result.difference > 0.0;
+                result.outcome = if increased == higher_is_better {
+                    MetricOutcome::Improved
+                } else {
+                    MetricOutcome::Degraded
+                };
+            }
+        }
+
+        let guardrails_passed = !results
+            .iter()
+            .any(|r| r.role == MetricRole::Guardrail && r.outcome == MetricOutcome::Degraded);
+
+        Ok(Scorecard {
+            control: self.control.clone(),
+            alpha: self.alpha,
+            correction: self.correction.clone(),
+            results,
+            guardrails_passed,
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use super::types::Correction;
+
+/// Adjusts a family of p-values for multiple comparisons.
+///
+/// Adjusted p-values are compared directly with the unadjusted significance level, and are
+/// capped at 1.
+///
+/// # Arguments
+///
+/// * `p_values` - The unadjusted p-values of the family.
+/// * `correction` - The correction to apply.
+///
+/// # Returns
+///
+/// The adjusted p-values, in the same order as `p_values`.
+///
+/// # Example
+/// ```rust
+/// use hypors::experiment::{adjust_p_values, Correction};
+///
+/// let adjusted = adjust_p_values(&[0.01, 0.04, 0.03], Correction::Holm);
+/// assert!((adjusted[0] - 0.03).abs() < 1e-12);
+/// assert!((adjusted[1] - 0.06).abs() < 1e-12 && (adjusted[2] - 0.06).abs() < 1e-12);
+/// ```
+pub fn adjust_p_values(p_values: &[f64], correction: Correction) -> Vec<f64> {
+    let m = p_values.len();
+    let mut order: Vec<usize> = (0..m).collect();
+    order.sort_by(|&a, &b| p_values[a].total_cmp(&p_values[b]));
+
+    let mut adjusted = vec![0.0; m];
+    match correction {
+        Correction::None => adjusted.copy_from_slice(p_values),
+        Correction::Bonferroni => {
+            for (a, &p) in adjusted.iter_mut().zip(p_values) {
+                *a = (p * m as f64).min(1.0);
+            }
+        }
+        Correction::Holm => {
+            // Step down from the smallest p-value, keeping adjusted values monotone
+            let mut running: f64 = 0.0;
+            for (rank, &i) in order.iter().enumerate() {
+                running = running.max((p_values[i] * (m - rank) as f64).min(1.0));
+                adjusted[i] = running;
+            }
+        }
+        Correction::BenjaminiHochberg => {
+            // Step up from the largest p-value, keeping adjusted values monotone
+            let mut running: f64 = 1.0;
+            for (rank, &i) in order.iter().enumerate().rev() {
+                running = running.min(p_values[i] * m as f64 / (rank + 1) as f64);
+                adjusted[i] = running;
+            }
+        }
+    }
+    adjusted
+}
@@ -0,0 +1,134 @@
+use super::types::CupedResult;
+use crate::common::{StatError, TailType};
+use crate::t::t_test_ind;
+
+/// Compares two means with CUPED (Controlled-experiment Using Pre-Experiment Data) regression
+/// adjustment.
+///
+/// Each outcome is adjusted by its pre-experiment covariate, `y - θ (x - x̄)`, where
+/// `θ = Cov(y, x) / Var(x)` and `x̄` are estimated on the data of both groups pooled. Because the
+/// covariate is measured before assignment, the adjustment leaves the expected difference
+/// unchanged while removing the variance the covariate explains. The adjusted means are compared
+/// with Welch's t-test.
+///
+/// # Arguments
+///
+/// * `outcome1` - The outcomes of the first group.
+/// * `covariate1` - The pre-experiment covariates of the first group, paired with `outcome1`.
+/// * `outcome2` - The outcomes of the second group.
+/// * `covariate2` - The pre-experiment covariates of the second group, paired with `outcome2`.
+/// * `tail` - The type of tail (left, right, or two) for the test.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+///
+/// # Returns
+///
+/// A `CupedResult` with the estimated `θ`, the adjusted difference, its standard error, the
+/// variance reduction and the `TestResult` of the adjusted comparison.
+///
+/// # Errors
+///
+/// Returns a `StatError` if a group has fewer than two observations, the outcome and covariate
+/// lengths differ, or the covariate is constant.
+///
+/// # Example
+/// ```rust
+/// use hypors::experiment::cuped_test;
+/// use hypors::common::TailType;
+///
+/// let pre1 = vec![10.0, 12.0, 9.0, 15.0, 11.0, 13.0];
+/// let post1 = vec![11.2, 13.1, 10.3, 16.0, 12.4, 14.1];
+/// let pre2 = vec![11.0, 9.0, 14.0, 12.0, 10.0, 13.0];
+/// let post2 = vec![11.3, 9.4, 14.2, 12.1, 10.5, 13.2];
+///
+/// let result = cuped_test(post1, pre1, post2, pre2, TailType::Two, 0.05).unwrap();
+/// assert!(result.variance_reduction > 0.9);
+/// assert!(result.result.reject_null);
+/// ```
+pub fn cuped_test<I1, I2, I3, I4, T>(
+    outcome1: I1,
+    covariate1: I2,
+    outcome2: I3,
+    covariate2: I4,
+    tail: TailType,
+    alpha: f64,
+) -> Result<CupedResult, StatError>
+where
+    I1: IntoIterator<Item = T>,
+    I2: IntoIterator<Item = T>,
+    I3: IntoIterator<Item = T>,
+    I4: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    let outcome1: Vec<f64> = outcome1.into_iter().map(|x| x.into()).collect();
+    let covariate1: Vec<f64> = covariate1.into_iter().map(|x| x.into()).collect();
+    let outcome2: Vec<f64> = outcome2.into_iter().map(|x| x.into()).collect();
+    let covariate2: Vec<f64> = covariate2.into_iter().map(|x| x.into()).collect();
+    if outcome1.is_empty() || outcome2.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    if outcome1.len() < 2 || outcome2.len() < 2 {
+        return Err(StatError::InsufficientData);
+    }
+    if outcome1.len() != covariate1.len() || outcome2.len() != covariate2.len() {
+        return Err(StatError::ComputeError(
+            "Outcome and covariate must have the same length".to_string(),
+        ));
+    }
+
+    // Estimate θ on the pooled data
+    let outcomes: Vec<f64> = outcome1.iter().chain(&outcome2).copied().collect();
+    let covariates: Vec<f64> = covariate1.iter().chain(&covariate2).copied().collect();
+    let n = outcomes.len() as f64;
+    let mean_y = outcomes.iter().sum::<f64>() / n;
+    let mean_x = covariates.iter().sum::<f64>() / n;
+    let var_x = covariates.iter().map(|x| (x - mean_x).powi(2)).sum::<f64>() / (n - 1.0);
+    let cov_xy = outcomes
+        .iter()
+        .zip(&covariates)
+        .map(|(y, x)| (y - mean_y) * (x - mean_x))
+        .sum::<f64>()
+        / (n - 1.0);
+    if var_x <= 0.0 {
+        return Err(StatError::ComputeError(
+            "Covariate has zero variance".to_string(),
+        ));
+    }
+    let theta = cov_xy / var_x;
+
+    let adjust = |outcome: &[f64], covariate: &[f64]| -> Vec<f64> {
+        outcome
+            .iter()
+            .zip(covariate)
+            .map(|(y, x)| y - theta * (x - mean_x))
+            .collect()
+    };
+    let adjusted1 = adjust(&outcome1, &covariate1);
+    let adjusted2 = adjust(&outcome2, &covariate2);
+
+    let variance = |values: &[f64]| {
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (values.len() as f64 - 1.0)
+    };
+    let (n1, n2) = (adjusted1.len() as f64, adjusted2.len() as f64);
+    let std_error = (variance(&adjusted1) / n1 + variance(&adjusted2) / n2).sqrt();
+    let adjusted_difference =
+        adjusted1.iter().sum::<f64>() / n1 - adjusted2.iter().sum::<f64>() / n2;
+
+    let adjusted_all: Vec<f64> = adjusted1.iter().chain(&adjusted2).copied().collect();
+    let raw_variance = variance(&outcomes);
+    let variance_reduction = if raw_variance > 0.0 {
+        1.0 - variance(&adjusted_all) / raw_variance
+    } else {
+        0.0
+    };
+
+    let result = t_test_ind(adjusted1, adjusted2, tail, alpha, false)?;
+
+    Ok(CupedResult {
+        theta,
+        adjusted_difference,
+        std_error,
+        variance_reduction,
+        result,
+    })
+}
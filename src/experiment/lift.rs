@@ -0,0 +1,292 @@
+use super::ratio::{ratio_moments, ratio_test_ind};
+use super::types::{LiftMethod, LiftResult};
+use crate::common::{StatError, TailType};
+use crate::proportion::z_test_ind;
+use crate::t::t_test_ind;
+use statrs::distribution::{ContinuousCDF, Normal};
+
+/// Calculates the relative lift `m1 / m2 - 1` of two independent estimates and its confidence
+/// interval.
+///
+/// # Arguments
+///
+/// * `m1`, `v1` - The estimate of group 1 and its variance.
+/// * `m2`, `v2` - The estimate of group 2 and its variance.
+/// * `method` - The method used for the confidence interval.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+///
+/// # Returns
+///
+/// A tuple `(lift, (lower, upper))`.
+///
+/// # Errors
+///
+/// Returns a `StatError` if `m2` is zero, or either estimate is not positive for the log-ratio
+/// method.
+pub(crate) fn lift_interval(
+    m1: f64,
+    v1: f64,
+    m2: f64,
+    v2: f64,
+    method: &LiftMethod,
+    alpha: f64,
+) -> Result<(f64, (f64, f64)), StatError> {
+    if m2 == 0.0 {
+        return Err(StatError::ComputeError(
+            "Estimate of the second group must be nonzero for a relative lift".to_string(),
+        ));
+    }
+    let z = Normal::new(0.0, 1.0)
+        .map_err(|e| StatError::ComputeError(format!("Failed to create Normal distribution: {e}")))?
+        .inverse_cdf(1.0 - alpha / 2.0);
+    let ratio = m1 / m2;
+
+    let (lower, upper) = match method {
+        LiftMethod::Delta => {
+            let std_error = (v1 / m2.powi(2) + m1.powi(2) * v2 / m2.powi(4)).sqrt();
+            (ratio - z * std_error, ratio + z * std_error)
+        }
+        LiftMethod::Fieller => {
+            // Roots of (m1 - ρ m2)² = z² (v1 + ρ² v2)
+            let a = m2.powi(2) - z.powi(2) * v2;
+            let discriminant = (m1 * m2).powi(2) - a * (m1.powi(2) - z.powi(2) * v1);
+            if a <= 0.0 || discriminant < 0.0 {
+                (f64::NEG_INFINITY, f64::INFINITY)
+            } else {
+                let root = discriminant.sqrt();
+                ((m1 * m2 - root) / a, (m1 * m2 + root) / a)
+            }
+        }
+        LiftMethod::LogRatio => {
+            if m1 <= 0.0 || m2 <= 0.0 {
+                return Err(StatError::ComputeError(
+                    "Log-ratio interval requires positive estimates".to_string(),
+                ));
+            }
+            let std_error = (v1 / m1.powi(2) + v2 / m2.powi(2)).sqrt();
+            (
+                ratio * (-z * std_error).exp(),
+                ratio * (z * std_error).exp(),
+            )
+        }
+    };
+
+    Ok((ratio - 1.0, (lower - 1.0, upper - 1.0)))
+}
+
+/// Estimates the relative lift in means of two independent samples.
+///
+/// The lift is `mean1 / mean2 - 1`, so group 2 is the baseline (e.g. the control). The absolute
+/// difference is tested with Welch's t-test, and the lift interval uses normal critical values.
+///
+/// # Arguments
+///
+/// * `data1` - An iterator containing the first sample (e.g. the treatment).
+/// * `data2` - An iterator containing the second sample (e.g. the control).
+/// * `method` - The method used for the lift confidence interval.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+///
+/// # Returns
+///
+/// A `LiftResult` with the lift, its confidence interval and the absolute `TestResult`.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the t-test fails, the baseline mean is zero, or the log-ratio
+/// method is used with a non-positive mean.
+///
+/// # Example
+/// ```rust
+/// use hypors::experiment::{relative_lift_mean, LiftMethod};
+///
+/// let treatment = vec![10.4, 10.9, 10.1, 11.0, 10.6, 10.8];
+/// let control = vec![10.0, 10.2, 9.8, 10.3, 9.9, 10.1];
+///
+/// let result = relative_lift_mean(treatment, control, LiftMethod::Fieller, 0.05).unwrap();
+/// println!("Lift: {:+.1}% ({:.1}% to {:.1}%)", 100.0 * result.lift,
+///     100.0 * result.confidence_interval.0, 100.0 * result.confidence_interval.1);
+/// assert!(result.confidence_interval.0 > 0.0);
+/// ```
+pub fn relative_lift_mean<I1, I2, T1, T2>(
+    data1: I1,
+    data2: I2,
+    method: LiftMethod,
+    alpha: f64,
+) -> Result<LiftResult, StatError>
+where
+    I1: IntoIterator<Item = T1>,
+    I2: IntoIterator<Item = T2>,
+    T1: Into<f64>,
+    T2: Into<f64>,
+{
+    let sample1: Vec<f64> = data1.into_iter().map(|x| x.into()).collect();
+    let sample2: Vec<f64> = data2.into_iter().map(|x| x.into()).collect();
+    let absolute = t_test_ind(
+        sample1.clone(),
+        sample2.clone(),
+        TailType::Two,
+        alpha,
+        false,
+    )?;
+
+    let moments = |sample: &[f64]| {
+        let n = sample.len() as f64;
+        let mean = sample.iter().sum::<f64>() / n;
+        let variance = sample.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        (mean, variance / n)
+    };
+    let (m1, v1) = moments(&sample1);
+    let (m2, v2) = moments(&sample2);
+    let (lift, confidence_interval) = lift_interval(m1, v1, m2, v2, &method, alpha)?;
+
+    Ok(LiftResult {
+        lift,
+        confidence_interval,
+        method,
+        absolute,
+    })
+}
+
+/// Estimates the relative lift in proportions of two independent samples of binary outcomes.
+///
+/// The lift is `p1 / p2 - 1`, so group 2 is the baseline (e.g. the control). The absolute
+/// difference is tested with the unpooled two-proportion Z-test, whose confidence interval is
+/// the Wald interval for `p1 - p2`.
+///
+/// # Arguments
+///
+/// * `data1` - Iterator of binary values for the first group (e.g. the treatment).
+/// * `data2` - Iterator of binary values for the second group (e.g. the control).
+/// * `method` - The method used for the lift confidence interval.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+///
+/// # Returns
+///
+/// A `LiftResult` with the lift, its confidence interval and the absolute `TestResult`.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the Z-test fails, the baseline proportion is zero, or the
+/// log-ratio method is used with a zero proportion.
+///
+/// # Example
+/// ```rust
+/// use hypors::experiment::{relative_lift_proportion, LiftMethod};
+///
+/// let treatment: Vec<f64> = (0..1000).map(|i| if i < 130 { 1.0 } else { 0.0 }).collect();
+/// let control: Vec<f64> = (0..1000).map(|i| if i < 100 { 1.0 } else { 0.0 }).collect();
+///
+/// let result = relative_lift_proportion(treatment, control, LiftMethod::LogRatio, 0.05).unwrap();
+/// assert!((result.lift - 0.3).abs() < 1e-12);
+/// ```
+pub fn relative_lift_proportion<I1, I2, T>(
+    data1: I1,
+    data2: I2,
+    method: LiftMethod,
+    alpha: f64,
+) -> Result<LiftResult, StatError>
+where
+    I1: IntoIterator<Item = T>,
+    I2: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    let sample1: Vec<f64> = data1.into_iter().map(|x| x.into()).collect();
+    let sample2: Vec<f64> = data2.into_iter().map(|x| x.into()).collect();
+    let absolute = z_test_ind(
+        sample1.clone(),
+        sample2.clone(),
+        TailType::Two,
+        alpha,
+        false,
+    )?;
+
+    let moments = |sample: &[f64]| {
+        let n = sample.len() as f64;
+        let p = sample.iter().sum::<f64>() / n;
+        (p, p * (1.0 - p) / n)
+    };
+    let (p1, v1) = moments(&sample1);
+    let (p2, v2) = moments(&sample2);
+    let (lift, confidence_interval) = lift_interval(p1, v1, p2, v2, &method, alpha)?;
+
+    Ok(LiftResult {
+        lift,
+        confidence_interval,
+        method,
+        absolute,
+    })
+}
+
+/// Estimates the relative lift of two ratio metrics, such as revenue per session.
+///
+/// The lift is `R1 / R2 - 1`, so group 2 is the baseline (e.g. the control). Each ratio's
+/// variance comes from the delta method, and the absolute difference is tested with
+/// `ratio_test_ind`.
+///
+/// # Arguments
+///
+/// * `numerator1`, `denominator1` - The per-unit numerator and denominator sums of group 1.
+/// * `numerator2`, `denominator2` - The per-unit numerator and denominator sums of group 2.
+/// * `method` - The method used for the lift confidence interval.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+///
+/// # Returns
+///
+/// A `LiftResult` with the lift, its confidence interval and the absolute `TestResult`.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the ratio test fails or the log-ratio method is used with a
+/// non-positive ratio.
+///
+/// # Example
+/// ```rust
+/// use hypors::experiment::{relative_lift_ratio, LiftMethod};
+///
+/// let revenue1 = vec![12.0, 0.0, 30.0, 8.0, 15.0, 22.0];
+/// let sessions1 = vec![3.0, 1.0, 5.0, 2.0, 3.0, 4.0];
+/// let revenue2 = vec![10.0, 5.0, 18.0, 0.0, 9.0, 14.0];
+/// let sessions2 = vec![3.0, 2.0, 4.0, 1.0, 3.0, 4.0];
+///
+/// let result = relative_lift_ratio(revenue1, sessions1, revenue2, sessions2, LiftMethod::Delta, 0.05).unwrap();
+/// assert!(result.lift > 0.0);
+/// ```
+pub fn relative_lift_ratio<I1, I2, I3, I4, T>(
+    numerator1: I1,
+    denominator1: I2,
+    numerator2: I3,
+    denominator2: I4,
+    method: LiftMethod,
+    alpha: f64,
+) -> Result<LiftResult, StatError>
+where
+    I1: IntoIterator<Item = T>,
+    I2: IntoIterator<Item = T>,
+    I3: IntoIterator<Item = T>,
+    I4: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    let numerator1: Vec<f64> = numerator1.into_iter().map(|x| x.into()).collect();
+    let denominator1: Vec<f64> = denominator1.into_iter().map(|x| x.into()).collect();
+    let numerator2: Vec<f64> = numerator2.into_iter().map(|x| x.into()).collect();
+    let denominator2: Vec<f64> = denominator2.into_iter().map(|x| x.into()).collect();
+    let absolute = ratio_test_ind(
+        numerator1.iter().copied(),
+        denominator1.iter().copied(),
+        numerator2.iter().copied(),
+        denominator2.iter().copied(),
+        TailType::Two,
+        alpha,
+    )?
+    .result;
+    let (r1, v1) = ratio_moments(&numerator1, &denominator1)?;
+    let (r2, v2) = ratio_moments(&numerator2, &denominator2)?;
+    let (lift, confidence_interval) = lift_interval(r1, v1, r2, v2, &method, alpha)?;
+
+    Ok(LiftResult {
+        lift,
+        confidence_interval,
+        method,
+        absolute,
+    })
+}
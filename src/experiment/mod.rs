@@ -0,0 +1,96 @@
+//! # Online Experiment Analysis
+//!
+//! The `experiment` module provides analyses that are specific to online controlled
+//! experiments (A/B tests), built on the tests in `t`, `z` and `proportion`.
+//!
+//! ## Variance Reduction
+//!
+//! - **CUPED**: Adjusts each outcome by a pre-experiment covariate with a regression coefficient
+//!   estimated on the pooled data, which shrinks the variance of the difference in means by the
+//!   squared correlation between outcome and covariate.
+//!
+//! ## Ratio Metrics
+//!
+//! - **Delta method**: Compares metrics such as revenue per session, whose numerator and
+//!   denominator are both summed per randomization unit, with delta-method variances of the
+//!   absolute and relative differences.
+//!
+//! ## Relative Lift
+//!
+//! Relative lifts `m1 / m2 - 1` of means, proportions and ratio metrics are reported with the
+//! test of the absolute difference, with confidence intervals from the delta method, Fieller's
+//! theorem or the log-ratio transform.
+//!
+//! ## Sample Ratio Mismatch
+//!
+//! - **Fixed-horizon**: Compares assignment counts with the intended allocation using the
+//!   chi-square goodness-of-fit test or the exact multinomial test.
+//! - **Sequential**: A Dirichlet-multinomial mixture Bayes factor gives an always-valid p-value
+//!   for checking the counts continuously while the experiment runs.
+//!
+//! ## Experiment Analysis
+//!
+//! An `Experiment` holds named variants and metrics (binary, continuous, rank and ratio), each
+//! with a role (primary, guardrail or secondary). `Experiment::analyze` compares every variant
+//! with the control using `proportion::z_test_ind`, `t::t_test_ind`, `mann_whitney::u_test` or
+//! `ratio_test_ind`, corrects the p-values for multiple comparisons within each role, and
+//! returns a serde-serialisable `Scorecard` with absolute and relative differences.
+//!
+//! ## Submodules
+//!
+//! - `analysis`: Contains the comparison of variants and the scorecard.
+//! - `correction`: Contains the multiple-comparison corrections.
+//!
+//! - `cuped`: Contains the covariate-adjusted comparison of means.
+//! - `lift`: Contains the relative lift estimates.
+//! - `ratio`: Contains the delta-method comparison of ratio metrics.
+//! - `srm`: Contains the sample ratio mismatch checks.
+//! - `types`: Defines the result types.
+//!
+//! ## Exports
+//!
+//! The following functions and types are made available for use:
+//!
+//! - `cuped_test`: Compares two means after CUPED adjustment.
+//! - `ratio_test_ind`: Compares two ratio metrics with the delta method.
+//! - `relative_lift_mean`, `relative_lift_proportion`, `relative_lift_ratio`: Estimate the
+//!   relative lift with a confidence interval.
+//! - `LiftMethod`, `LiftResult`: The lift interval method and result.
+//! - `srm_test`: Checks assignment counts against the intended allocation.
+//! - `SequentialSrm`: Checks assignment counts continuously with an always-valid p-value.
+//! - `SrmMethod`: Chi-square or exact multinomial p-values.
+//! - `adjust_p_values`: Adjusts a family of p-values for multiple comparisons.
+//! - `Experiment`: The variants, metrics and observations of an experiment.
+//! - `Metric`, `MetricKind`, `MetricRole`, `MetricData`: Describe a metric and its observations.
+//! - `Correction`: Bonferroni, Holm or Benjamini-Hochberg correction.
+//! - `Scorecard`, `MetricResult`, `MetricOutcome`: The analysis of an experiment.
+//! - `CupedResult`: The adjusted difference, its standard error and test result.
+//! - `RatioResult`: The ratios, their absolute and relative differences and test result.
+//!
+//! ## Example
+//! ```rust
+//! use hypors::experiment::{
+//!     adjust_p_values, cuped_test, ratio_test_ind, relative_lift_mean, relative_lift_proportion,
+//!     relative_lift_ratio, srm_test, Correction, CupedResult, Experiment, LiftMethod, LiftResult,
+//!     Metric, MetricData, MetricKind, MetricOutcome, MetricResult, MetricRole, RatioResult,
+//!     Scorecard, SequentialSrm, SrmMethod,
+//! };
+//! ```
+
+pub mod analysis;
+pub mod correction;
+pub mod cuped;
+pub mod lift;
+pub mod ratio;
+pub mod srm;
+pub mod types;
+
+pub use correction::adjust_p_values;
+pub use cuped::cuped_test;
+pub use lift::{relative_lift_mean, relative_lift_proportion, relative_lift_ratio};
+pub use ratio::ratio_test_ind;
+pub use srm::srm_test;
+pub use types::{
+    Correction, CupedResult, Experiment, LiftMethod, LiftResult, Metric, MetricData, MetricKind,
+    MetricOutcome, MetricResult, MetricRole, RatioResult, Scorecard, SequentialSrm, SrmMethod,
+};
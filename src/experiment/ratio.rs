@@ -0,0 +1,171 @@
+use super::lift::lift_interval;
+use super::types::{LiftMethod, RatioResult};
+use crate::common::{StatError, TailType, TestResult, calculate_ci, calculate_p};
+use statrs::distribution::Normal;
+
+/// Calculates a ratio metric and the delta-method variance of its estimate.
+///
+/// With per-unit numerators `y` and denominators `x`, the ratio `R = ȳ / x̄` has approximate
+/// variance `(s_y² - 2 R s_xy + R² s_x²) / (n x̄²)`.
+///
+/// # Returns
+///
+/// A tuple `(ratio, variance)`.
+///
+/// # Errors
+///
+/// Returns a `StatError` if there are fewer than two units, the lengths differ, or the mean
+/// denominator is zero.
+pub(crate) fn ratio_moments(
+    numerator: &[f64],
+    denominator: &[f64],
+) -> Result<(f64, f64), StatError> {
+    if numerator.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    if numerator.len() < 2 {
+        return Err(StatError::InsufficientData);
+    }
+    if numerator.len() != denominator.len() {
+        return Err(StatError::ComputeError(
+            "Numerator and denominator must have the same length".to_string(),
+        ));
+    }
+
+    let n = numerator.len() as f64;
+    let mean_y = numerator.iter().sum::<f64>() / n;
+    let mean_x = denominator.iter().sum::<f64>() / n;
+    if mean_x == 0.0 {
+        return Err(StatError::ComputeError(
+            "Mean denominator must be nonzero".to_string(),
+        ));
+    }
+
+    let var_y = numerator.iter().map(|y| (y - mean_y).powi(2)).sum::<f64>() / (n - 1.0);
+    let var_x = denominator
+        .iter()
+        .map(|x| (x - mean_x).powi(2))
+        .sum::<f64>()
+        / (n - 1.0);
+    let cov_xy = numerator
+        .iter()
+        .zip(denominator)
+        .map(|(y, x)| (y - mean_y) * (x - mean_x))
+        .sum::<f64>()
+        / (n - 1.0);
+
+    let ratio = mean_y / mean_x;
+    let variance = (var_y - 2.0 * ratio * cov_xy + ratio.powi(2) * var_x) / (n * mean_x.powi(2));
+    Ok((ratio, variance))
+}
+
+/// Compares two ratio metrics, such as revenue per session, using the delta method.
+///
+/// The unit of randomization (e.g. the user) is the unit of analysis: each group supplies the
+/// per-unit sums of the numerator and of the denominator. Treating the denominator units (e.g.
+/// sessions) as independent would understate the variance, since the units within a user are
+/// correlated. The delta method gives the variance of each ratio, of their difference and of
+/// their relative difference, and the difference is tested with a Z-test.
+///
+/// # Arguments
+///
+/// * `numerator1` - The per-unit numerator sums of the first group.
+/// * `denominator1` - The per-unit denominator sums of the first group, paired with
+///   `numerator1`.
+/// * `numerator2` - The per-unit numerator sums of the second group.
+/// * `denominator2` - The per-unit denominator sums of the second group, paired with
+///   `numerator2`.
+/// * `tail` - The type of tail (left, right, or two) for the test.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+///
+/// # Returns
+///
+/// A `RatioResult` with both ratios, the absolute and relative differences, the confidence
+/// interval for the relative difference and the `TestResult` of the absolute difference.
+///
+/// # Errors
+///
+/// Returns a `StatError` if a group has fewer than two units, the numerator and denominator
+/// lengths differ, or a mean denominator is zero.
+///
+/// # Example
+/// ```rust
+/// use hypors::experiment::ratio_test_ind;
+/// use hypors::common::TailType;
+///
+/// let revenue1 = vec![12.0, 0.0, 30.0, 8.0, 15.0, 22.0];
+/// let sessions1 = vec![3.0, 1.0, 5.0, 2.0, 3.0, 4.0];
+/// let revenue2 = vec![10.0, 5.0, 18.0, 0.0, 9.0, 14.0];
+/// let sessions2 = vec![3.0, 2.0, 4.0, 1.0, 3.0, 4.0];
+///
+/// let result = ratio_test_ind(revenue1, sessions1, revenue2, sessions2, TailType::Two, 0.05).unwrap();
+/// assert!((result.ratio1 - 87.0 / 18.0).abs() < 1e-12);
+/// assert!(result.relative_difference > 0.0);
+/// ```
+pub fn ratio_test_ind<I1, I2, I3, I4, T>(
+    numerator1: I1,
+    denominator1: I2,
+    numerator2: I3,
+    denominator2: I4,
+    tail: TailType,
+    alpha: f64,
+) -> Result<RatioResult, StatError>
+where
+    I1: IntoIterator<Item = T>,
+    I2: IntoIterator<Item = T>,
+    I3: IntoIterator<Item = T>,
+    I4: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    let numerator1: Vec<f64> = numerator1.into_iter().map(|x| x.into()).collect();
+    let denominator1: Vec<f64> = denominator1.into_iter().map(|x| x.into()).collect();
+    let numerator2: Vec<f64> = numerator2.into_iter().map(|x| x.into()).collect();
+    let denominator2: Vec<f64> = denominator2.into_iter().map(|x| x.into()).collect();
+    let (ratio1, var1) = ratio_moments(&numerator1, &denominator1)?;
+    let (ratio2, var2) = ratio_moments(&numerator2, &denominator2)?;
+    if ratio2 == 0.0 {
+        return Err(StatError::ComputeError(
+            "Ratio of the second group must be nonzero for the relative difference".to_string(),
+        ));
+    }
+
+    let difference = ratio1 - ratio2;
+    let std_error = (var1 + var2).sqrt();
+    let test_statistic = difference / std_error;
+
+    let z_dist = Normal::new(0.0, 1.0).map_err(|e| {
+        StatError::ComputeError(format!("Failed to create Normal distribution: {e}"))
+    })?;
+    let p_value = calculate_p(test_statistic, tail.clone(), &z_dist);
+    let confidence_interval = calculate_ci(difference, std_error, alpha, &z_dist);
+
+    let (relative_difference, relative_confidence_interval) =
+        lift_interval(ratio1, var1, ratio2, var2, &LiftMethod::Delta, alpha)?;
+
+    let null_hypothesis = match tail {
+        TailType::Left => "H0: R1 >= R2".to_string(),
+        TailType::Right => "H0: R1 <= R2".to_string(),
+        TailType::Two => "H0: R1 = R2".to_string(),
+    };
+    let alt_hypothesis = match tail {
+        TailType::Left => "Ha: R1 < R2".to_string(),
+        TailType::Right => "Ha: R1 > R2".to_string(),
+        TailType::Two => "Ha: R1 ≠ R2".to_string(),
+    };
+
+    Ok(RatioResult {
+        ratio1,
+        ratio2,
+        difference,
+        relative_difference,
+        relative_confidence_interval,
+        result: TestResult {
+            test_statistic,
+            p_value,
+            confidence_interval,
+            null_hypothesis,
+            alt_hypothesis,
+            reject_null: p_value < alpha,
+        },
+    })
+}
@@ -0,0 +1,185 @@
+use super::types::{SequentialSrm, SrmMethod};
+use crate::chi_square::exact::multinomial_exact_p;
+use crate::chi_square::goodness_of_fit;
+use crate::common::{StatError, TestResult};
+use statrs::function::gamma::ln_gamma;
+
+const NULL_HYPOTHESIS: &str = "H0: Assignment counts match the intended allocation";
+const ALT_HYPOTHESIS: &str = "Ha: Assignment counts do not match the intended allocation";
+
+/// Normalizes allocation weights to probabilities.
+fn allocation_probs(weights: &[f64]) -> Result<Vec<f64>, StatError> {
+    if weights.len() < 2 {
+        return Err(StatError::ComputeError(
+            "At least two variants required".into(),
+        ));
+    }
+    if weights.iter().any(|&w| !(w > 0.0 && w.is_finite())) {
+        return Err(StatError::ComputeError(
+            "Allocation weights must be positive".into(),
+        ));
+    }
+    let total: f64 = weights.iter().sum();
+    Ok(weights.iter().map(|w| w / total).collect())
+}
+
+fn check_counts(observed: &[f64], num_variants: usize) -> Result<(), StatError> {
+    if observed.len() != num_variants {
+        return Err(StatError::ComputeError(
+            "Observed counts and allocation weights lengths must match".into(),
+        ));
+    }
+    if observed.iter().any(|&x| !(x >= 0.0 && x.is_finite())) {
+        return Err(StatError::ComputeError(
+            "Observed counts must be non-negative".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Checks for a sample ratio mismatch (SRM) between observed assignment counts and the
+/// intended allocation.
+///
+/// A mismatch signals a bug in assignment or logging, which biases every metric comparison, so
+/// experiments usually require a very small `alpha` (e.g., 0.001) before results are distrusted.
+/// The chi-square method applies `chi_square::goodness_of_fit` to the expected counts implied by
+/// the weights; the exact method sums the multinomial probabilities of every assignment no more
+/// likely than the observed one.
+///
+/// # Arguments
+///
+/// * `observed` - The number of units assigned to each variant.
+/// * `weights` - The intended allocation weights, in the same order (e.g., `[1.0, 1.0]` for
+///   50/50); they need not sum to 1.
+/// * `method` - How to compute the p-value.
+/// * `alpha` - The significance level for the check.
+///
+/// # Returns
+///
+/// A `TestResult` with the chi-square statistic and the p-value of the chosen method; the
+/// confidence interval is not applicable and returns `(NaN, NaN)`.
+///
+/// # Errors
+///
+/// Returns a `StatError` if there are fewer than two variants, the lengths differ, a weight is
+/// not positive, a count is negative, or the exact test has too many outcomes to enumerate.
+///
+/// # Example
+/// ```rust
+/// use hypors::experiment::{srm_test, SrmMethod};
+///
+/// let result = srm_test(&[5120.0, 4880.0], &[1.0, 1.0], SrmMethod::ChiSquare, 0.001).unwrap();
+/// assert!((result.test_statistic - 5.76).abs() < 1e-9);
+/// assert!(!result.reject_null);
+/// ```
+pub fn srm_test(
+    observed: &[f64],
+    weights: &[f64],
+    method: SrmMethod,
+    alpha: f64,
+) -> Result<TestResult, StatError> {
+    let probs = allocation_probs(weights)?;
+    check_counts(observed, probs.len())?;
+    let total: f64 = observed.iter().sum();
+    if total <= 0.0 {
+        return Err(StatError::EmptyData);
+    }
+
+    let expected: Vec<f64> = probs.iter().map(|p| p * total).collect();
+    let mut result = goodness_of_fit(observed.iter().copied(), expected, alpha)?;
+    if method == SrmMethod::ExactMultinomial {
+        result.p_value = multinomial_exact_p(observed, &probs)?;
+        result.reject_null = result.p_value < alpha;
+    }
+    result.null_hypothesis = NULL_HYPOTHESIS.into();
+    result.alt_hypothesis = ALT_HYPOTHESIS.into();
+    Ok(result)
+}
+
+impl SequentialSrm {
+    /// Creates a sequential sample ratio mismatch check.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - The intended allocation weights; they need not sum to 1.
+    /// * `alpha` - The significance level, controlled over all checks.
+    /// * `concentration` - The total concentration of the Dirichlet mixing distribution, which
+    ///   is centred on the intended allocation; larger values are more sensitive to small
+    ///   mismatches (e.g., 100).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StatError` if there are fewer than two variants, a weight is not positive,
+    /// `alpha` is not between 0 and 1, or the concentration is not positive.
+    pub fn new(weights: &[f64], alpha: f64, concentration: f64) -> Result<Self, StatError> {
+        let probs = allocation_probs(weights)?;
+        if alpha <= 0.0 || alpha >= 1.0 {
+            return Err(StatError::ComputeError(
+                "Alpha must be between 0 and 1".to_string(),
+            ));
+        }
+        if !(concentration > 0.0 && concentration.is_finite()) {
+            return Err(StatError::ComputeError(
+                "Concentration must be positive".to_string(),
+            ));
+        }
+        Ok(SequentialSrm {
+            counts: vec![0.0; probs.len()],
+            probs,
+            alpha,
+            concentration,
+            p_value: 1.0,
+        })
+    }
+
+    /// Adds a batch of assignment counts and checks the cumulative counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `counts` - The number of units newly assigned to each variant.
+    ///
+    /// # Returns
+    ///
+    /// A `TestResult` whose test statistic is the Bayes factor against the intended allocation
+    /// and whose p-value is the always-valid p-value; the confidence interval is not applicable
+    /// and returns `(NaN, NaN)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StatError` if the number of counts differs from the number of variants or a
+    /// count is negative.
+    pub fn update(&mut self, counts: &[f64]) -> Result<TestResult, StatError> {
+        check_counts(counts, self.probs.len())?;
+        for (total, count) in self.counts.iter_mut().zip(counts) {
+            *total += count;
+        }
+
+        let n: f64 = self.counts.iter().sum();
+        let log_bf = ln_gamma(self.concentration) - ln_gamma(self.concentration + n)
+            + self
+                .counts
+                .iter()
+                .zip(&self.probs)
+                .map(|(&x, &p)| {
+                    let prior = self.concentration * p;
+                    ln_gamma(prior + x) - ln_gamma(prior) - x * p.ln()
+                })
+                .sum::<f64>();
+        let bayes_factor = log_bf.exp();
+        self.p_value = self.p_value.min(1.0 / bayes_factor);
+
+        Ok(TestResult {
+            test_statistic: bayes_factor,
+            p_value: self.p_value,
+            confidence_interval: (f64::NAN, f64::NAN),
+            null_hypothesis: NULL_HYPOTHESIS.into(),
+            alt_hypothesis: ALT_HYPOTHESIS.into(),
+            reject_null: self.p_value < self.alpha,
+        })
+    }
+
+    /// Returns the cumulative assignment counts.
+    pub fn counts(&self) -> &[f64] {
+        &self.counts
+    }
+}
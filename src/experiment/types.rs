@@ -0,0 +1,266 @@
+use crate::common::TestResult;
+use serde::{Deserialize, Serialize};
+
+/// Stores the result of a CUPED covariate-adjusted comparison of two means.
+///
+/// # Fields
+///
+/// * `theta` - The regression coefficient of the outcome on the covariate, estimated on the
+///   pooled data.
+/// * `adjusted_difference` - The difference in adjusted means (group 1 minus group 2).
+/// * `std_error` - The standard error of the adjusted difference.
+/// * `variance_reduction` - The fraction of the outcome variance removed by the adjustment.
+/// * `result` - The test of the adjusted difference, with its confidence interval and p-value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CupedResult {
+    pub theta: f64,
+    pub adjusted_difference: f64,
+    pub std_error: f64,
+    pub variance_reduction: f64,
+    pub result: TestResult,
+}
+
+/// Stores the result of a delta-method comparison of two ratio metrics.
+///
+/// # Fields
+///
+/// * `ratio1` - The ratio of the summed numerators to the summed denominators in group 1.
+/// * `ratio2` - The same ratio in group 2.
+/// * `difference` - The absolute difference `ratio1 - ratio2`.
+/// * `relative_difference` - The relative difference `ratio1 / ratio2 - 1`.
+/// * `relative_confidence_interval` - The delta-method confidence interval for the relative
+///   difference.
+/// * `result` - The test of the absolute difference, with its confidence interval and p-value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RatioResult {
+    pub ratio1: f64,
+    pub ratio2: f64,
+    pub difference: f64,
+    pub relative_difference: f64,
+    pub relative_confidence_interval: (f64, f64),
+    pub result: TestResult,
+}
+
+/// Represents the method used to compute the p-value of a sample ratio mismatch check.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SrmMethod {
+    /// Pearson's chi-square goodness-of-fit test, suitable for large counts.
+    ChiSquare,
+    /// The exact multinomial test, which enumerates every assignment with the same total.
+    ExactMultinomial,
+}
+
+/// Tracks a sequential sample ratio mismatch check for continuous monitoring of assignment
+/// counts.
+///
+/// The Bayes factor of a Dirichlet-multinomial mixture against the intended allocation is a
+/// martingale under the null hypothesis, so the p-value `min(1, 1 / max BF)` remains valid no
+/// matter how often the counts are checked.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::experiment::SequentialSrm;
+///
+/// let mut srm = SequentialSrm::new(&[1.0, 1.0], 0.05, 100.0).unwrap();
+/// let result = srm.update(&[510.0, 490.0]).unwrap();
+/// assert!(!result.reject_null);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SequentialSrm {
+    pub(crate) probs: Vec<f64>,
+    pub(crate) alpha: f64,
+    pub(crate) concentration: f64,
+    pub(crate) counts: Vec<f64>,
+    pub(crate) p_value: f64,
+}
+
+/// Represents the kind of a metric, which determines the test used to compare each variant with
+/// the control.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MetricKind {
+    /// A 0/1 outcome per unit, compared with the pooled two-proportion Z-test.
+    Binary,
+    /// A continuous outcome per unit, compared with Welch's t-test.
+    Continuous,
+    /// A continuous outcome per unit, compared with the Mann-Whitney U test; the estimate is the
+    /// difference in medians.
+    Rank,
+    /// A ratio of per-unit numerator and denominator sums, compared with the delta method.
+    Ratio,
+}
+
+/// Represents the role of a metric in the decision about an experiment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MetricRole {
+    /// A metric the experiment is designed to move.
+    Primary,
+    /// A metric that must not degrade.
+    Guardrail,
+    /// A metric reported for information only.
+    Secondary,
+}
+
+/// Represents a multiple-comparison correction applied to a family of p-values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Correction {
+    /// No correction.
+    None,
+    /// Bonferroni correction, controlling the family-wise error rate.
+    Bonferroni,
+    /// Holm's step-down correction, controlling the family-wise error rate.
+    Holm,
+    /// Benjamini-Hochberg step-up correction, controlling the false discovery rate.
+    BenjaminiHochberg,
+}
+
+/// Represents the observations of one metric in one variant.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetricData {
+    /// One value per unit, for binary, continuous and rank metrics.
+    Values(Vec<f64>),
+    /// Per-unit numerator and denominator sums, for ratio metrics.
+    Ratio {
+        numerator: Vec<f64>,
+        denominator: Vec<f64>,
+    },
+}
+
+/// Describes a metric of an experiment.
+///
+/// # Fields
+///
+/// * `name` - The name of the metric.
+/// * `kind` - The kind of metric, which determines the test.
+/// * `role` - The role of the metric in the decision.
+/// * `higher_is_better` - Whether an increase is an improvement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Metric {
+    pub name: String,
+    pub kind: MetricKind,
+    pub role: MetricRole,
+    pub higher_is_better: bool,
+}
+
+/// Holds the variants, metrics and observations of an online experiment.
+///
+/// Each non-control variant is compared with the control on every metric, and the p-values
+/// are corrected for multiple comparisons within each metric role.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::experiment::{Correction, Experiment, MetricData, MetricKind, MetricRole};
+///
+/// let mut experiment = Experiment::new("control", 0.05, Correction::Holm);
+/// experiment.add_metric("converted", MetricKind::Binary, MetricRole::Primary, true);
+/// experiment.add_data("control", "converted", MetricData::Values(vec![0.0, 1.0, 0.0, 0.0]));
+/// experiment.add_data("treatment", "converted", MetricData::Values(vec![1.0, 1.0, 0.0, 1.0]));
+///
+/// let scorecard = experiment.analyze().unwrap();
+/// assert_eq!(scorecard.results.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Experiment {
+    pub(crate) control: String,
+    pub(crate) alpha: f64,
+    pub(crate) correction: Correction,
+    pub(crate) variants: Vec<String>,
+    pub(crate) metrics: Vec<Metric>,
+    pub(crate) data: Vec<(String, String, MetricData)>,
+}
+
+/// Represents the conclusion for one metric in one variant.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MetricOutcome {
+    /// The metric changed significantly in the desired direction.
+    Improved,
+    /// The metric changed significantly in the undesired direction.
+    Degraded,
+    /// The change is not significant after correction.
+    Inconclusive,
+}
+
+/// Stores the comparison of one variant with the control on one metric.
+///
+/// # Fields
+///
+/// * `metric` - The name of the metric.
+/// * `variant` - The name of the variant.
+/// * `kind` - The kind of metric.
+/// * `role` - The role of the metric.
+/// * `control_value` - The estimate of the metric in the control (mean, rate, median or ratio).
+/// * `variant_value` - The estimate of the metric in the variant.
+/// * `difference` - The variant estimate minus the control estimate.
+/// * `confidence_interval` - The unadjusted confidence interval for the difference, or
+///   `(NaN, NaN)` for rank metrics.
+/// * `relative_difference` - The relative lift `variant_value / control_value - 1`, or `NaN`
+///   for rank metrics.
+/// * `relative_confidence_interval` - The delta-method confidence interval for the relative
+///   lift, or `(NaN, NaN)` for rank metrics.
+/// * `p_value` - The unadjusted p-value.
+/// * `adjusted_p_value` - The p-value corrected within the metric's role.
+/// * `outcome` - Whether the metric improved, degraded or is inconclusive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricResult {
+    pub metric: String,
+    pub variant: String,
+    pub kind: MetricKind,
+    pub role: MetricRole,
+    pub control_value: f64,
+    pub variant_value: f64,
+    pub difference: f64,
+    pub confidence_interval: (f64, f64),
+    pub relative_difference: f64,
+    pub relative_confidence_interval: (f64, f64),
+    pub p_value: f64,
+    pub adjusted_p_value: f64,
+    pub outcome: MetricOutcome,
+}
+
+/// Stores the analysis of an experiment.
+///
+/// # Fields
+///
+/// * `control` - The name of the control variant.
+/// * `alpha` - The significance level.
+/// * `correction` - The multiple-comparison correction applied within each metric role.
+/// * `results` - The comparison of each variant with the control on each metric, by variant
+///   and then metric in the order they were added.
+/// * `guardrails_passed` - Whether no guardrail metric degraded in any variant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Scorecard {
+    pub control: String,
+    pub alpha: f64,
+    pub correction: Correction,
+    pub results: Vec<MetricResult>,
+    pub guardrails_passed: bool,
+}
+
+/// Represents the method used for the confidence interval of a relative lift.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LiftMethod {
+    /// Delta-method standard error of `m1 / m2 - 1`; symmetric around the estimate.
+    Delta,
+    /// Fieller's theorem, inverting the test of `m1 - ρ m2 = 0`; unbounded when the control
+    /// estimate is not significantly different from zero.
+    Fieller,
+    /// Delta method on `ln(m1 / m2)`, transformed back; requires positive estimates.
+    LogRatio,
+}
+
+/// Stores a relative lift estimate together with the absolute comparison it is derived from.
+///
+/// # Fields
+///
+/// * `lift` - The relative lift `m1 / m2 - 1` of group 1 over group 2.
+/// * `confidence_interval` - The confidence interval for the lift.
+/// * `method` - The method used for the confidence interval.
+/// * `absolute` - The test of the absolute difference `m1 - m2`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiftResult {
+    pub lift: f64,
+    pub confidence_interval: (f64, f64),
+    pub method: LiftMethod,
+    pub absolute: TestResult,
+}
@@ -9,14 +9,40 @@
 //! - [`common`] - Contains shared utilities and helper functions for statistical calculations, including confidence intervals and p-values.
 //! - [`t`] - Implements various t-tests, including one-sample, two-sample paired, and two-sample independent t-tests.
 //! - [`z`] - Implements z-tests for one-sample and two-sample scenarios, supporting both paired and independent tests.
-//! - [`proportion`] - Implements tests for proportions, including one-sample and two-sample proportion tests.
+//! - [`proportion`] - Implements tests for proportions, including one-sample and two-sample proportion tests and tests for paired binary data.
 //! - [`anova`] - Implements one-way ANOVA tests for comparing means across multiple groups.
 //! - [`chi_square`] - Implements Chi-square tests for categorical data analysis.
+//! - [`contingency`] - Implements risk difference, risk ratio and odds ratio estimates for 2x2 tables, stratified Cochran-Mantel-Haenszel analyses and log-linear models for multi-way tables.
 //! - [`mann_whitney`] - Implements the Mann-Whitney U test for comparing two independent samples.
+//! - [`noncentral`] - Implements the noncentral t, chi-squared and F distributions used for exact power calculations.
+//! - [`bootstrap`] - Implements bootstrap confidence intervals for arbitrary statistics.
+//! - [`equivalence`] - Implements equivalence (TOST), non-inferiority and superiority tests.
+//! - [`bayes`] - Implements Bayesian A/B comparisons of conversion rates and means.
+//! - [`experiment`] - Implements analyses for online experiments, such as CUPED variance reduction, delta-method ratio metrics, sample ratio mismatch checks and experiment scorecards.
+//! - [`sequential`] - Implements group sequential designs with O'Brien-Fleming, Pocock and Lan-DeMets boundaries, always-valid mSPRT inference and Wald's SPRT.
+//! - [`power`] - Provides a unified power analysis that solves a design for sample size, power, minimum detectable effect or alpha.
 //!
 //! ### Sample Size Calculations
 //!
 //! Each of the parametrized tests have the faculty to also calculate minimum sample sizes required based on the Alpha and Power values.
+//! Sample sizes are found by iterating on the exact power of each test, computed from the noncentral t, F and chi-squared distributions in [`noncentral`].
+//! The [`power`] module describes a design once and solves for whichever of sample size, power, minimum detectable effect or alpha is missing:
+//! ```rust
+//! use hypors::power::{PowerDesign, PowerTest};
+//! use hypors::common::TailType;
+//!
+//! let design = PowerDesign {
+//!     test: PowerTest::TwoSampleT,
+//!     effect_size: Some(0.5),
+//!     alpha: Some(0.05),
+//!     power: None,
+//!     n: Some(64.0),
+//!     ratio: 1.0,
+//!     tail: TailType::Two,
+//! };
+//! println!("Power: {}", design.solve().unwrap().power);
+//! ```
+//! Power curves over grids of sample sizes or effect sizes, and minimum detectable effects in the units of the metric, are also available in [`power`], along with Monte Carlo power simulation for any test in the crate.
 //!
 //! ## Hypothesis Tests
 //!
@@ -91,6 +117,7 @@
 //! #### Features
 //! - **One-sample proportion test**: Tests whether the proportion of successes in a single sample differs from a specified population proportion.
 //! - **Two-sample proportion test**: Tests whether the proportions of successes in two independent samples differ.
+//! - **Paired binary tests**: McNemar's test (asymptotic, continuity-corrected and exact), the McNemar-Bowker symmetry test and Cochran's Q for related binary outcomes.
 //! - **Sample Size Calculation**: Use `prop_sample_size` to determine the required sample size for specified power and significance levels.
 //!
 //! ---
@@ -135,6 +162,10 @@
 //! #### Features
 //! - **Chi-square variance test**: Tests whether the variance of the distribution differs from the expected variance.
 //! - **Chi-square test for independence**: Tests whether two categorical variables are independent of each other.
+//! - **Ordered categories**: `cochran_armitage` tests for a trend in proportions across ordered groups of a 2×k table, and `linear_by_linear` tests for a linear association in an ordered r×c table, with user-specified scores.
+//! - **Fitted distributions**: `goodness_of_fit_ddof` removes a degree of freedom per estimated parameter, and `goodness_of_fit_fitted` fits a Poisson, binomial, geometric or normal distribution by maximum likelihood, bins the data, merges sparse bins and runs the adjusted test.
+//! - **Sparse categories**: `goodness_of_fit_monte_carlo` and `independence_monte_carlo` give seeded Monte Carlo p-values, and `goodness_of_fit_exact` gives the exact multinomial p-value for small totals.
+//! - **Independence options**: `independence_test` offers Yates' correction, the likelihood-ratio G statistic and Williams' correction, with Pearson and adjusted residuals, Cramér's V, phi and the contingency coefficient with confidence intervals, and warnings for expected counts below 5.
 //! - **Chi-square goodness-of-fit test**: Tests whether the observed frequency distribution differs from the expected distribution.
 //! - **Sample Size Calculation**: Use `chi2_sample_size_gof`, `chi2_sample_size_ind`,`chi2_sample_size_variance` to determine the required sample sizes for the different implementations respectively.
 //!
@@ -161,6 +192,141 @@
 //!
 //! ---
 //!
+//! ### Contingency Table Effect Measures
+//! Example of estimating the risk difference and odds ratio of a 2x2 table:
+//! ```rust
+//! use hypors::contingency::{odds_ratio, risk_difference, OddsRatioMethod, RiskDifferenceMethod};
+//!
+//! let table = vec![vec![56.0, 14.0], vec![48.0, 32.0]];
+//! let rd = risk_difference(&table, RiskDifferenceMethod::MiettinenNurminen, 0.05).unwrap();
+//! let or = odds_ratio(&table, OddsRatioMethod::Exact, 0.05).unwrap();
+//! println!("Risk difference: {} {:?}", rd.estimate, rd.result.confidence_interval);
+//! println!("Odds ratio: {} {:?}", or.estimate, or.result.confidence_interval);
+//! ```
+//!
+//! #### Features
+//! - **Risk Difference**: Newcombe hybrid score and Miettinen-Nurminen intervals with a score test.
+//! - **Risk Ratio**: Katz log interval with a Wald test.
+//! - **Odds Ratio**: Woolf, exact conditional and mid-p intervals with matching tests.
+//! - **Stratified Tables**: Cochran-Mantel-Haenszel test for 2x2 and r×c strata, the Mantel-Haenszel common odds ratio with the Robins-Breslow-Greenland interval, and the Breslow-Day homogeneity test.
+//! - **Log-Linear Models**: Iterative proportional fitting of hierarchical log-linear models to three- and four-way tables, with deviance tests of mutual independence, conditional independence and homogeneous association, and fitted counts.
+//!
+//! ---
+//!
+//! ### Bootstrap
+//! Example of a bootstrap confidence interval for a median:
+//! ```rust
+//! use hypors::bootstrap::{bootstrap, BootstrapMethod};
+//!
+//! let data = vec![1.2, 2.3, 3.1, 2.8, 1.9, 4.5, 2.2, 3.6];
+//! let median = |x: &[f64]| {
+//!     let mut v = x.to_vec();
+//!     v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+//!     (v[v.len() / 2 - 1] + v[v.len() / 2]) / 2.0
+//! };
+//!
+//! let result = bootstrap(data, median, BootstrapMethod::BCa, 2000, 0.05, 42).unwrap();
+//! println!("Estimate: {}", result.estimate);
+//! println!("Confidence Interval: {:?}", result.confidence_interval);
+//! ```
+//!
+//! #### Features
+//! - **Interval methods**: Percentile, basic, studentized and BCa intervals.
+//! - **Resampling schemes**: Ordinary, stratified and moving block bootstrap.
+//! - **Deterministic seeding**: Every function takes a seed for reproducible results.
+//!
+//! ---
+//!
+//! ### Equivalence Tests
+//! Example of showing two groups are equivalent within ±0.3:
+//! ```rust
+//! use hypors::equivalence::t_tost_ind;
+//!
+//! let before = vec![5.1, 4.9, 5.0, 5.2, 4.8, 5.1, 5.0, 4.9];
+//! let after = vec![5.0, 5.1, 4.9, 5.0, 5.1, 4.9, 5.0, 5.2];
+//!
+//! let result = t_tost_ind(before, after, -0.3, 0.3, 0.05, false).unwrap();
+//! println!("P-value: {}", result.p_value);
+//! println!("Equivalent: {}", result.reject_null);
+//! ```
+//!
+//! #### Features
+//! - **TOST**: Equivalence tests with symmetric or asymmetric bounds for one-sample, paired and independent t/z tests and two-sample proportions.
+//! - **Non-inferiority and superiority**: One-sided tests against a margin for the same designs.
+//!
+//! ---
+//!
+//! ### Sequential Testing
+//! Example of monitoring an A/B test at five equally spaced looks:
+//! ```rust
+//! use hypors::sequential::{group_sequential_design, BoundaryType, SequentialDecision, SpendingFunction};
+//! use hypors::common::TailType;
+//!
+//! let design = group_sequential_design(
+//!     &[0.2, 0.4, 0.6, 0.8, 1.0],
+//!     0.05,
+//!     TailType::Two,
+//!     BoundaryType::LanDeMets(SpendingFunction::OBrienFleming),
+//!     None,
+//! )
+//! .unwrap();
+//!
+//! // Z-statistic from e.g. `proportion::z_test_ind` on the data so far
+//! let decision = design.decide(1, 2.4).unwrap();
+//! assert_eq!(decision, SequentialDecision::Continue);
+//! ```
+//!
+//! #### Features
+//! - **Efficacy boundaries**: Classical O'Brien-Fleming and Pocock, or Lan-DeMets alpha spending at arbitrary looks.
+//! - **Futility boundaries**: Non-binding boundaries from beta spending for one-tailed designs.
+//! - **Always-valid inference**: mSPRT p-values and confidence sequences for differences in means and proportions, updated from summary statistics at every look.
+//! - **Wald's SPRT**: Sequential probability ratio tests for Bernoulli, normal and Poisson observations, with ASN and OC curves.
+//!
+//! ---
+//!
+//! ### Bayesian A/B Testing
+//! Example of computing the probability that variant B converts better than A:
+//! ```rust
+//! use hypors::bayes::{beta_binomial_ab_counts, BetaPrior};
+//!
+//! let result = beta_binomial_ab_counts(100.0, 1000.0, 130.0, 1000.0, &BetaPrior::default(), 0.05).unwrap();
+//! println!("P(B > A): {}", result.prob_b_beats_a);
+//! println!("Expected loss of choosing B: {}", result.expected_loss_b);
+//! ```
+//!
+//! #### Features
+//! - **Beta-Binomial**: Exact `P(B > A)`, expected loss and credible intervals for conversion rates.
+//! - **Normal-Inverse-Gamma**: The same summaries for continuous metrics with unknown variance.
+//! - **Bayes Factors**: JZS Bayes factors for t-tests with a configurable Cauchy prior scale, and
+//!   Gunel-Dickey Bayes factors for contingency tables.
+//!
+//! ---
+//!
+//! ### Experiment Analysis
+//! Example of comparing two variants with CUPED adjustment by a pre-experiment covariate:
+//! ```rust
+//! use hypors::experiment::cuped_test;
+//! use hypors::common::TailType;
+//!
+//! let pre1 = vec![10.0, 12.0, 9.0, 15.0, 11.0, 13.0];
+//! let post1 = vec![11.2, 13.1, 10.3, 16.0, 12.4, 14.1];
+//! let pre2 = vec![11.0, 9.0, 14.0, 12.0, 10.0, 13.0];
+//! let post2 = vec![11.3, 9.4, 14.2, 12.1, 10.5, 13.2];
+//!
+//! let result = cuped_test(post1, pre1, post2, pre2, TailType::Two, 0.05).unwrap();
+//! println!("Adjusted difference: {} ± {}", result.adjusted_difference, result.std_error);
+//! println!("Variance reduction: {}", result.variance_reduction);
+//! ```
+//!
+//! #### Features
+//! - **CUPED**: Regression adjustment by pre-experiment data, with a standard error, confidence interval and p-value for the adjusted difference.
+//! - **Ratio Metrics**: Delta-method tests for metrics such as revenue per session, with confidence intervals on the absolute and relative differences.
+//! - **Relative Lift**: Lifts of means, proportions and ratio metrics with delta-method, Fieller and log-ratio confidence intervals, reported alongside the absolute results.
+//! - **Sample Ratio Mismatch**: Chi-square, exact multinomial and sequential checks of assignment counts against the intended allocation.
+//! - **Scorecards**: An `Experiment` of named variants and binary, continuous, rank and ratio metrics, analysed against the control with multiplicity correction into a serialisable scorecard.
+//!
+//! ---
+//!
 //! ## Common Features
 //!
 //! - **Customizable tail type**: Supports left-tailed, right-tailed, and two-tailed tests for both t-tests and z-tests.
@@ -180,6 +346,7 @@
 //!
 //! - [`statrs`](https://crates.io/crates/statrs) for statistical distributions.
 //! - [`serde`](https://crates.io/crates/serde) for object serialization and deserialization.
+//! - [`rand`](https://crates.io/crates/rand) for seeded random number generation in resampling methods.
 //!
 //! ## Error Handling
 //!
@@ -192,8 +359,16 @@
 pub mod common;
 
 pub mod anova;
+pub mod bayes;
+pub mod bootstrap;
 pub mod chi_square;
+pub mod contingency;
+pub mod equivalence;
+pub mod experiment;
 pub mod mann_whitney;
+pub mod noncentral;
+pub mod power;
 pub mod proportion;
+pub mod sequential;
 pub mod t;
 pub mod z;
@@ -0,0 +1,91 @@
+use statrs::function::gamma::ln_gamma;
+
+/// Relative size below which Poisson mixture weights are treated as negligible.
+const TOLERANCE: f64 = 1e-16;
+
+/// Upper bound on the number of mixture terms evaluated in each direction from the mode.
+const MAX_TERMS: usize = 100_000;
+
+/// Sums a Poisson mixture `Σ_j P(J = j) * term(j)` with `J ~ Poisson(mean)`.
+///
+/// The sum starts at the mode of the Poisson distribution and proceeds outwards in both
+/// directions until the weights become negligible, which keeps the number of terms small even
+/// for large noncentrality parameters.
+///
+/// # Arguments
+///
+/// * `mean` - The mean of the Poisson mixing distribution (half the noncentrality parameter).
+/// * `term` - The conditional value for mixture component `j`.
+pub(crate) fn poisson_mixture<F>(mean: f64, term: F) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    if mean == 0.0 {
+        return term(0.0);
+    }
+
+    let log_weight = |j: f64| -mean + j * mean.ln() - ln_gamma(j + 1.0);
+    let mode = mean.floor();
+
+    let mut total = 0.0;
+    for k in 0..MAX_TERMS {
+        let j = mode + k as f64;
+        let weight = log_weight(j).exp();
+        total += weight * term(j);
+        if weight < TOLERANCE && j > mean {
+            break;
+        }
+    }
+    for k in 1..MAX_TERMS {
+        let j = mode - k as f64;
+        if j < 0.0 {
+            break;
+        }
+        let weight = log_weight(j).exp();
+        total += weight * term(j);
+        if weight < TOLERANCE {
+            break;
+        }
+    }
+    total
+}
+
+/// Inverts a monotone CDF by bracketing and bisection.
+///
+/// # Arguments
+///
+/// * `cdf` - The cumulative distribution function to invert.
+/// * `p` - The probability whose quantile is required (between 0 and 1).
+/// * `lower` - The lower end of the support.
+/// * `start` - A positive initial guess for the width of the bracket.
+pub(crate) fn invert_cdf<F>(cdf: F, p: f64, lower: f64, start: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let mut low = if lower.is_finite() { lower } else { -start };
+    let mut high = start.max(1.0);
+
+    while !lower.is_finite() && cdf(low) > p {
+        low *= 2.0;
+    }
+    while cdf(high) < p {
+        low = high;
+        high *= 2.0;
+        if !high.is_finite() {
+            return f64::INFINITY;
+        }
+    }
+
+    for _ in 0..200 {
+        let mid = 0.5 * (low + high);
+        if cdf(mid) >= p {
+            high = mid;
+        } else {
+            low = mid;
+        }
+        if (high - low) <= 1e-12 * high.abs().max(1.0) {
+            break;
+        }
+    }
+    0.5 * (low + high)
+}
@@ -0,0 +1,38 @@
+//! # Noncentral Distributions
+//!
+//! The `noncentral` module provides the noncentral Student's t, chi-squared and F distributions.
+//!
+//! Under an alternative hypothesis, the t, chi-square and F test statistics follow noncentral
+//! distributions. Their CDFs give the exact power of these tests, which is used by the
+//! `*_sample_size` functions of the test modules.
+//!
+//! All distributions implement `statrs::distribution::ContinuousCDF`, so they can be used with
+//! `common::calculate_p` and `common::calculate_ci` like the central distributions.
+//!
+//! ## Submodules
+//!
+//! - `students_t`: Contains the noncentral Student's t distribution.
+//! - `chi_squared`: Contains the noncentral chi-squared distribution.
+//! - `fisher_snedecor`: Contains the noncentral F distribution.
+//!
+//! ## Exports
+//!
+//! The following types are made available for use:
+//!
+//! - `NoncentralStudentsT`: Noncentral t distribution with degrees of freedom and noncentrality `delta`.
+//! - `NoncentralChiSquared`: Noncentral chi-squared distribution with degrees of freedom and noncentrality `lambda`.
+//! - `NoncentralFisherSnedecor`: Noncentral F distribution with two degrees of freedom and noncentrality `lambda`.
+//!
+//! ## Example
+//! ```rust
+//! use hypors::noncentral::{NoncentralChiSquared, NoncentralFisherSnedecor, NoncentralStudentsT};
+//! ```
+
+pub mod chi_squared;
+pub mod fisher_snedecor;
+pub(crate) mod mixture;
+pub mod students_t;
+
+pub use chi_squared::NoncentralChiSquared;
+pub use fisher_snedecor::NoncentralFisherSnedecor;
+pub use students_t::NoncentralStudentsT;
@@ -0,0 +1,110 @@
+use super::types::{PowerDesign, PowerSolution};
+use crate::common::StatError;
+
+/// Evaluates the power of a design over a grid of sample sizes.
+///
+/// The `effect_size`, `alpha`, `ratio`, `tail` and `test` of the design are held fixed; its
+/// `power` and `n` fields are ignored.
+///
+/// # Arguments
+///
+/// * `design` - The design, with `effect_size` and `alpha` set.
+/// * `sample_sizes` - The sample sizes at which to evaluate power, as defined for `PowerDesign::n`.
+///
+/// # Returns
+///
+/// A `Result` containing one `PowerSolution` per sample size, or a `StatError` if the grid is
+/// empty or any point is an invalid design.
+///
+/// # Example
+/// ```rust
+/// use hypors::power::{power_curve_n, PowerDesign, PowerTest};
+/// use hypors::common::TailType;
+///
+/// let design = PowerDesign {
+///     test: PowerTest::TwoSampleZ,
+///     effect_size: Some(0.5),
+///     alpha: Some(0.05),
+///     power: None,
+///     n: None,
+///     ratio: 1.0,
+///     tail: TailType::Two,
+/// };
+///
+/// let curve = power_curve_n(&design, &[20.0, 40.0, 60.0, 80.0]).unwrap();
+/// assert!(curve.windows(2).all(|w| w[0].power < w[1].power));
+/// ```
+pub fn power_curve_n(
+    design: &PowerDesign,
+    sample_sizes: &[f64],
+) -> Result<Vec<PowerSolution>, StatError> {
+    if sample_sizes.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+
+    sample_sizes
+        .iter()
+        .map(|&n| {
+            PowerDesign {
+                power: None,
+                n: Some(n),
+                ..design.clone()
+            }
+            .solve()
+        })
+        .collect()
+}
+
+/// Evaluates the power of a design over a grid of effect sizes.
+///
+/// The `n`, `alpha`, `ratio`, `tail` and `test` of the design are held fixed; its `power` and
+/// `effect_size` fields are ignored.
+///
+/// # Arguments
+///
+/// * `design` - The design, with `n` and `alpha` set.
+/// * `effect_sizes` - The standardized effect sizes at which to evaluate power.
+///
+/// # Returns
+///
+/// A `Result` containing one `PowerSolution` per effect size, or a `StatError` if the grid is
+/// empty or any point is an invalid design.
+///
+/// # Example
+/// ```rust
+/// use hypors::power::{power_curve_effect, PowerDesign, PowerTest};
+/// use hypors::common::TailType;
+///
+/// let design = PowerDesign {
+///     test: PowerTest::Anova { num_groups: 3 },
+///     effect_size: None,
+///     alpha: Some(0.05),
+///     power: None,
+///     n: Some(53.0),
+///     ratio: 1.0,
+///     tail: TailType::Right,
+/// };
+///
+/// let curve = power_curve_effect(&design, &[0.1, 0.25, 0.4]).unwrap();
+/// assert!((curve[1].power - 0.8048).abs() < 1e-3);
+/// ```
+pub fn power_curve_effect(
+    design: &PowerDesign,
+    effect_sizes: &[f64],
+) -> Result<Vec<PowerSolution>, StatError> {
+    if effect_sizes.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+
+    effect_sizes
+        .iter()
+        .map(|&effect_size| {
+            PowerDesign {
+                effect_size: Some(effect_size),
+                power: None,
+                ..design.clone()
+            }
+            .solve()
+        })
+        .collect()
+}
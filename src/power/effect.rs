@@ -0,0 +1,75 @@
+use crate::common::StatError;
+
+/// Calculates Cohen's h, the effect size for a difference between two proportions.
+///
+/// `h = 2 * asin(√p2) - 2 * asin(√p1)`, the difference on the variance-stabilizing arcsine
+/// scale.
+///
+/// # Arguments
+///
+/// * `p1` - The proportion in the first group.
+/// * `p2` - The proportion in the second group.
+///
+/// # Returns
+///
+/// Cohen's h, or `NaN` if either proportion lies outside `[0, 1]`.
+///
+/// # Example
+/// ```rust
+/// use hypors::power::cohens_h;
+///
+/// let h = cohens_h(0.4, 0.5);
+/// assert!((h - 0.2014).abs() < 1e-4);
+/// ```
+pub fn cohens_h(p1: f64, p2: f64) -> f64 {
+    if !(0.0..=1.0).contains(&p1) || !(0.0..=1.0).contains(&p2) {
+        return f64::NAN;
+    }
+    2.0 * p2.sqrt().asin() - 2.0 * p1.sqrt().asin()
+}
+
+/// Calculates Cohen's w, the effect size for a chi-square test.
+///
+/// `w = √Σ (p1ᵢ - p0ᵢ)² / p0ᵢ`, where `p0` holds the cell probabilities under the null
+/// hypothesis and `p1` those under the alternative. For a test of independence, pass the
+/// flattened table of joint probabilities and the products of their margins as `p0`.
+///
+/// # Arguments
+///
+/// * `null_probs` - The cell probabilities under the null hypothesis.
+/// * `alt_probs` - The cell probabilities under the alternative hypothesis.
+///
+/// # Returns
+///
+/// A `Result` containing Cohen's w, or a `StatError` if the inputs are empty, of different
+/// lengths, or contain non-positive null probabilities.
+///
+/// # Example
+/// ```rust
+/// use hypors::power::cohens_w;
+///
+/// let w = cohens_w(&[0.25, 0.25, 0.25, 0.25], &[0.1, 0.4, 0.25, 0.25]).unwrap();
+/// assert!((w - 0.4243).abs() < 1e-4);
+/// ```
+pub fn cohens_w(null_probs: &[f64], alt_probs: &[f64]) -> Result<f64, StatError> {
+    if null_probs.is_empty() || alt_probs.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    if null_probs.len() != alt_probs.len() {
+        return Err(StatError::ComputeError(
+            "Null and alternative probabilities must have the same length".to_string(),
+        ));
+    }
+    if null_probs.iter().any(|&p| p <= 0.0) {
+        return Err(StatError::ComputeError(
+            "Null probabilities must be positive".to_string(),
+        ));
+    }
+
+    let sum: f64 = null_probs
+        .iter()
+        .zip(alt_probs)
+        .map(|(&p0, &p1)| (p1 - p0).powi(2) / p0)
+        .sum();
+    Ok(sum.sqrt())
+}
@@ -0,0 +1,152 @@
+use super::solve::bisect;
+use super::types::{PowerDesign, PowerTest};
+use crate::common::{StatError, TailType};
+use crate::proportion::prop_power_ind;
+
+/// Calculates the minimum detectable difference in means at a fixed sample size.
+///
+/// The standardized minimum detectable effect is found with `PowerDesign::solve` and scaled by
+/// the standard deviation, giving the smallest true difference that the test detects with the
+/// desired power.
+///
+/// # Arguments
+///
+/// * `test` - A t-test or Z-test (`OneSampleT`, `PairedT`, `TwoSampleT`, `OneSampleZ`,
+///   `PairedZ` or `TwoSampleZ`).
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `power` - The desired statistical power (e.g., 0.80 for 80% power).
+/// * `n` - The sample size, of the first group for two-sample tests.
+/// * `std_dev` - The standard deviation of the metric (of the differences for paired tests).
+/// * `ratio` - The allocation ratio `n2 / n1` for two-sample tests.
+/// * `tail` - The type of tail (left, right, or two) for the test.
+///
+/// # Returns
+///
+/// A `Result` containing the minimum detectable difference (negative for left-tailed tests),
+/// or a `StatError` if the test is not a test of means or the design is invalid.
+///
+/// # Example
+/// ```rust
+/// use hypors::power::{mde_mean, PowerTest};
+/// use hypors::common::TailType;
+///
+/// let mde = mde_mean(PowerTest::TwoSampleZ, 0.05, 0.80, 1000.0, 20.0, 1.0, TailType::Two).unwrap();
+/// assert!((mde - 2.5057).abs() < 1e-3);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn mde_mean(
+    test: PowerTest,
+    alpha: f64,
+    power: f64,
+    n: f64,
+    std_dev: f64,
+    ratio: f64,
+    tail: TailType,
+) -> Result<f64, StatError> {
+    match test {
+        PowerTest::OneSampleT
+        | PowerTest::PairedT
+        | PowerTest::TwoSampleT
+        | PowerTest::OneSampleZ
+        | PowerTest::PairedZ
+        | PowerTest::TwoSampleZ => {}
+        _ => {
+            return Err(StatError::ComputeError(
+                "Minimum detectable mean difference requires a t-test or Z-test".to_string(),
+            ));
+        }
+    }
+    if std_dev <= 0.0 {
+        return Err(StatError::ComputeError(
+            "Standard deviation must be positive".to_string(),
+        ));
+    }
+
+    let design = PowerDesign {
+        test,
+        effect_size: None,
+        alpha: Some(alpha),
+        power: Some(power),
+        n: Some(n),
+        ratio,
+        tail,
+    };
+    Ok(design.solve()?.effect_size * std_dev)
+}
+
+/// Calculates the minimum detectable change from a baseline rate at a fixed sample size.
+///
+/// The first group has the baseline rate, and the result is the smallest absolute change in
+/// the second group's rate at which `prop_power_ind` reaches the desired power. Right- and
+/// two-tailed tests look for an increase, left-tailed tests for a decrease.
+///
+/// # Arguments
+///
+/// * `baseline` - The rate in the first (control) group.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `power` - The desired statistical power (e.g., 0.80 for 80% power).
+/// * `n` - The sample size of the first group.
+/// * `ratio` - The allocation ratio `n2 / n1`.
+/// * `tail` - The type of tail (left, right, or two) for the test.
+///
+/// # Returns
+///
+/// A `Result` containing the minimum detectable absolute change in rate (negative for
+/// left-tailed tests), or a `StatError` if the inputs are invalid or no change within `[0, 1]`
+/// reaches the desired power.
+///
+/// # Example
+/// ```rust
+/// use hypors::power::mde_proportion;
+/// use hypors::common::TailType;
+///
+/// let mde = mde_proportion(0.4, 0.05, 0.80, 388.0, 1.0, TailType::Two).unwrap();
+/// assert!((mde - 0.1).abs() < 1e-3);
+/// ```
+pub fn mde_proportion(
+    baseline: f64,
+    alpha: f64,
+    power: f64,
+    n: f64,
+    ratio: f64,
+    tail: TailType,
+) -> Result<f64, StatError> {
+    if baseline <= 0.0 || baseline >= 1.0 {
+        return Err(StatError::ComputeError(
+            "Baseline rate must be between 0 and 1".to_string(),
+        ));
+    }
+    if alpha <= 0.0 || alpha >= 1.0 || power <= alpha || power >= 1.0 {
+        return Err(StatError::ComputeError(
+            "Alpha and power must satisfy 0 < alpha < power < 1".to_string(),
+        ));
+    }
+    if n < 1.0 || !(ratio > 0.0 && ratio.is_finite()) {
+        return Err(StatError::InsufficientData);
+    }
+
+    let n2 = ratio * n;
+    let (direction, max_change) = match tail {
+        TailType::Left => (-1.0, baseline),
+        _ => (1.0, 1.0 - baseline),
+    };
+    let power_at = |change: f64| {
+        prop_power_ind(
+            baseline,
+            baseline + direction * change,
+            alpha,
+            n,
+            n2,
+            tail.clone(),
+        )
+    };
+
+    // The variance vanishes at the boundary rate, so search just inside it
+    let high = max_change * (1.0 - 1e-9);
+    if power_at(high) < power {
+        return Err(StatError::ComputeError(
+            "Desired power cannot be reached at this sample size".to_string(),
+        ));
+    }
+    Ok(direction * bisect(|change| power_at(change) - power, 0.0, high))
+}
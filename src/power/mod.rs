@@ -0,0 +1,72 @@
+//! # Power Analysis
+//!
+//! The `power` module provides a single interface for power analysis across the tests in this
+//! crate.
+//!
+//! A `PowerDesign` describes the test, effect size, significance level, power, sample size,
+//! allocation ratio and tail once, leaving exactly one of effect size, alpha, power and sample
+//! size unset. `PowerDesign::solve` then finds the missing quantity:
+//!
+//! - **Power**: The exact power of the design.
+//! - **Sample size**: The smallest sample size reaching the desired power.
+//! - **Minimum detectable effect**: The smallest effect reaching the desired power.
+//! - **Alpha**: The smallest significance level reaching the desired power.
+//!
+//! Power is calculated from the noncentral t, chi-square and F distributions of the test
+//! statistics (see the `noncentral` module), and from the normal distribution for Z-tests and
+//! proportions.
+//!
+//! For planning reviews, `power_curve_n` and `power_curve_effect` tabulate power over a grid
+//! of sample sizes or effect sizes, and `mde_mean` and `mde_proportion` express the minimum
+//! detectable effect at a fixed sample size in the units of the metric.
+//!
+//! Designs without closed-form power (rank tests, unequal variances, skewed data) can be
+//! handled with `simulate_power`, which runs any hypors test on samples drawn from `statrs`
+//! distributions, optionally across several threads.
+//!
+//! ## Submodules
+//!
+//! - `curve`: Contains power curves over grids of sample sizes and effect sizes.
+//! - `effect`: Contains effect size helpers for proportions and chi-square tests.
+//! - `mde`: Contains minimum detectable effect calculators for means and rates.
+//! - `simulate`: Contains the Monte Carlo power engine.
+//! - `solve`: Contains the power calculation and the solver for a missing quantity.
+//! - `types`: Defines the `PowerTest`, `PowerDesign` and `PowerSolution` types.
+//!
+//! ## Exports
+//!
+//! The following functions and types are made available for use:
+//!
+//! - `cohens_h`: Calculates Cohen's h for two proportions.
+//! - `cohens_w`: Calculates Cohen's w for a chi-square test.
+//! - `power_curve_n`: Evaluates power over a grid of sample sizes.
+//! - `power_curve_effect`: Evaluates power over a grid of effect sizes.
+//! - `mde_mean`: Calculates the minimum detectable difference in means.
+//! - `mde_proportion`: Calculates the minimum detectable change from a baseline rate.
+//! - `simulate_power`: Estimates the power of any test by simulation.
+//! - `PowerTest`: The test being planned and the scale of its effect size.
+//! - `PowerDesign`: The design with one missing quantity.
+//! - `PowerSolution`: The fully specified design.
+//! - `SimulationGroup`: A distribution and sample size to simulate.
+//! - `SimulationResult`: The simulated power and its confidence interval.
+//!
+//! ## Example
+//! ```rust
+//! use hypors::power::{
+//!     cohens_h, cohens_w, mde_mean, mde_proportion, power_curve_effect, power_curve_n,
+//!     simulate_power, PowerDesign, PowerSolution, PowerTest, SimulationGroup, SimulationResult,
+//! };
+//! ```
+
+pub mod curve;
+pub mod effect;
+pub mod mde;
+pub mod simulate;
+pub mod solve;
+pub mod types;
+
+pub use curve::{power_curve_effect, power_curve_n};
+pub use effect::{cohens_h, cohens_w};
+pub use mde::{mde_mean, mde_proportion};
+pub use simulate::simulate_power;
+pub use types::{PowerDesign, PowerSolution, PowerTest, SimulationGroup, SimulationResult};
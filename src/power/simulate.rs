@@ -0,0 +1,150 @@
+use super::types::{SimulationGroup, SimulationResult};
+use crate::common::{StatError, TestResult};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use statrs::distribution::{Beta, ContinuousCDF};
+use std::fmt::Display;
+use std::thread;
+
+/// Estimates the power of any test by Monte Carlo simulation.
+///
+/// Each simulation draws a fresh sample for every group from its distribution, runs `test` on
+/// the samples, and records whether the null hypothesis was rejected. This covers designs
+/// without closed-form power, such as rank tests, unequal variances or skewed data.
+///
+/// Simulation `i` uses a random number generator seeded with the `i`-th output of a SplitMix64
+/// stream started from `seed`, so results are identical for any number of threads and runs with
+/// different seeds share no simulated samples.
+///
+/// # Arguments
+///
+/// * `groups` - The groups to simulate, in the order they are passed to `test`.
+/// * `test` - The test to run on each simulated sample; any hypors test returning a
+///   `TestResult` can be wrapped in a closure.
+/// * `n_simulations` - The number of simulated samples (e.g., 1000).
+/// * `alpha` - The significance level of the confidence interval for the power.
+/// * `seed` - The seed of the random number generator, making results reproducible.
+/// * `threads` - The number of threads to run simulations on (1 runs them on the caller).
+///
+/// # Returns
+///
+/// A `SimulationResult` struct containing the estimated power and its Clopper-Pearson
+/// confidence interval.
+///
+/// # Errors
+///
+/// Returns a `StatError` if there are no groups or simulations, if `threads` is zero, or if
+/// the test fails on any simulated sample.
+///
+/// # Example
+/// ```rust
+/// use hypors::power::{simulate_power, SimulationGroup};
+/// use hypors::mann_whitney::u_test;
+/// use hypors::common::TailType;
+/// use statrs::distribution::Normal;
+///
+/// let groups = vec![
+///     SimulationGroup::new(Normal::new(0.0, 1.0).unwrap(), 30),
+///     SimulationGroup::new(Normal::new(0.8, 1.0).unwrap(), 30),
+/// ];
+///
+/// let result = simulate_power(
+///     &groups,
+///     |samples| u_test(samples[0].clone(), samples[1].clone(), 0.05, TailType::Two),
+///     500,
+///     0.05,
+///     42,
+///     2,
+/// )
+/// .unwrap();
+///
+/// let (lower, upper) = result.confidence_interval;
+/// assert!(lower < result.power && result.power < upper);
+/// ```
+pub fn simulate_power<F, E>(
+    groups: &[SimulationGroup],
+    test: F,
+    n_simulations: usize,
+    alpha: f64,
+    seed: u64,
+    threads: usize,
+) -> Result<SimulationResult, StatError>
+where
+    F: Fn(&[Vec<f64>]) -> Result<TestResult, E> + Sync,
+    E: Display,
+{
+    if groups.is_empty() || n_simulations == 0 {
+        return Err(StatError::EmptyData);
+    }
+    if threads == 0 {
+        return Err(StatError::ComputeError(
+            "At least one thread is required".to_string(),
+        ));
+    }
+
+    // Counts the rejections among simulations `start..end`
+    let run = |start: usize, end: usize| -> Result<usize, StatError> {
+        let mut rejections = 0;
+        for i in start..end {
+            let mut rng = StdRng::seed_from_u64(simulation_seed(seed, i as u64));
+            let samples: Vec<Vec<f64>> = groups.iter().map(|g| g.draw(&mut rng)).collect();
+            let result = test(&samples).map_err(|e| StatError::ComputeError(e.to_string()))?;
+            if result.reject_null {
+                rejections += 1;
+            }
+        }
+        Ok(rejections)
+    };
+
+    let n_rejections = if threads == 1 {
+        run(0, n_simulations)?
+    } else {
+        let chunk = n_simulations.div_ceil(threads);
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..n_simulations)
+                .step_by(chunk)
+                .map(|start| {
+                    let run = &run;
+                    scope.spawn(move || run(start, (start + chunk).min(n_simulations)))
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|h| h.join().expect("Simulation thread panicked"))
+                .sum::<Result<usize, StatError>>()
+        })?
+    };
+
+    Ok(SimulationResult {
+        power: n_rejections as f64 / n_simulations as f64,
+        confidence_interval: clopper_pearson(n_rejections, n_simulations, alpha),
+        n_rejections,
+        n_simulations,
+    })
+}
+
+/// Calculates the Clopper-Pearson (exact) confidence interval for a binomial proportion.
+pub(crate) fn clopper_pearson(successes: usize, trials: usize, alpha: f64) -> (f64, f64) {
+    let x = successes as f64;
+    let n = trials as f64;
+
+    let lower = match Beta::new(x, n - x + 1.0) {
+        Ok(beta) if successes > 0 => beta.inverse_cdf(alpha / 2.0),
+        _ => 0.0,
+    };
+    let upper = match Beta::new(x + 1.0, n - x) {
+        Ok(beta) if successes < trials => beta.inverse_cdf(1.0 - alpha / 2.0),
+        _ => 1.0,
+    };
+    (lower, upper)
+}
+
+/// Derives the seed of simulation `i` as the `i`-th output of a SplitMix64 stream started from
+/// `seed`.
+fn simulation_seed(seed: u64, i: u64) -> u64 {
+    let mut z = seed.wrapping_add(i.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
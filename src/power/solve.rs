@@ -0,0 +1,273 @@
+use super::types::{PowerDesign, PowerSolution, PowerTest};
+use crate::anova::f_power;
+use crate::common::calc::{allocated_size, solve_sample_size};
+use crate::common::{StatError, TailType};
+use crate::noncentral::NoncentralChiSquared;
+use crate::t::{t_power, t_power_ind};
+use crate::z::{z_power, z_power_ind};
+use statrs::distribution::{ChiSquared, ContinuousCDF};
+
+const BISECT_ITERATIONS: usize = 200;
+const MAX_EFFECT: f64 = 1e6;
+
+/// Calculates the power of `test` at the given effect size, alpha and sample size.
+///
+/// The effect is oriented along the tail, so one-tailed tests are powered in their own
+/// direction whatever the sign of `effect_size`.
+pub(crate) fn design_power(
+    test: &PowerTest,
+    effect_size: f64,
+    alpha: f64,
+    n: f64,
+    ratio: f64,
+    tail: &TailType,
+) -> f64 {
+    let effect = match tail {
+        TailType::Left => -effect_size.abs(),
+        _ => effect_size.abs(),
+    };
+
+    match test {
+        PowerTest::OneSampleT | PowerTest::PairedT => t_power(effect, alpha, n, 1.0, tail.clone()),
+        PowerTest::TwoSampleT => t_power_ind(
+            effect,
+            alpha,
+            n,
+            allocated_size(n, ratio),
+            1.0,
+            tail.clone(),
+        ),
+        PowerTest::OneSampleZ | PowerTest::PairedZ => z_power(effect, alpha, n, 1.0, tail.clone()),
+        PowerTest::TwoSampleZ | PowerTest::TwoProportions => z_power_ind(
+            effect,
+            alpha,
+            n,
+            allocated_size(n, ratio),
+            1.0,
+            1.0,
+            tail.clone(),
+        ),
+        PowerTest::Anova { num_groups } => f_power(effect, alpha, n, *num_groups),
+        PowerTest::ChiSquare { df } => {
+            let df = *df as f64;
+            match (
+                ChiSquared::new(df),
+                NoncentralChiSquared::new(df, n * effect.powi(2)),
+            ) {
+                (Ok(chi2), Ok(ncx2)) => 1.0 - ncx2.cdf(chi2.inverse_cdf(1.0 - alpha)),
+                _ => f64::NAN,
+            }
+        }
+    }
+}
+
+/// Returns the smallest sample size for which `test` is defined.
+fn min_sample_size(test: &PowerTest) -> f64 {
+    match test {
+        PowerTest::OneSampleT | PowerTest::PairedT | PowerTest::TwoSampleT => 2.0,
+        PowerTest::Anova { .. } => 2.0,
+        _ => 1.0,
+    }
+}
+
+/// Returns the total number of observations implied by a sample size of `n`.
+fn total_sample_size(test: &PowerTest, n: f64, ratio: f64) -> f64 {
+    match test {
+        PowerTest::TwoSampleT | PowerTest::TwoSampleZ | PowerTest::TwoProportions => {
+            n + allocated_size(n, ratio)
+        }
+        PowerTest::Anova { num_groups } => n * *num_groups as f64,
+        _ => n,
+    }
+}
+
+/// Finds the root of an increasing function `f` on `[low, high]` where `f(low) < 0 <= f(high)`.
+pub(crate) fn bisect<F: Fn(f64) -> f64>(f: F, mut low: f64, mut high: f64) -> f64 {
+    for _ in 0..BISECT_ITERATIONS {
+        let mid = (low + high) / 2.0;
+        if f(mid) >= 0.0 {
+            high = mid;
+        } else {
+            low = mid;
+        }
+        if high - low <= 1e-12 * high.abs().max(1.0) {
+            break;
+        }
+    }
+    high
+}
+
+fn check_design(design: &PowerDesign) -> Result<(), StatError> {
+    let missing = [
+        design.effect_size.is_none(),
+        design.alpha.is_none(),
+        design.power.is_none(),
+        design.n.is_none(),
+    ]
+    .iter()
+    .filter(|&&m| m)
+    .count();
+    if missing != 1 {
+        return Err(StatError::ComputeError(
+            "Exactly one of effect_size, alpha, power and n must be None".to_string(),
+        ));
+    }
+
+    if design
+        .alpha
+        .is_some_and(|alpha| alpha <= 0.0 || alpha >= 1.0)
+    {
+        return Err(StatError::ComputeError(
+            "Alpha must be between 0 and 1".to_string(),
+        ));
+    }
+    if design
+        .power
+        .is_some_and(|power| power <= 0.0 || power >= 1.0)
+    {
+        return Err(StatError::ComputeError(
+            "Power must be between 0 and 1".to_string(),
+        ));
+    }
+    if design.n.is_some_and(|n| n < min_sample_size(&design.test)) {
+        return Err(StatError::InsufficientData);
+    }
+    if design.effect_size.is_some_and(|d| !d.is_finite()) {
+        return Err(StatError::ComputeError(
+            "Effect size must be finite".to_string(),
+        ));
+    }
+    if !(design.ratio > 0.0 && design.ratio.is_finite()) {
+        return Err(StatError::ComputeError(
+            "Allocation ratio must be positive".to_string(),
+        ));
+    }
+
+    match design.test {
+        PowerTest::Anova { num_groups } if num_groups < 2 => Err(StatError::ComputeError(
+            "ANOVA requires at least two groups".to_string(),
+        )),
+        PowerTest::ChiSquare { df: 0 } => Err(StatError::ComputeError(
+            "Chi-square test requires at least one degree of freedom".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+impl PowerDesign {
+    /// Calculates the power of the design at the given effect size, alpha and sample size,
+    /// ignoring the `power` field.
+    ///
+    /// # Returns
+    ///
+    /// The probability of rejecting the null hypothesis, or `NaN` if any of `effect_size`,
+    /// `alpha` or `n` is missing or invalid.
+    pub fn power_at(&self, effect_size: f64, alpha: f64, n: f64) -> f64 {
+        design_power(&self.test, effect_size, alpha, n, self.ratio, &self.tail)
+    }
+
+    /// Solves the design for its single missing quantity.
+    ///
+    /// - A missing `power` is calculated exactly from the noncentral distribution of the test
+    ///   statistic.
+    /// - A missing `n` is the smallest whole sample size reaching the desired power.
+    /// - A missing `effect_size` is the minimum detectable effect: the smallest effect (in the
+    ///   direction of the tail) reaching the desired power.
+    /// - A missing `alpha` is the smallest significance level reaching the desired power.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `PowerSolution` with every quantity filled in, or a `StatError`
+    /// if the design does not have exactly one missing quantity, has invalid inputs, or the
+    /// desired power cannot be reached.
+    ///
+    /// # Example
+    /// ```rust
+    /// use hypors::power::{PowerDesign, PowerTest};
+    /// use hypors::common::TailType;
+    ///
+    /// let design = PowerDesign {
+    ///     test: PowerTest::OneSampleT,
+    ///     effect_size: None,
+    ///     alpha: Some(0.05),
+    ///     power: Some(0.80),
+    ///     n: Some(34.0),
+    ///     ratio: 1.0,
+    ///     tail: TailType::Two,
+    /// };
+    ///
+    /// let solution = design.solve().unwrap();
+    /// assert!((solution.effect_size - 0.4950).abs() < 1e-3);
+    /// ```
+    pub fn solve(&self) -> Result<PowerSolution, StatError> {
+        check_design(self)?;
+
+        let (effect_size, alpha, power, n) =
+            match (self.effect_size, self.alpha, self.power, self.n) {
+                (Some(effect_size), Some(alpha), None, Some(n)) => {
+                    let power = self.power_at(effect_size, alpha, n);
+                    (effect_size, alpha, power, n)
+                }
+                (Some(effect_size), Some(alpha), Some(power), None) => {
+                    if effect_size == 0.0 {
+                        return Err(StatError::ComputeError(
+                            "Effect size must be nonzero to solve for n".to_string(),
+                        ));
+                    }
+                    let n = solve_sample_size(
+                        |n| self.power_at(effect_size, alpha, n),
+                        alpha,
+                        power,
+                        min_sample_size(&self.test),
+                    );
+                    if !n.is_finite() {
+                        return Err(StatError::ComputeError(
+                            "Desired power cannot be reached with a finite sample size".to_string(),
+                        ));
+                    }
+                    (effect_size, alpha, self.power_at(effect_size, alpha, n), n)
+                }
+                (None, Some(alpha), Some(power), Some(n)) => {
+                    if power <= alpha {
+                        return Err(StatError::ComputeError(
+                            "Power must exceed alpha to solve for the effect size".to_string(),
+                        ));
+                    }
+                    let mut high = 1.0;
+                    while self.power_at(high, alpha, n) < power {
+                        high *= 2.0;
+                        if high > MAX_EFFECT {
+                            return Err(StatError::ComputeError(
+                                "Desired power cannot be reached at this sample size".to_string(),
+                            ));
+                        }
+                    }
+                    let effect = bisect(|d| self.power_at(d, alpha, n) - power, 0.0, high);
+                    let effect_size = match self.tail {
+                        TailType::Left => -effect,
+                        _ => effect,
+                    };
+                    (effect_size, alpha, self.power_at(effect, alpha, n), n)
+                }
+                (Some(effect_size), None, Some(power), Some(n)) => {
+                    let high = 1.0 - 1e-12;
+                    if self.power_at(effect_size, high, n) < power {
+                        return Err(StatError::ComputeError(
+                            "Desired power cannot be reached at any significance level".to_string(),
+                        ));
+                    }
+                    let alpha = bisect(|a| self.power_at(effect_size, a, n) - power, 0.0, high);
+                    (effect_size, alpha, self.power_at(effect_size, alpha, n), n)
+                }
+                _ => unreachable!("check_design guarantees exactly one missing quantity"),
+            };
+
+        Ok(PowerSolution {
+            effect_size,
+            alpha,
+            power,
+            n,
+            n_total: total_sample_size(&self.test, n, self.ratio),
+        })
+    }
+}
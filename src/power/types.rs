@@ -0,0 +1,147 @@
+use crate::common::TailType;
+use rand::distributions::Distribution;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+
+/// Represents the test a power analysis is carried out for, together with the scale of its
+/// effect size.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PowerTest {
+    /// One-sample t-test; effect size is Cohen's d.
+    OneSampleT,
+    /// Paired t-test; effect size is Cohen's d of the differences.
+    PairedT,
+    /// Independent two-sample t-test with pooled variance; effect size is Cohen's d.
+    TwoSampleT,
+    /// One-sample Z-test; effect size is Cohen's d.
+    OneSampleZ,
+    /// Paired Z-test; effect size is Cohen's d of the differences.
+    PairedZ,
+    /// Independent two-sample Z-test; effect size is Cohen's d.
+    TwoSampleZ,
+    /// Two-sample test of proportions; effect size is Cohen's h (see `cohens_h`).
+    TwoProportions,
+    /// One-way ANOVA with equal group sizes; effect size is Cohen's f.
+    Anova { num_groups: usize },
+    /// Chi-square goodness-of-fit or independence test; effect size is Cohen's w (see `cohens_w`).
+    ChiSquare { df: usize },
+}
+
+/// Describes a study design for power analysis.
+///
+/// Exactly one of `effect_size`, `alpha`, `power` and `n` must be `None`; `PowerDesign::solve`
+/// finds the missing quantity from the other three.
+///
+/// # Fields
+///
+/// * `test` - The test being planned.
+/// * `effect_size` - The standardized effect size, on the scale given by `test`.
+/// * `alpha` - The significance level.
+/// * `power` - The statistical power.
+/// * `n` - The sample size: of the first group for two-sample tests, per group for ANOVA, and
+///   in total for chi-square tests.
+/// * `ratio` - The allocation ratio `n2 / n1` for two-sample tests (ignored otherwise); the
+///   second group has `ceil(ratio * n)` units.
+/// * `tail` - The type of tail (left, right, or two) for the test. Chi-square and ANOVA tests
+///   are always right-tailed.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::power::{PowerDesign, PowerTest};
+/// use hypors::common::TailType;
+///
+/// let design = PowerDesign {
+///     test: PowerTest::TwoSampleT,
+///     effect_size: Some(0.5),
+///     alpha: Some(0.05),
+///     power: Some(0.80),
+///     n: None,
+///     ratio: 1.0,
+///     tail: TailType::Two,
+/// };
+///
+/// let solution = design.solve().unwrap();
+/// assert_eq!(solution.n, 64.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PowerDesign {
+    pub test: PowerTest,
+    pub effect_size: Option<f64>,
+    pub alpha: Option<f64>,
+    pub power: Option<f64>,
+    pub n: Option<f64>,
+    pub ratio: f64,
+    pub tail: TailType,
+}
+
+/// Stores a fully specified design after solving for the missing quantity.
+///
+/// # Fields
+///
+/// * `effect_size` - The standardized effect size.
+/// * `alpha` - The significance level.
+/// * `power` - The achieved power at the other three values.
+/// * `n` - The sample size, as defined for `PowerDesign::n`.
+/// * `n_total` - The total number of observations across all groups.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PowerSolution {
+    pub effect_size: f64,
+    pub alpha: f64,
+    pub power: f64,
+    pub n: f64,
+    pub n_total: f64,
+}
+
+/// Describes one group of a power simulation: the distribution its observations are drawn
+/// from and the number of observations per simulated sample.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::power::SimulationGroup;
+/// use statrs::distribution::{Exp, Normal};
+///
+/// let control = SimulationGroup::new(Normal::new(0.0, 1.0).unwrap(), 30);
+/// let treatment = SimulationGroup::new(Exp::new(1.0).unwrap(), 30);
+/// assert_eq!(control.n, 30);
+/// ```
+pub struct SimulationGroup {
+    pub n: usize,
+    sampler: Box<dyn Fn(&mut StdRng) -> f64 + Send + Sync>,
+}
+
+impl SimulationGroup {
+    /// Creates a group drawing `n` observations from `dist`, which may be any `statrs`
+    /// distribution (or other `rand` distribution) over `f64`.
+    pub fn new<D>(dist: D, n: usize) -> Self
+    where
+        D: Distribution<f64> + Send + Sync + 'static,
+    {
+        SimulationGroup {
+            n,
+            sampler: Box::new(move |rng| dist.sample(rng)),
+        }
+    }
+
+    /// Draws one simulated sample of the group.
+    pub(crate) fn draw(&self, rng: &mut StdRng) -> Vec<f64> {
+        (0..self.n).map(|_| (self.sampler)(rng)).collect()
+    }
+}
+
+/// Stores the result of a power simulation.
+///
+/// # Fields
+///
+/// * `power` - The proportion of simulated samples in which the null hypothesis was rejected.
+/// * `confidence_interval` - The Clopper-Pearson confidence interval for the power.
+/// * `n_rejections` - The number of simulated samples in which the null hypothesis was rejected.
+/// * `n_simulations` - The number of simulated samples.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationResult {
+    pub power: f64,
+    pub confidence_interval: (f64, f64),
+    pub n_rejections: usize,
+    pub n_simulations: usize,
+}
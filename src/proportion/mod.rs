@@ -5,15 +5,26 @@
 //! Proportion tests are used to determine if there is a significant difference
 //! between the proportions of two groups or to test a single proportion against a known value.
 //!
+//! ## Paired Binary Data
+//!
+//! When the same units are measured more than once, the samples are not independent:
+//! - `mcnemar_test`: Compares two paired binary outcomes (asymptotic, continuity-corrected or exact).
+//! - `mcnemar_bowker`: Tests the symmetry of a k×k table of paired categorical outcomes.
+//! - `cochran_q`: Compares k related binary outcomes.
+//!
 //! ## Sample Size Calculation
 //!
 //! To calculate the required sample size for proportion tests, you can use the following functions:
-//! - `prop_sample_size`: Calculates the required sample size for proportion tests, including both one-sample and two-sample tests.
+//! - `prop_sample_size`: Calculates the required sample size per group for two-sample proportion tests, iterating on power.
+//! - `prop_power`: Calculates the power of a two-sample proportion test.
+//! - `prop_sample_size_ind`: Calculates per-group sample sizes with an allocation ratio and a choice of tail.
 //!
 //! ## Submodules
 //!
 //! - `one_sample`: Contains functions for conducting one-sample proportion tests.
 //! - `two_sample`: Contains functions for conducting two-sample proportion tests.
+//! - `paired`: Contains McNemar's test, the McNemar-Bowker test and Cochran's Q.
+//! - `types`: Defines the `McNemarMethod` enumeration.
 //!
 //! ## Exports
 //!
@@ -21,16 +32,28 @@
 //!
 //! - `z_test`: Performs a one-sample proportion test.
 //! - `z_test_ind`: Performs a two-sample independent proportion test.
+//! - `mcnemar_test`: Performs McNemar's test for paired binary outcomes.
+//! - `mcnemar_bowker`: Performs the McNemar-Bowker test of symmetry.
+//! - `cochran_q`: Performs Cochran's Q test for k related binary outcomes.
+//! - `McNemarMethod`: Asymptotic, continuity-corrected or exact.
+//! - `prop_sample_size`: Calculates the required sample size for proportion tests.
+//! - `prop_power`: Calculates the power of proportion tests.
+//! - `prop_power_ind`: Calculates the power of proportion tests with unequal groups and a choice of tail.
+//! - `prop_sample_size_ind`: Calculates the required per-group sample sizes with an allocation ratio.
 //!
 //! ## Example
 //! ```rust
-//! use hypors::proportion::{z_test, z_test_ind, prop_sample_size};
+//! use hypors::proportion::{z_test, z_test_ind, mcnemar_test, mcnemar_bowker, cochran_q, McNemarMethod, prop_power, prop_power_ind, prop_sample_size, prop_sample_size_ind};
 //! ```
 
 pub mod one_sample;
+pub mod paired;
 pub mod sample_size;
 pub mod two_sample;
+pub mod types;
 
 pub use one_sample::z_test;
-pub use sample_size::prop_sample_size;
+pub use paired::{cochran_q, mcnemar_bowker, mcnemar_test};
+pub use sample_size::{prop_power, prop_power_ind, prop_sample_size, prop_sample_size_ind};
 pub use two_sample::z_test_ind;
+pub use types::McNemarMethod;
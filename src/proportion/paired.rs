@@ -0,0 +1,302 @@
+use super::types::McNemarMethod;
+use crate::common::{StatError, TailType, TestResult, calculate_p};
+use statrs::distribution::{Binomial, ChiSquared, ContinuousCDF, DiscreteCDF, Normal};
+
+/// Performs McNemar's test for two paired binary outcomes.
+///
+/// Each pair is one unit measured twice, such as two models scored on the same users. Only the
+/// discordant pairs carry information: `b` pairs with `1` in the first sample and `0` in the
+/// second, and `c` pairs the other way round. Under the null hypothesis of equal marginal
+/// proportions, each discordant pair is equally likely to go either way.
+///
+/// # Arguments
+///
+/// * `data1` - Iterator of binary values for the first measurement (e.g., 0/1, bool).
+/// * `data2` - Iterator of binary values for the second measurement, paired with `data1`.
+/// * `method` - How to compute the p-value.
+/// * `alpha` - The significance level (e.g., 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` with the chi-square statistic (or `b` for the exact method), the two-sided
+/// p-value, and the Wald confidence interval for the difference in paired proportions
+/// `(b - c) / n`.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the samples are empty, have different lengths, or contain values
+/// other than 0 and 1.
+///
+/// # Example
+/// ```rust
+/// use hypors::proportion::{mcnemar_test, McNemarMethod};
+///
+/// let model_a = vec![1, 1, 0, 1, 1, 0, 1, 1, 1, 0];
+/// let model_b = vec![1, 0, 0, 0, 1, 0, 0, 1, 0, 0];
+///
+/// let result = mcnemar_test(model_a, model_b, McNemarMethod::Exact, 0.05).unwrap();
+/// assert!((result.p_value - 0.125).abs() < 1e-12); // 4 discordant pairs, all one way
+/// ```
+pub fn mcnemar_test<I1, I2, T>(
+    data1: I1,
+    data2: I2,
+    method: McNemarMethod,
+    alpha: f64,
+) -> Result<TestResult, StatError>
+where
+    I1: IntoIterator<Item = T>,
+    I2: IntoIterator<Item = T>,
+    T: Into<f64>,
+{
+    let sample1: Vec<f64> = data1.into_iter().map(|x| x.into()).collect();
+    let sample2: Vec<f64> = data2.into_iter().map(|x| x.into()).collect();
+
+    if sample1.is_empty() || sample2.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    if sample1.len() != sample2.len() {
+        return Err(StatError::ComputeError(format!(
+            "Sample sizes must be equal: {} vs {}",
+            sample1.len(),
+            sample2.len()
+        )));
+    }
+    if sample1
+        .iter()
+        .chain(&sample2)
+        .any(|&x| x != 0.0 && x != 1.0)
+    {
+        return Err(StatError::ComputeError(
+            "Values must be binary (0 or 1)".to_string(),
+        ));
+    }
+
+    let n = sample1.len() as f64;
+    let b = sample1
+        .iter()
+        .zip(&sample2)
+        .filter(|(x1, x2)| **x1 == 1.0 && **x2 == 0.0)
+        .count() as f64;
+    let c = sample1
+        .iter()
+        .zip(&sample2)
+        .filter(|(x1, x2)| **x1 == 0.0 && **x2 == 1.0)
+        .count() as f64;
+    let discordant = b + c;
+
+    let (test_statistic, p_value) = if discordant == 0.0 {
+        (0.0, 1.0)
+    } else {
+        match method {
+            McNemarMethod::Asymptotic | McNemarMethod::ContinuityCorrected => {
+                let correction = if method == McNemarMethod::ContinuityCorrected {
+                    1.0
+                } else {
+                    0.0
+                };
+                let statistic = ((b - c).abs() - correction).max(0.0).powi(2) / discordant;
+                let chi_distribution = ChiSquared::new(1.0).map_err(|e| {
+                    StatError::ComputeError(format!("Chi-squared distribution error: {e}"))
+                })?;
+                (
+                    statistic,
+                    calculate_p(statistic, TailType::Right, &chi_distribution),
+                )
+            }
+            McNemarMethod::Exact => {
+                let binomial = Binomial::new(0.5, discordant as u64).map_err(|e| {
+                    StatError::ComputeError(format!("Binomial distribution error: {e}"))
+                })?;
+                let p_value = (2.0 * binomial.cdf(b.min(c) as u64)).min(1.0);
+                (b, p_value)
+            }
+        }
+    };
+
+    // Wald interval for the difference in paired proportions
+    let z_dist = Normal::new(0.0, 1.0).map_err(|e| {
+        StatError::ComputeError(format!("Failed to create Normal distribution: {e}"))
+    })?;
+    let difference = (b - c) / n;
+    let std_error = (discordant - (b - c).powi(2) / n).max(0.0).sqrt() / n;
+    let margin = z_dist.inverse_cdf(1.0 - alpha / 2.0) * std_error;
+
+    Ok(TestResult {
+        test_statistic,
+        p_value,
+        confidence_interval: (difference - margin, difference + margin),
+        null_hypothesis: "H0: p1 = p2".to_string(),
+        alt_hypothesis: "Ha: p1 ≠ p2".to_string(),
+        reject_null: p_value < alpha,
+    })
+}
+
+/// Performs the McNemar-Bowker test of symmetry for a k×k table of paired categorical outcomes.
+///
+/// Cell `(i, j)` counts the units in category `i` at the first measurement and category `j` at
+/// the second. The statistic `Σ_{i<j} (n_ij - n_ji)² / (n_ij + n_ji)` is compared with a
+/// chi-square distribution with `k(k - 1)/2` degrees of freedom; pairs of cells that are both
+/// empty contribute nothing. For a 2x2 table it equals McNemar's asymptotic statistic.
+///
+/// # Arguments
+///
+/// * `table` - The k×k table of paired counts.
+/// * `alpha` - The significance level (e.g., 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` with the chi-square statistic and p-value; the confidence interval is not
+/// applicable and returns `(NaN, NaN)`.
+///
+/// # Errors
+///
+/// Returns a `StatError` if the table is not square with at least two categories, or a count
+/// is negative.
+///
+/// # Example
+/// ```rust
+/// use hypors::proportion::mcnemar_bowker;
+///
+/// let table = vec![
+///     vec![20.0, 5.0, 2.0],
+///     vec![10.0, 30.0, 3.0],
+///     vec![6.0, 9.0, 15.0],
+/// ];
+/// let result = mcnemar_bowker(&table, 0.05).unwrap();
+/// assert!((result.test_statistic - (25.0 / 15.0 + 16.0 / 8.0 + 36.0 / 12.0)).abs() < 1e-12);
+/// ```
+pub fn mcnemar_bowker(table: &[Vec<f64>], alpha: f64) -> Result<TestResult, StatError> {
+    let k = table.len();
+    if k < 2 || table.iter().any(|row| row.len() != k) {
+        return Err(StatError::ComputeError(
+            "Table must be square with at least two categories".into(),
+        ));
+    }
+    if table.iter().flatten().any(|&x| x < 0.0) {
+        return Err(StatError::ComputeError(
+            "Counts must be non-negative".into(),
+        ));
+    }
+
+    let test_statistic: f64 = (0..k)
+        .flat_map(|i| ((i + 1)..k).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let total = table[i][j] + table[j][i];
+            if total > 0.0 {
+                (table[i][j] - table[j][i]).powi(2) / total
+            } else {
+                0.0
+            }
+        })
+        .sum();
+
+    let df = (k * (k - 1) / 2) as f64;
+    let chi_distribution = ChiSquared::new(df)
+        .map_err(|e| StatError::ComputeError(format!("Chi-squared distribution error: {e}")))?;
+    let p_value = calculate_p(test_statistic, TailType::Right, &chi_distribution);
+
+    Ok(TestResult {
+        test_statistic,
+        p_value,
+        confidence_interval: (f64::NAN, f64::NAN),
+        null_hypothesis: "H0: The table is symmetric".into(),
+        alt_hypothesis: "Ha: The table is not symmetric".into(),
+        reject_null: p_value < alpha,
+    })
+}
+
+/// Performs Cochran's Q test for k related binary outcomes.
+///
+/// Each group holds one treatment's binary outcomes for the same units in the same order, such
+/// as the conversions of the same users under k UI flows. The statistic
+/// `Q = (k - 1)(k ΣC_j² - N²) / (kN - ΣR_i²)`, with treatment totals `C_j`, unit totals `R_i`
+/// and grand total `N`, is compared with a chi-square distribution with `k - 1` degrees of
+/// freedom. For two treatments it equals McNemar's asymptotic statistic.
+///
+/// # Arguments
+///
+/// * `data_groups` - One slice of binary outcomes per treatment, all of the same length.
+/// * `alpha` - The significance level (e.g., 0.05).
+///
+/// # Returns
+///
+/// A `TestResult` with the Q statistic and p-value; the confidence interval is not applicable
+/// and returns `(NaN, NaN)`.
+///
+/// # Errors
+///
+/// Returns a `StatError` if there are fewer than two treatments, the groups are empty or of
+/// different lengths, a value is not 0 or 1, or every unit has the same outcome under all
+/// treatments.
+///
+/// # Example
+/// ```rust
+/// use hypors::proportion::cochran_q;
+///
+/// let flow_a = vec![1, 1, 0, 1, 1, 1, 0, 1];
+/// let flow_b = vec![1, 0, 0, 1, 0, 1, 0, 0];
+/// let flow_c = vec![0, 0, 0, 1, 0, 1, 0, 0];
+///
+/// let result = cochran_q(&[flow_a, flow_b, flow_c], 0.05).unwrap();
+/// assert!(result.reject_null);
+/// ```
+pub fn cochran_q<T, I>(data_groups: &[I], alpha: f64) -> Result<TestResult, StatError>
+where
+    T: Into<f64> + Copy,
+    I: AsRef<[T]>,
+{
+    let k = data_groups.len();
+    if k < 2 {
+        return Err(StatError::ComputeError(
+            "Cochran's Q requires at least two treatments".into(),
+        ));
+    }
+    let groups: Vec<Vec<f64>> = data_groups
+        .iter()
+        .map(|group| group.as_ref().iter().map(|&x| x.into()).collect())
+        .collect();
+    let n = groups[0].len();
+    if n == 0 {
+        return Err(StatError::EmptyData);
+    }
+    if groups.iter().any(|group| group.len() != n) {
+        return Err(StatError::ComputeError(
+            "All treatments must have the same number of units".into(),
+        ));
+    }
+    if groups.iter().flatten().any(|&x| x != 0.0 && x != 1.0) {
+        return Err(StatError::ComputeError(
+            "Values must be binary (0 or 1)".into(),
+        ));
+    }
+
+    let k = k as f64;
+    let treatment_totals: Vec<f64> = groups.iter().map(|group| group.iter().sum()).collect();
+    let unit_totals: Vec<f64> = (0..n)
+        .map(|i| groups.iter().map(|group| group[i]).sum())
+        .collect();
+    let grand_total: f64 = treatment_totals.iter().sum();
+
+    let denominator = k * grand_total - unit_totals.iter().map(|r| r.powi(2)).sum::<f64>();
+    if denominator == 0.0 {
+        return Err(StatError::ComputeError(
+            "Every unit has the same outcome under all treatments".into(),
+        ));
+    }
+    let test_statistic = (k - 1.0)
+        * (k * treatment_totals.iter().map(|c| c.powi(2)).sum::<f64>() - grand_total.powi(2))
+        / denominator;
+
+    let chi_distribution = ChiSquared::new(k - 1.0)
+        .map_err(|e| StatError::ComputeError(format!("Chi-squared distribution error: {e}")))?;
+    let p_value = calculate_p(test_statistic, TailType::Right, &chi_distribution);
+
+    Ok(TestResult {
+        test_statistic,
+        p_value,
+        confidence_interval: (f64::NAN, f64::NAN),
+        null_hypothesis: "H0: All treatments have the same proportion".into(),
+        alt_hypothesis: "Ha: At least one treatment proportion differs".into(),
+        reject_null: p_value < alpha,
+    })
+}
@@ -1,9 +1,90 @@
+use crate::common::TailType;
+use crate::common::calc::{solve_allocated_sample_size, solve_sample_size};
 use statrs::distribution::{ContinuousCDF, Normal};
 
+/// Calculates the power of a two-sided, two-sample test of proportions.
+///
+/// The test statistic uses the pooled standard error under the null hypothesis and the
+/// unpooled standard error under the alternative, and both rejection regions are counted.
+///
+/// # Arguments
+///
+/// * `p1` - The expected proportion in the first group.
+/// * `p2` - The expected proportion in the second group.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `n` - The sample size per group.
+///
+/// # Returns
+///
+/// The probability of rejecting the null hypothesis of equal proportions.
+///
+/// # Example
+/// ```rust
+/// use hypors::proportion::prop_power;
+///
+/// let power = prop_power(0.4, 0.5, 0.05, 388.0);
+/// assert!((power - 0.8005).abs() < 1e-3);
+/// ```
+pub fn prop_power(p1: f64, p2: f64, alpha: f64, n: f64) -> f64 {
+    prop_power_ind(p1, p2, alpha, n, n, TailType::Two)
+}
+
+/// Calculates the power of a two-sample test of proportions with unequal group sizes.
+///
+/// The test statistic uses the pooled standard error under the null hypothesis and the
+/// unpooled standard error under the alternative. A right-tailed test has the alternative
+/// `p2 > p1` and a left-tailed test `p2 < p1`.
+///
+/// # Arguments
+///
+/// * `p1` - The expected proportion in the first group.
+/// * `p2` - The expected proportion in the second group.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `n1` - The sample size of the first group.
+/// * `n2` - The sample size of the second group.
+/// * `tail` - The type of tail (left, right, or two) for the test.
+///
+/// # Returns
+///
+/// The probability of rejecting the null hypothesis of equal proportions.
+///
+/// # Example
+/// ```rust
+/// use hypors::proportion::prop_power_ind;
+/// use hypors::common::TailType;
+///
+/// let power = prop_power_ind(0.4, 0.5, 0.05, 306.0, 306.0, TailType::Right);
+/// assert!((power - 0.8012).abs() < 1e-3);
+/// ```
+pub fn prop_power_ind(p1: f64, p2: f64, alpha: f64, n1: f64, n2: f64, tail: TailType) -> f64 {
+    let normal = Normal::new(0.0, 1.0).expect("Failed to create Normal distribution");
+    let p = (n1 * p1 + n2 * p2) / (n1 + n2); // Pooled proportion
+
+    let se_null = (p * (1.0 - p) * (1.0 / n1 + 1.0 / n2)).sqrt();
+    let se_alt = (p1 * (1.0 - p1) / n1 + p2 * (1.0 - p2) / n2).sqrt();
+    let diff = p2 - p1;
+
+    match tail {
+        TailType::Right => {
+            let z_alpha = normal.inverse_cdf(1.0 - alpha);
+            normal.cdf((diff - z_alpha * se_null) / se_alt)
+        }
+        TailType::Left => {
+            let z_alpha = normal.inverse_cdf(1.0 - alpha);
+            normal.cdf((-diff - z_alpha * se_null) / se_alt)
+        }
+        TailType::Two => {
+            let z_alpha = normal.inverse_cdf(1.0 - alpha / 2.0);
+            normal.cdf((diff - z_alpha * se_null) / se_alt)
+                + normal.cdf((-diff - z_alpha * se_null) / se_alt)
+        }
+    }
+}
+
 /// Calculates the required sample size for a test of proportions.
 ///
-/// This function computes the necessary sample size to detect a minimum detectable difference
-/// in proportions for a given alpha, power, and expected proportions.
+/// This function finds the smallest sample size per group whose power, evaluated with
+/// `prop_power`, reaches the desired power for the expected proportions and alpha.
 ///
 /// # Arguments
 ///
@@ -14,7 +95,8 @@ use statrs::distribution::{ContinuousCDF, Normal};
 ///
 /// # Returns
 ///
-/// The estimated sample size required to achieve the specified power and significance level.
+/// The sample size per group required to achieve the specified power and significance level,
+/// or `NaN` if the inputs are invalid.
 ///
 /// # Example
 /// ```rust
@@ -26,20 +108,57 @@ use statrs::distribution::{ContinuousCDF, Normal};
 /// let power = 0.80; // 80% power
 ///
 /// let sample_size = prop_sample_size(p1, p2, alpha, power);
-/// println!("Required sample size: {}", sample_size);
+/// assert_eq!(sample_size, 388.0);
 /// ```
 pub fn prop_sample_size(p1: f64, p2: f64, alpha: f64, power: f64) -> f64 {
-    let p = (p1 + p2) / 2.0; // Pooled proportion
-    let z_alpha = Normal::new(0.0, 1.0)
-        .unwrap()
-        .inverse_cdf(1.0 - alpha / 2.0); // Two-tailed
-    let z_beta = Normal::new(0.0, 1.0).unwrap().inverse_cdf(power);
-
-    // Formula: n = ((z_alpha * sqrt(2 * p * (1 - p)) + z_beta * sqrt(p1 * (1 - p1) + p2 * (1 - p2)))^2) / (p2 - p1)^2
-    let n = (z_alpha * (2.0 * p * (1.0 - p)).sqrt()
-        + z_beta * ((p1 * (1.0 - p1)) + (p2 * (1.0 - p2))).sqrt())
-    .powi(2)
-        / (p2 - p1).powi(2);
+    solve_sample_size(|n| prop_power(p1, p2, alpha, n), alpha, power, 1.0)
+}
 
-    n.ceil() // Rounds up to the next whole sample size
+/// Calculates the required per-group sample sizes for a two-sample test of proportions.
+///
+/// The second group receives `ratio` times as many units as the first (rounded up), so a
+/// 90/10 split is `ratio = 9.0` or `ratio = 1.0 / 9.0`. The first group size is the smallest
+/// whose power, evaluated with `prop_power_ind`, reaches the desired power. A right-tailed test
+/// has the alternative `p2 > p1` and a left-tailed test `p2 < p1`.
+///
+/// # Arguments
+///
+/// * `p1` - The expected proportion in the first group.
+/// * `p2` - The expected proportion in the second group.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `power` - The desired statistical power (e.g., 0.80 for 80% power).
+/// * `ratio` - The allocation ratio `n2 / n1`.
+/// * `tail` - The type of tail (left, right, or two) for the test.
+///
+/// # Returns
+///
+/// A tuple `(n1, n2)` of the sample sizes required in each group, or infinite sizes if the
+/// proportions lie on the wrong side of each other for a one-tailed test.
+///
+/// # Example
+/// ```rust
+/// use hypors::proportion::prop_sample_size_ind;
+/// use hypors::common::TailType;
+///
+/// let (n1, n2) = prop_sample_size_ind(0.4, 0.5, 0.05, 0.80, 1.0, TailType::Two);
+/// assert_eq!((n1, n2), (388.0, 388.0));
+///
+/// let (n1, n2) = prop_sample_size_ind(0.4, 0.5, 0.05, 0.80, 1.0 / 9.0, TailType::Right);
+/// assert_eq!((n1, n2), (1504.0, 168.0));
+/// ```
+pub fn prop_sample_size_ind(
+    p1: f64,
+    p2: f64,
+    alpha: f64,
+    power: f64,
+    ratio: f64,
+    tail: TailType,
+) -> (f64, f64) {
+    solve_allocated_sample_size(
+        |n1, n2| prop_power_ind(p1, p2, alpha, n1, n2, tail.clone()),
+        alpha,
+        power,
+        ratio,
+        1.0,
+    )
 }
@@ -0,0 +1,11 @@
+/// Represents the method used to compute the p-value of McNemar's test.
+#[derive(Debug, Clone, PartialEq)]
+pub enum McNemarMethod {
+    /// The chi-square approximation, `(b - c)² / (b + c)`.
+    Asymptotic,
+    /// The chi-square approximation with Edwards' continuity correction,
+    /// `(|b - c| - 1)² / (b + c)`.
+    ContinuityCorrected,
+    /// The exact binomial test of the discordant pairs, suitable when `b + c` is small.
+    Exact,
+}
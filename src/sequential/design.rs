@@ -0,0 +1,283 @@
+use super::integrate::{Grid, bisect_decreasing};
+use super::types::{
+    BoundaryType, FutilityBound, SequentialDecision, SequentialDesign, SequentialLook,
+};
+use crate::common::{StatError, TailType};
+use statrs::distribution::{ContinuousCDF, Normal};
+
+/// Largest boundary considered; crossing probabilities beyond it are negligible.
+const MAX_BOUND: f64 = 40.0;
+
+/// Calculates the type I error crossed at each look under the null hypothesis for the given
+/// efficacy boundaries, ignoring futility (non-binding).
+fn null_crossings(info_fractions: &[f64], bounds: &[f64], two_sided: bool) -> Vec<f64> {
+    let mut grid = Grid::start();
+    let mut crossings = Vec::with_capacity(bounds.len());
+    for (&t, &c) in info_fractions.iter().zip(bounds) {
+        let lower = if two_sided { -c } else { f64::NEG_INFINITY };
+        let mut p = grid.cross_above(t, 0.0, c);
+        if two_sided {
+            p += grid.cross_below(t, 0.0, lower);
+        }
+        crossings.push(p);
+        grid = grid.advance(t, 0.0, lower, c);
+    }
+    crossings
+}
+
+/// Calculates efficacy boundaries for a one-sided (upper) or symmetric two-sided design.
+fn efficacy_bounds(
+    info_fractions: &[f64],
+    alpha: f64,
+    boundary: &BoundaryType,
+    two_sided: bool,
+) -> Vec<f64> {
+    match boundary {
+        BoundaryType::OBrienFleming | BoundaryType::Pocock => {
+            let shape: Vec<f64> = info_fractions
+                .iter()
+                .map(|&t| match boundary {
+                    BoundaryType::OBrienFleming => 1.0 / t.sqrt(),
+                    _ => 1.0,
+                })
+                .collect();
+            let total = |c: f64| -> f64 {
+                let bounds: Vec<f64> = shape.iter().map(|s| c * s).collect();
+                null_crossings(info_fractions, &bounds, two_sided)
+                    .iter()
+                    .sum()
+            };
+            let c = bisect_decreasing(|c| total(c) - alpha, 0.0, MAX_BOUND);
+            shape.iter().map(|s| c * s).collect()
+        }
+        BoundaryType::LanDeMets(spending) => {
+            let mut grid = Grid::start();
+            let mut spent = 0.0;
+            let mut bounds = Vec::with_capacity(info_fractions.len());
+            for &t in info_fractions {
+                // Two-tailed designs spend half of alpha on each side
+                let cumulative = if two_sided {
+                    2.0 * spending.spend(alpha / 2.0, t)
+                } else {
+                    spending.spend(alpha, t)
+                };
+                let target = cumulative - spent;
+                spent = cumulative;
+
+                let crossing = |c: f64| {
+                    let mut p = grid.cross_above(t, 0.0, c);
+                    if two_sided {
+                        p += grid.cross_below(t, 0.0, -c);
+                    }
+                    p
+                };
+                let c = bisect_decreasing(|c| crossing(c) - target, 0.0, MAX_BOUND);
+                bounds.push(c);
+
+                let lower = if two_sided { -c } else { f64::NEG_INFINITY };
+                grid = grid.advance(t, 0.0, lower, c);
+            }
+            bounds
+        }
+    }
+}
+
+/// Calculates non-binding futility boundaries for a one-sided (upper) design by beta spending.
+fn futility_bounds(info_fractions: &[f64], efficacy: &[f64], futility: &FutilityBound) -> Vec<f64> {
+    let drift = futility.drift;
+    let mut grid = Grid::start();
+    let mut spent = 0.0;
+    let mut bounds = Vec::with_capacity(info_fractions.len());
+
+    for (k, (&t, &c)) in info_fractions.iter().zip(efficacy).enumerate() {
+        let cumulative = futility.spending.spend(futility.beta, t);
+        let target = cumulative - spent;
+        spent = cumulative;
+
+        // The final look forces a decision, so the boundaries meet there
+        let b = if k + 1 == info_fractions.len() {
+            c
+        } else {
+            let low = -MAX_BOUND;
+            // Crossing probability is increasing in b, so bisect on its negation
+            -bisect_decreasing(|nb| grid.cross_below(t, drift, -nb) - target, -c, -low)
+        };
+        let b = b.min(c);
+        bounds.push(b);
+        grid = grid.advance(t, drift, b, c);
+    }
+    bounds
+}
+
+/// Constructs a group sequential design with efficacy and optional futility boundaries.
+///
+/// Efficacy boundaries control the overall type I error at `alpha` however many looks are
+/// taken, either with the classical O'Brien-Fleming or Pocock shapes or with a Lan-DeMets
+/// alpha-spending function. Futility boundaries are non-binding: the efficacy boundaries are
+/// computed as if the trial never stopped for futility, so ignoring a futility stop does not
+/// inflate the type I error. Crossing probabilities are evaluated by recursive numerical
+/// integration of the joint distribution of the Z-statistics.
+///
+/// # Arguments
+///
+/// * `info_fractions` - The fraction of the maximum information (usually sample size) at each
+///   look, strictly increasing and ending at 1.
+/// * `alpha` - The overall significance level (e.g., 0.05).
+/// * `tail` - The type of tail (left, right, or two) for the test.
+/// * `boundary` - The method used to derive the efficacy boundaries.
+/// * `futility` - The futility boundary specification, for one-tailed designs only.
+///
+/// # Returns
+///
+/// A `SequentialDesign` containing the boundaries of each look.
+///
+/// # Errors
+///
+/// Returns a `StatError` if there are no looks, the information fractions are not strictly
+/// increasing in `(0, 1]` and ending at 1, alpha or beta lie outside `(0, 1)`, or futility
+/// boundaries are requested for a two-tailed design.
+///
+/// # Example
+/// ```rust
+/// use hypors::sequential::{group_sequential_design, BoundaryType, SpendingFunction};
+/// use hypors::common::TailType;
+///
+/// let design = group_sequential_design(
+///     &[0.2, 0.4, 0.6, 0.8, 1.0],
+///     0.05,
+///     TailType::Two,
+///     BoundaryType::LanDeMets(SpendingFunction::OBrienFleming),
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert!((design.looks[0].efficacy_bound - 4.877).abs() < 1e-2);
+/// assert!((design.looks[4].efficacy_bound - 2.031).abs() < 1e-2);
+/// ```
+pub fn group_sequential_design(
+    info_fractions: &[f64],
+    alpha: f64,
+    tail: TailType,
+    boundary: BoundaryType,
+    futility: Option<FutilityBound>,
+) -> Result<SequentialDesign, StatError> {
+    if info_fractions.is_empty() {
+        return Err(StatError::EmptyData);
+    }
+    if info_fractions[0] <= 0.0
+        || info_fractions.windows(2).any(|w| w[1] <= w[0])
+        || (info_fractions[info_fractions.len() - 1] - 1.0).abs() > 1e-12
+    {
+        return Err(StatError::ComputeError(
+            "Information fractions must be strictly increasing in (0, 1] and end at 1".to_string(),
+        ));
+    }
+    if alpha <= 0.0 || alpha >= 1.0 {
+        return Err(StatError::ComputeError(
+            "Alpha must be between 0 and 1".to_string(),
+        ));
+    }
+    if let Some(f) = &futility {
+        if tail == TailType::Two {
+            return Err(StatError::ComputeError(
+                "Futility boundaries require a one-tailed design".to_string(),
+            ));
+        }
+        if f.beta <= 0.0 || f.beta >= 1.0 {
+            return Err(StatError::ComputeError(
+                "Beta must be between 0 and 1".to_string(),
+            ));
+        }
+    }
+
+    let two_sided = tail == TailType::Two;
+    let efficacy = efficacy_bounds(info_fractions, alpha, &boundary, two_sided);
+    let futility = futility.map(|f| futility_bounds(info_fractions, &efficacy, &f));
+    let crossings = null_crossings(info_fractions, &efficacy, two_sided);
+
+    // Left-tailed designs mirror the upper boundaries
+    let sign = if tail == TailType::Left { -1.0 } else { 1.0 };
+    let normal = Normal::new(0.0, 1.0).expect("Failed to create Normal distribution");
+    let mut cumulative_alpha = 0.0;
+    let looks = info_fractions
+        .iter()
+        .enumerate()
+        .map(|(k, &t)| {
+            cumulative_alpha += crossings[k];
+            let tail_prob = normal.sf(efficacy[k]);
+            SequentialLook {
+                info_fraction: t,
+                efficacy_bound: sign * efficacy[k],
+                futility_bound: futility.as_ref().map(|b| sign * b[k]),
+                nominal_alpha: if two_sided {
+                    2.0 * tail_prob
+                } else {
+                    tail_prob
+                },
+                cumulative_alpha,
+            }
+        })
+        .collect();
+
+    Ok(SequentialDesign { alpha, tail, looks })
+}
+
+impl SequentialDesign {
+    /// Decides whether to stop or continue at a look, given the Z-statistic observed there.
+    ///
+    /// The statistic should be computed on all data accumulated up to the look, e.g. the
+    /// `test_statistic` of `proportion::z_test_ind` or (for large samples) `t::t_test_ind`.
+    ///
+    /// # Arguments
+    ///
+    /// * `look` - The index of the look, starting at 0.
+    /// * `z_stat` - The Z-statistic observed at the look.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `SequentialDecision`, or a `StatError` if the look index is
+    /// out of range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use hypors::sequential::{group_sequential_design, BoundaryType, SequentialDecision};
+    /// use hypors::common::TailType;
+    ///
+    /// let design = group_sequential_design(
+    ///     &[0.5, 1.0], 0.05, TailType::Two, BoundaryType::Pocock, None,
+    /// )
+    /// .unwrap();
+    ///
+    /// // 2.1 would be significant in a single test, but not at the first of two looks
+    /// assert_eq!(design.decide(0, 2.1).unwrap(), SequentialDecision::Continue);
+    /// assert_eq!(design.decide(1, 2.1).unwrap(), SequentialDecision::AcceptNull);
+    /// assert_eq!(design.decide(1, -2.5).unwrap(), SequentialDecision::RejectNull);
+    /// ```
+    pub fn decide(&self, look: usize, z_stat: f64) -> Result<SequentialDecision, StatError> {
+        let bounds = self.looks.get(look).ok_or_else(|| {
+            StatError::ComputeError(format!(
+                "Look {look} is out of range for a design with {} looks",
+                self.looks.len()
+            ))
+        })?;
+
+        // Orient the statistic so that larger values favour the alternative
+        let (z, efficacy, futility) = match self.tail {
+            TailType::Right => (z_stat, bounds.efficacy_bound, bounds.futility_bound),
+            TailType::Left => (
+                -z_stat,
+                -bounds.efficacy_bound,
+                bounds.futility_bound.map(|b| -b),
+            ),
+            TailType::Two => (z_stat.abs(), bounds.efficacy_bound, None),
+        };
+
+        if z >= efficacy {
+            Ok(SequentialDecision::RejectNull)
+        } else if look + 1 == self.looks.len() || futility.is_some_and(|b| z <= b) {
+            Ok(SequentialDecision::AcceptNull)
+        } else {
+            Ok(SequentialDecision::Continue)
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Numerical integration of the joint distribution of sequentially monitored Z-statistics.
+//!
+//! The score statistic `S_k = Z_k √t_k` has independent normal increments with mean
+//! `drift * Δt` and variance `Δt`. The sub-density of `S_k` over the continuation region is
+//! carried from look to look on a Simpson grid (Armitage, McPherson and Rowe's recursion), from
+//! which the probability of crossing each boundary follows.
+
+use statrs::distribution::{Continuous, ContinuousCDF, Normal};
+
+/// Half the number of Simpson intervals on each grid.
+const HALF_INTERVALS: usize = 80;
+/// Number of standard deviations covered when a boundary is infinite.
+const SPAN: f64 = 10.0;
+
+/// The sub-density of the score statistic at a look, restricted to the continuation region.
+#[derive(Debug, Clone)]
+pub(crate) struct Grid {
+    /// The information fraction at which the grid is evaluated.
+    t: f64,
+    /// The score values of the grid points.
+    points: Vec<f64>,
+    /// The sub-density at each point multiplied by its quadrature weight.
+    mass: Vec<f64>,
+}
+
+impl Grid {
+    /// The distribution of the score statistic before any data is observed: a point mass at 0.
+    pub(crate) fn start() -> Self {
+        Grid {
+            t: 0.0,
+            points: vec![0.0],
+            mass: vec![1.0],
+        }
+    }
+
+    /// Calculates the probability of continuing to the next look at information fraction `t`
+    /// and then finding `Z >= upper` there.
+    pub(crate) fn cross_above(&self, t: f64, drift: f64, upper: f64) -> f64 {
+        let normal = standard_normal();
+        let dt = t - self.t;
+        let u = upper * t.sqrt();
+        self.points
+            .iter()
+            .zip(&self.mass)
+            .map(|(&s, &m)| m * normal.sf((u - s - drift * dt) / dt.sqrt()))
+            .sum()
+    }
+
+    /// Calculates the probability of continuing to the next look at information fraction `t`
+    /// and then finding `Z <= lower` there.
+    pub(crate) fn cross_below(&self, t: f64, drift: f64, lower: f64) -> f64 {
+        let normal = standard_normal();
+        let dt = t - self.t;
+        let l = lower * t.sqrt();
+        self.points
+            .iter()
+            .zip(&self.mass)
+            .map(|(&s, &m)| m * normal.cdf((l - s - drift * dt) / dt.sqrt()))
+            .sum()
+    }
+
+    /// Carries the sub-density to the next look at information fraction `t`, keeping only the
+    /// continuation region `lower < Z < upper` (either bound may be infinite).
+    pub(crate) fn advance(&self, t: f64, drift: f64, lower: f64, upper: f64) -> Self {
+        let normal = standard_normal();
+        let dt = t - self.t;
+        let sd = dt.sqrt();
+
+        // Clip infinite or distant boundaries to where the score has non-negligible density
+        let centre = drift * t;
+        let low = (lower * t.sqrt()).max(centre - SPAN * t.sqrt());
+        let high = (upper * t.sqrt()).min(centre + SPAN * t.sqrt());
+        if low >= high {
+            return Grid {
+                t,
+                points: Vec::new(),
+                mass: Vec::new(),
+            };
+        }
+
+        let intervals = 2 * HALF_INTERVALS;
+        let h = (high - low) / intervals as f64;
+        let points: Vec<f64> = (0..=intervals).map(|i| low + i as f64 * h).collect();
+        let mass = points
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| {
+                let weight = if i == 0 || i == intervals {
+                    1.0
+                } else if i % 2 == 1 {
+                    4.0
+                } else {
+                    2.0
+                } * h
+                    / 3.0;
+                let density: f64 = self
+                    .points
+                    .iter()
+                    .zip(&self.mass)
+                    .map(|(&s, &m)| m * normal.pdf((x - s - drift * dt) / sd) / sd)
+                    .sum();
+                weight * density
+            })
+            .collect();
+
+        Grid { t, points, mass }
+    }
+}
+
+fn standard_normal() -> Normal {
+    Normal::new(0.0, 1.0).expect("Failed to create Normal distribution")
+}
+
+/// Finds the root of a decreasing function `f` on `[low, high]`, returning `high` if `f` stays
+/// positive and `low` if it is never positive.
+pub(crate) fn bisect_decreasing<F: Fn(f64) -> f64>(f: F, mut low: f64, mut high: f64) -> f64 {
+    if f(high) > 0.0 {
+        return high;
+    }
+    if f(low) <= 0.0 {
+        return low;
+    }
+    for _ in 0..100 {
+        let mid = (low + high) / 2.0;
+        if f(mid) > 0.0 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+        if high - low < 1e-10 {
+            break;
+        }
+    }
+    (low + high) / 2.0
+}
@@ -0,0 +1,75 @@
+//! # Sequential Testing
+//!
+//! The `sequential` module provides group sequential designs, which allow data to be analysed
+//! at interim looks while controlling the overall type I error.
+//!
+//! Repeatedly applying a fixed-sample test such as `proportion::z_test_ind` or `t::t_test_ind`
+//! as data accumulates inflates the false-positive rate. A group sequential design instead
+//! compares the Z-statistic at each look with an efficacy boundary chosen so that the
+//! probability of ever crossing it under the null hypothesis is `alpha`, and optionally with a
+//! futility boundary for stopping early without rejecting.
+//!
+//! ## Boundaries
+//!
+//! - `OBrienFleming`: Classical O'Brien-Fleming boundaries, very conservative early on.
+//! - `Pocock`: Classical Pocock boundaries, constant across looks.
+//! - `LanDeMets`: Boundaries from an O'Brien-Fleming- or Pocock-type alpha-spending function,
+//!   which allow looks at unplanned information fractions.
+//!
+//! ## Always-Valid Inference
+//!
+//! For continuous monitoring without a fixed schedule of looks, `MsprtMeans` and
+//! `MsprtProportions` implement the mixture sequential probability ratio test (mSPRT). They
+//! are fed batches of summary statistics and return, at every look, an always-valid p-value
+//! and confidence sequence that remain valid at any stopping time.
+//!
+//! ## Wald's SPRT
+//!
+//! `Sprt` implements Wald's sequential probability ratio test of two simple hypotheses for
+//! Bernoulli, normal (known variance) and Poisson observations, returning a decision as each
+//! observation arrives, with the expected sample number (`asn`) and operating characteristic
+//! (`oc`) at any parameter value.
+//!
+//! ## Submodules
+//!
+//! - `design`: Contains the boundary calculations and the decision at each look.
+//! - `msprt`: Contains the mixture sequential probability ratio tests.
+//! - `sprt`: Contains Wald's sequential probability ratio test.
+//! - `integrate`: Contains the numerical integration of the sequential Z-statistics.
+//! - `types`: Defines the spending function, boundary, design and decision types.
+//!
+//! ## Exports
+//!
+//! The following functions and types are made available for use:
+//!
+//! - `group_sequential_design`: Constructs the efficacy and futility boundaries of a design.
+//! - `SpendingFunction`: The Lan-DeMets alpha- (or beta-) spending function.
+//! - `BoundaryType`: The method used to derive efficacy boundaries.
+//! - `FutilityBound`: The beta-spending specification of futility boundaries.
+//! - `SequentialDesign`: The boundaries at each look, with the `decide` method.
+//! - `SequentialLook`: The boundaries and error spent at a single look.
+//! - `SequentialDecision`: Whether to reject, continue or stop at a look.
+//! - `MsprtMeans`: The always-valid test for a difference in means.
+//! - `MsprtProportions`: The always-valid test for a difference in proportions.
+//! - `Sprt`: Wald's sequential probability ratio test.
+//! - `SprtModel`: The observation model and hypotheses of an SPRT.
+//!
+//! ## Example
+//! ```rust
+//! use hypors::sequential::{
+//!     group_sequential_design, BoundaryType, FutilityBound, MsprtMeans, MsprtProportions,
+//!     SequentialDecision, SequentialDesign, SequentialLook, SpendingFunction, Sprt, SprtModel,
+//! };
+//! ```
+
+pub mod design;
+pub(crate) mod integrate;
+pub mod msprt;
+pub mod sprt;
+pub mod types;
+
+pub use design::group_sequential_design;
+pub use types::{
+    BoundaryType, FutilityBound, MsprtMeans, MsprtProportions, SequentialDecision,
+    SequentialDesign, SequentialLook, SpendingFunction, Sprt, SprtModel,
+};
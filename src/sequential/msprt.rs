@@ -0,0 +1,324 @@
+use super::types::{MsprtMeans, MsprtProportions, RunningMoments};
+use crate::common::{StatError, TestResult};
+
+/// Calculates the normal-mixture likelihood ratio for an estimate with variance `variance`,
+/// against a hypothesized value, with a `N(null, tau²)` mixing distribution.
+fn mixture_likelihood_ratio(estimate: f64, null: f64, variance: f64, tau: f64) -> f64 {
+    let tau2 = tau.powi(2);
+    (variance / (variance + tau2)).sqrt()
+        * ((estimate - null).powi(2) * tau2 / (2.0 * variance * (variance + tau2))).exp()
+}
+
+/// Calculates the half-width of the mixture confidence sequence, the set of values whose
+/// likelihood ratio stays below `1 / alpha`.
+fn confidence_half_width(variance: f64, tau: f64, alpha: f64) -> f64 {
+    let tau2 = tau.powi(2);
+    (variance * (variance + tau2) / tau2
+        * (2.0 * (1.0 / alpha).ln() + ((variance + tau2) / variance).ln()))
+    .sqrt()
+}
+
+fn check_parameters(alpha: f64, tau: f64) -> Result<(), StatError> {
+    if alpha <= 0.0 || alpha >= 1.0 {
+        return Err(StatError::ComputeError(
+            "Alpha must be between 0 and 1".to_string(),
+        ));
+    }
+    if !(tau > 0.0 && tau.is_finite()) {
+        return Err(StatError::ComputeError(
+            "Mixing standard deviation must be positive".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Updates the running p-value and confidence sequence with the estimate at the current look.
+#[allow(clippy::too_many_arguments)]
+fn look(
+    estimate: f64,
+    variance: f64,
+    null: f64,
+    tau: f64,
+    alpha: f64,
+    p_value: &mut f64,
+    confidence_interval: &mut (f64, f64),
+    label: &str,
+) -> TestResult {
+    let ratio = mixture_likelihood_ratio(estimate, null, variance, tau);
+    *p_value = p_value.min(1.0 / ratio);
+
+    // The confidence sequence is the running intersection of the per-look intervals
+    let half_width = confidence_half_width(variance, tau, alpha);
+    confidence_interval.0 = confidence_interval.0.max(estimate - half_width);
+    confidence_interval.1 = confidence_interval.1.min(estimate + half_width);
+
+    TestResult {
+        test_statistic: ratio,
+        p_value: *p_value,
+        confidence_interval: *confidence_interval,
+        null_hypothesis: format!("H0: {label} = {null}"),
+        alt_hypothesis: format!("Ha: {label} ≠ {null}"),
+        reject_null: *p_value < alpha,
+    }
+}
+
+impl RunningMoments {
+    /// Merges a batch summarised by its size, mean and sample variance.
+    fn merge(&mut self, n: f64, mean: f64, variance: f64) {
+        let total = self.n + n;
+        let delta = mean - self.mean;
+        let batch_m2 = if n > 1.0 { variance * (n - 1.0) } else { 0.0 };
+        self.m2 += batch_m2 + delta.powi(2) * self.n * n / total;
+        self.mean += delta * n / total;
+        self.n = total;
+    }
+
+    fn variance(&self) -> f64 {
+        self.m2 / (self.n - 1.0)
+    }
+}
+
+impl MsprtMeans {
+    /// Creates an mSPRT for the difference in means `µ1 - µ2`.
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence sequence).
+    /// * `tau` - The standard deviation of the normal mixing distribution over the difference;
+    ///   the test is most sensitive to differences of roughly this size.
+    /// * `null_diff` - The hypothesized difference in means (usually 0).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the test with no observations, or a `StatError` if `alpha` lies
+    /// outside `(0, 1)` or `tau` is not positive.
+    pub fn new(alpha: f64, tau: f64, null_diff: f64) -> Result<Self, StatError> {
+        check_parameters(alpha, tau)?;
+        Ok(MsprtMeans {
+            alpha,
+            tau,
+            null_diff,
+            group1: RunningMoments::default(),
+            group2: RunningMoments::default(),
+            p_value: 1.0,
+            confidence_interval: (f64::NEG_INFINITY, f64::INFINITY),
+        })
+    }
+
+    /// Adds a batch of observations and returns the always-valid inference at this look.
+    ///
+    /// Each group's batch is given by its size, mean and sample variance; a batch may be empty
+    /// (`n = 0`) for a group with no new observations. The variance of the difference is
+    /// estimated from all observations so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `n1`, `mean1`, `var1` - The size, mean and sample variance of the new batch of group 1.
+    /// * `n2`, `mean2`, `var2` - The size, mean and sample variance of the new batch of group 2.
+    ///
+    /// # Returns
+    ///
+    /// A `TestResult` struct containing:
+    /// - `test_statistic`: The mixture likelihood ratio at this look.
+    /// - `p_value`: The always-valid p-value, the running minimum of the inverse likelihood ratio.
+    /// - `confidence_interval`: The confidence sequence for `µ1 - µ2`, intersected over looks.
+    /// - `null_hypothesis`, `alt_hypothesis`: The hypotheses being tested.
+    /// - `reject_null`: Whether the always-valid p-value is below alpha.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StatError` if a batch has a negative size or variance, or if either group
+    /// has fewer than two observations or zero variance so far.
+    ///
+    /// # Example
+    /// ```rust
+    /// use hypors::sequential::MsprtMeans;
+    ///
+    /// let mut test = MsprtMeans::new(0.05, 0.5, 0.0).unwrap();
+    /// let mut result = test.update(500.0, 10.4, 4.0, 500.0, 10.0, 4.0).unwrap();
+    /// assert!(result.reject_null);
+    ///
+    /// // Later looks can never undo a rejection
+    /// result = test.update(500.0, 10.0, 4.0, 500.0, 10.0, 4.0).unwrap();
+    /// assert!(result.reject_null);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        n1: f64,
+        mean1: f64,
+        var1: f64,
+        n2: f64,
+        mean2: f64,
+        var2: f64,
+    ) -> Result<TestResult, StatError> {
+        if n1 < 0.0 || n2 < 0.0 || var1 < 0.0 || var2 < 0.0 {
+            return Err(StatError::ComputeError(
+                "Batch sizes and variances must be non-negative".to_string(),
+            ));
+        }
+        if n1 > 0.0 {
+            self.group1.merge(n1, mean1, var1);
+        }
+        if n2 > 0.0 {
+            self.group2.merge(n2, mean2, var2);
+        }
+        if self.group1.n < 2.0 || self.group2.n < 2.0 {
+            return Err(StatError::InsufficientData);
+        }
+
+        let variance =
+            self.group1.variance() / self.group1.n + self.group2.variance() / self.group2.n;
+        if variance <= 0.0 {
+            return Err(StatError::ComputeError(
+                "Variance of the difference must be positive".to_string(),
+            ));
+        }
+
+        Ok(look(
+            self.group1.mean - self.group2.mean,
+            variance,
+            self.null_diff,
+            self.tau,
+            self.alpha,
+            &mut self.p_value,
+            &mut self.confidence_interval,
+            "µ1 - µ2",
+        ))
+    }
+
+    /// Adds a batch of raw observations; see `update`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use hypors::sequential::MsprtMeans;
+    ///
+    /// let mut test = MsprtMeans::new(0.05, 1.0, 0.0).unwrap();
+    /// let result = test.update_data(&[1.0, 2.0, 3.0], &[2.0, 3.0, 4.0]).unwrap();
+    /// assert!(result.p_value <= 1.0);
+    /// ```
+    pub fn update_data(&mut self, data1: &[f64], data2: &[f64]) -> Result<TestResult, StatError> {
+        let summarise = |data: &[f64]| {
+            let n = data.len() as f64;
+            if data.is_empty() {
+                return (0.0, 0.0, 0.0);
+            }
+            let mean = data.iter().sum::<f64>() / n;
+            let variance = if data.len() > 1 {
+                data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+            } else {
+                0.0
+            };
+            (n, mean, variance)
+        };
+        let (n1, mean1, var1) = summarise(data1);
+        let (n2, mean2, var2) = summarise(data2);
+        self.update(n1, mean1, var1, n2, mean2, var2)
+    }
+}
+
+impl MsprtProportions {
+    /// Creates an mSPRT for the difference in proportions `p1 - p2`.
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence sequence).
+    /// * `tau` - The standard deviation of the normal mixing distribution over the difference;
+    ///   the test is most sensitive to differences of roughly this size.
+    /// * `null_diff` - The hypothesized difference in proportions (usually 0).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the test with no observations, or a `StatError` if `alpha` lies
+    /// outside `(0, 1)` or `tau` is not positive.
+    pub fn new(alpha: f64, tau: f64, null_diff: f64) -> Result<Self, StatError> {
+        check_parameters(alpha, tau)?;
+        Ok(MsprtProportions {
+            alpha,
+            tau,
+            null_diff,
+            successes1: 0.0,
+            trials1: 0.0,
+            successes2: 0.0,
+            trials2: 0.0,
+            p_value: 1.0,
+            confidence_interval: (f64::NEG_INFINITY, f64::INFINITY),
+        })
+    }
+
+    /// Adds a batch of trials and returns the always-valid inference at this look.
+    ///
+    /// The variance of the difference is estimated from the observed proportions so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `successes1`, `trials1` - The new successes and trials of group 1.
+    /// * `successes2`, `trials2` - The new successes and trials of group 2.
+    ///
+    /// # Returns
+    ///
+    /// A `TestResult` struct containing:
+    /// - `test_statistic`: The mixture likelihood ratio at this look.
+    /// - `p_value`: The always-valid p-value, the running minimum of the inverse likelihood ratio.
+    /// - `confidence_interval`: The confidence sequence for `p1 - p2`, intersected over looks.
+    /// - `null_hypothesis`, `alt_hypothesis`: The hypotheses being tested.
+    /// - `reject_null`: Whether the always-valid p-value is below alpha.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `StatError` if the counts are negative or exceed the trials, if either group
+    /// has no trials so far, or if both observed proportions are 0 or 1.
+    ///
+    /// # Example
+    /// ```rust
+    /// use hypors::sequential::MsprtProportions;
+    ///
+    /// let mut test = MsprtProportions::new(0.05, 0.05, 0.0).unwrap();
+    /// for _ in 0..10 {
+    ///     test.update(130.0, 1000.0, 100.0, 1000.0).unwrap();
+    /// }
+    /// let result = test.update(130.0, 1000.0, 100.0, 1000.0).unwrap();
+    /// assert!(result.reject_null);
+    /// assert!(result.confidence_interval.0 > 0.0);
+    /// ```
+    pub fn update(
+        &mut self,
+        successes1: f64,
+        trials1: f64,
+        successes2: f64,
+        trials2: f64,
+    ) -> Result<TestResult, StatError> {
+        if successes1 < 0.0 || successes2 < 0.0 || successes1 > trials1 || successes2 > trials2 {
+            return Err(StatError::ComputeError(
+                "Successes must be between 0 and the number of trials".to_string(),
+            ));
+        }
+        self.successes1 += successes1;
+        self.trials1 += trials1;
+        self.successes2 += successes2;
+        self.trials2 += trials2;
+        if self.trials1 == 0.0 || self.trials2 == 0.0 {
+            return Err(StatError::InsufficientData);
+        }
+
+        let p1 = self.successes1 / self.trials1;
+        let p2 = self.successes2 / self.trials2;
+        let variance = p1 * (1.0 - p1) / self.trials1 + p2 * (1.0 - p2) / self.trials2;
+        if variance <= 0.0 {
+            return Err(StatError::ComputeError(
+                "Variance of the difference must be positive".to_string(),
+            ));
+        }
+
+        Ok(look(
+            p1 - p2,
+            variance,
+            self.null_diff,
+            self.tau,
+            self.alpha,
+            &mut self.p_value,
+            &mut self.confidence_interval,
+            "p1 - p2",
+        ))
+    }
+}
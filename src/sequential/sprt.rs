@@ -0,0 +1,335 @@
+use super::types::{SequentialDecision, Sprt, SprtModel};
+use crate::common::StatError;
+
+impl SprtModel {
+    /// Checks that the hypotheses are valid and distinct.
+    fn check(&self) -> Result<(), StatError> {
+        let valid = match *self {
+            SprtModel::Bernoulli { p0, p1 } => {
+                p0 > 0.0 && p0 < 1.0 && p1 > 0.0 && p1 < 1.0 && p0 != p1
+            }
+            SprtModel::Normal { mu0, mu1, std_dev } => {
+                std_dev > 0.0 && mu0.is_finite() && mu1.is_finite() && mu0 != mu1
+            }
+            SprtModel::Poisson { lambda0, lambda1 } => {
+                lambda0 > 0.0 && lambda1 > 0.0 && lambda0 != lambda1
+            }
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(StatError::ComputeError(
+                "SPRT hypotheses must be distinct, valid parameter values".to_string(),
+            ))
+        }
+    }
+
+    /// Calculates the log-likelihood ratio contributed by a single observation.
+    fn log_lr(&self, x: f64) -> Result<f64, StatError> {
+        match *self {
+            SprtModel::Bernoulli { p0, p1 } => {
+                if x == 1.0 {
+                    Ok((p1 / p0).ln())
+                } else if x == 0.0 {
+                    Ok(((1.0 - p1) / (1.0 - p0)).ln())
+                } else {
+                    Err(StatError::ComputeError(
+                        "Bernoulli observations must be 0 or 1".to_string(),
+                    ))
+                }
+            }
+            SprtModel::Normal { mu0, mu1, std_dev } => {
+                if !x.is_finite() {
+                    return Err(StatError::ComputeError(
+                        "Normal observations must be finite".to_string(),
+                    ));
+                }
+                Ok((mu1 - mu0) / std_dev.powi(2) * (x - (mu0 + mu1) / 2.0))
+            }
+            SprtModel::Poisson { lambda0, lambda1 } => {
+                if x < 0.0 || x.fract() != 0.0 {
+                    return Err(StatError::ComputeError(
+                        "Poisson observations must be non-negative integers".to_string(),
+                    ));
+                }
+                Ok(x * (lambda1 / lambda0).ln() - (lambda1 - lambda0))
+            }
+        }
+    }
+
+    /// Calculates the mean and variance of the per-observation log-likelihood ratio when the
+    /// true parameter is `theta`.
+    fn log_lr_moments(&self, theta: f64) -> (f64, f64) {
+        match *self {
+            SprtModel::Bernoulli { p0, p1 } => {
+                let a = (p1 / p0).ln();
+                let b = ((1.0 - p1) / (1.0 - p0)).ln();
+                (
+                    theta * a + (1.0 - theta) * b,
+                    theta * (1.0 - theta) * (a - b).powi(2),
+                )
+            }
+            SprtModel::Normal { mu0, mu1, std_dev } => {
+                let slope = (mu1 - mu0) / std_dev.powi(2);
+                (
+                    slope * (theta - (mu0 + mu1) / 2.0),
+                    slope.powi(2) * std_dev.powi(2),
+                )
+            }
+            SprtModel::Poisson { lambda0, lambda1 } => {
+                let a = (lambda1 / lambda0).ln();
+                (theta * a - (lambda1 - lambda0), theta * a.powi(2))
+            }
+        }
+    }
+
+    /// Calculates the cumulant generating function of the per-observation log-likelihood ratio,
+    /// `ln E_θ[exp(h z)]`.
+    fn cumulant(&self, theta: f64, h: f64) -> f64 {
+        match *self {
+            SprtModel::Bernoulli { p0, p1 } => {
+                (theta * (p1 / p0).powf(h) + (1.0 - theta) * ((1.0 - p1) / (1.0 - p0)).powf(h)).ln()
+            }
+            SprtModel::Normal { .. } => {
+                let (mean, variance) = self.log_lr_moments(theta);
+                h * mean + h.powi(2) * variance / 2.0
+            }
+            SprtModel::Poisson { lambda0, lambda1 } => {
+                theta * ((lambda1 / lambda0).powf(h) - 1.0) - h * (lambda1 - lambda0)
+            }
+        }
+    }
+
+    /// Checks that `theta` is a valid parameter value for the model.
+    fn check_theta(&self, theta: f64) -> Result<(), StatError> {
+        let valid = match self {
+            SprtModel::Bernoulli { .. } => (0.0..=1.0).contains(&theta),
+            SprtModel::Normal { .. } => theta.is_finite(),
+            SprtModel::Poisson { .. } => theta >= 0.0 && theta.is_finite(),
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(StatError::ComputeError(
+                "Parameter value is outside the support of the model".to_string(),
+            ))
+        }
+    }
+}
+
+/// Finds the nonzero root `h` of the cumulant generating function, which Wald's approximations
+/// use to express the operating characteristic. Returns 0 when the log-likelihood ratio has
+/// zero drift.
+fn wald_exponent(model: &SprtModel, theta: f64) -> f64 {
+    let (mean, variance) = model.log_lr_moments(theta);
+    if variance <= 0.0 {
+        // Degenerate observations: the test moves deterministically towards one boundary
+        return if mean > 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        };
+    }
+    if mean.abs() < 1e-12 * variance.sqrt() {
+        return 0.0;
+    }
+
+    // The cumulant is convex with slope `mean` at 0, so its other root lies on the opposite side
+    let direction = if mean < 0.0 { 1.0 } else { -1.0 };
+    let mut outer = direction;
+    while model.cumulant(theta, outer) <= 0.0 {
+        outer *= 2.0;
+        if outer.abs() > 1e6 {
+            return outer;
+        }
+    }
+    let mut inner = 0.0;
+    for _ in 0..200 {
+        let mid = (inner + outer) / 2.0;
+        if model.cumulant(theta, mid) > 0.0 {
+            outer = mid;
+        } else {
+            inner = mid;
+        }
+        if (outer - inner).abs() < 1e-12 * outer.abs().max(1.0) {
+            break;
+        }
+    }
+    (inner + outer) / 2.0
+}
+
+impl Sprt {
+    /// Creates a Wald SPRT for the given model and error rates.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The observation model and simple hypotheses.
+    /// * `alpha` - The type I error, the probability of rejecting `H0` when it is true.
+    /// * `beta` - The type II error, the probability of accepting `H0` when `H1` is true.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the test with no observations, or a `StatError` if the hypotheses
+    /// are invalid or the error rates do not satisfy `0 < α, β` and `α + β < 1`.
+    pub fn new(model: SprtModel, alpha: f64, beta: f64) -> Result<Self, StatError> {
+        model.check()?;
+        if alpha <= 0.0 || beta <= 0.0 || alpha + beta >= 1.0 {
+            return Err(StatError::ComputeError(
+                "Error rates must be positive with alpha + beta < 1".to_string(),
+            ));
+        }
+        Ok(Sprt {
+            model,
+            alpha,
+            beta,
+            log_lr: 0.0,
+            n: 0,
+            decision: SequentialDecision::Continue,
+        })
+    }
+
+    /// Returns Wald's boundaries `(ln B, ln A)` for the log-likelihood ratio.
+    pub fn bounds(&self) -> (f64, f64) {
+        (
+            (self.beta / (1.0 - self.alpha)).ln(),
+            ((1.0 - self.beta) / self.alpha).ln(),
+        )
+    }
+
+    /// Returns the cumulative log-likelihood ratio of `H1` to `H0`.
+    pub fn log_likelihood_ratio(&self) -> f64 {
+        self.log_lr
+    }
+
+    /// Returns the number of observations used so far.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Adds an observation and returns the decision.
+    ///
+    /// Once a boundary has been crossed, the test has stopped: further observations are
+    /// ignored and the final decision is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The observation (0 or 1 for Bernoulli, a count for Poisson).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `RejectNull` (accept `H1`), `AcceptNull` or `Continue`, or a
+    /// `StatError` if the observation is outside the support of the model.
+    pub fn update(&mut self, x: f64) -> Result<SequentialDecision, StatError> {
+        if self.decision != SequentialDecision::Continue {
+            return Ok(self.decision.clone());
+        }
+
+        self.log_lr += self.model.log_lr(x)?;
+        self.n += 1;
+
+        let (lower, upper) = self.bounds();
+        self.decision = if self.log_lr >= upper {
+            SequentialDecision::RejectNull
+        } else if self.log_lr <= lower {
+            SequentialDecision::AcceptNull
+        } else {
+            SequentialDecision::Continue
+        };
+        Ok(self.decision.clone())
+    }
+
+    /// Adds observations in order until a boundary is crossed, and returns the decision.
+    ///
+    /// # Example
+    /// ```rust
+    /// use hypors::sequential::{Sprt, SprtModel, SequentialDecision};
+    ///
+    /// let model = SprtModel::Normal { mu0: 0.0, mu1: 1.0, std_dev: 1.0 };
+    /// let mut sprt = Sprt::new(model, 0.05, 0.05).unwrap();
+    ///
+    /// let decision = sprt.update_batch(&[1.2, 0.8, 1.5, 2.1, 0.9, 1.4]).unwrap();
+    /// assert_eq!(decision, SequentialDecision::RejectNull);
+    /// assert_eq!(sprt.n(), 4);
+    /// ```
+    pub fn update_batch(&mut self, data: &[f64]) -> Result<SequentialDecision, StatError> {
+        for &x in data {
+            if self.update(x)? != SequentialDecision::Continue {
+                break;
+            }
+        }
+        Ok(self.decision.clone())
+    }
+
+    /// Calculates the operating characteristic: the probability of accepting `H0` when the
+    /// true parameter is `theta`, using Wald's approximation.
+    ///
+    /// # Arguments
+    ///
+    /// * `theta` - The true success probability, mean or rate.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the acceptance probability, or a `StatError` if `theta` is outside
+    /// the support of the model.
+    ///
+    /// # Example
+    /// ```rust
+    /// use hypors::sequential::{Sprt, SprtModel};
+    ///
+    /// let sprt = Sprt::new(SprtModel::Bernoulli { p0: 0.1, p1: 0.3 }, 0.05, 0.1).unwrap();
+    /// assert!((sprt.oc(0.1).unwrap() - 0.95).abs() < 1e-6);
+    /// assert!((sprt.oc(0.3).unwrap() - 0.10).abs() < 1e-6);
+    /// ```
+    pub fn oc(&self, theta: f64) -> Result<f64, StatError> {
+        self.model.check_theta(theta)?;
+        let (b, a) = self.bounds();
+        let h = wald_exponent(&self.model, theta);
+
+        let oc = if h == 0.0 {
+            a / (a - b)
+        } else if h == f64::INFINITY {
+            1.0
+        } else if h == f64::NEG_INFINITY {
+            0.0
+        } else if h > 0.0 {
+            (1.0 - (-h * a).exp()) / (1.0 - (h * (b - a)).exp())
+        } else {
+            ((h * (a - b)).exp() - (-h * b).exp()) / ((h * (a - b)).exp() - 1.0)
+        };
+        Ok(oc.clamp(0.0, 1.0))
+    }
+
+    /// Calculates the average sample number (ASN): the expected number of observations before
+    /// stopping when the true parameter is `theta`, using Wald's approximation.
+    ///
+    /// # Arguments
+    ///
+    /// * `theta` - The true success probability, mean or rate.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the expected sample number, or a `StatError` if `theta` is outside
+    /// the support of the model.
+    ///
+    /// # Example
+    /// ```rust
+    /// use hypors::sequential::{Sprt, SprtModel};
+    ///
+    /// let model = SprtModel::Normal { mu0: 0.0, mu1: 0.5, std_dev: 1.0 };
+    /// let sprt = Sprt::new(model, 0.05, 0.05).unwrap();
+    ///
+    /// // Far fewer observations on average than the 44 of a fixed-sample test
+    /// assert!((sprt.asn(0.0).unwrap() - 21.2).abs() < 0.1);
+    /// ```
+    pub fn asn(&self, theta: f64) -> Result<f64, StatError> {
+        let oc = self.oc(theta)?;
+        let (b, a) = self.bounds();
+        let (mean, variance) = self.model.log_lr_moments(theta);
+
+        if wald_exponent(&self.model, theta) == 0.0 {
+            Ok(-a * b / variance)
+        } else {
+            Ok((oc * b + (1.0 - oc) * a) / mean)
+        }
+    }
+}
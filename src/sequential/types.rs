@@ -0,0 +1,220 @@
+use crate::common::TailType;
+use serde::{Deserialize, Serialize};
+use statrs::distribution::{ContinuousCDF, Normal};
+
+/// Represents a Lan-DeMets alpha-spending function, giving the cumulative type I error
+/// spent by information fraction `t`.
+///
+/// In two-tailed designs the function spends `α/2` on each side.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpendingFunction {
+    /// O'Brien-Fleming-type spending, `α(t) = 2 - 2Φ(z_{1-α/2} / √t)`, which spends very little
+    /// early on.
+    OBrienFleming,
+    /// Pocock-type spending, `α(t) = α ln(1 + (e - 1)t)`, which spends more evenly.
+    Pocock,
+}
+
+impl SpendingFunction {
+    /// Calculates the cumulative error spent by information fraction `t`.
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - The total error to spend by the final analysis (`t = 1`).
+    /// * `t` - The information fraction, between 0 and 1.
+    ///
+    /// # Example
+    /// ```rust
+    /// use hypors::sequential::SpendingFunction;
+    ///
+    /// let spent = SpendingFunction::Pocock.spend(0.05, 0.5);
+    /// assert!((spent - 0.0310).abs() < 1e-4);
+    /// assert!((SpendingFunction::OBrienFleming.spend(0.05, 1.0) - 0.05).abs() < 1e-9);
+    /// ```
+    pub fn spend(&self, alpha: f64, t: f64) -> f64 {
+        if t <= 0.0 {
+            return 0.0;
+        }
+        let t = t.min(1.0);
+        match self {
+            SpendingFunction::OBrienFleming => {
+                let normal = Normal::new(0.0, 1.0).expect("Failed to create Normal distribution");
+                2.0 * normal.sf(normal.inverse_cdf(1.0 - alpha / 2.0) / t.sqrt())
+            }
+            SpendingFunction::Pocock => alpha * (1.0 + (std::f64::consts::E - 1.0) * t).ln(),
+        }
+    }
+}
+
+/// Represents the method used to derive efficacy boundaries.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BoundaryType {
+    /// Classical O'Brien-Fleming boundaries, `c_k = C / √t_k`.
+    OBrienFleming,
+    /// Classical Pocock boundaries, `c_k = C`, the same at every look.
+    Pocock,
+    /// Lan-DeMets boundaries from an alpha-spending function, which allow looks at arbitrary
+    /// information fractions.
+    LanDeMets(SpendingFunction),
+}
+
+/// Describes non-binding futility boundaries derived from beta spending.
+///
+/// # Fields
+///
+/// * `spending` - The function spending the type II error over the looks.
+/// * `beta` - The total type II error (one minus the design power).
+/// * `drift` - The expected Z-statistic at the final analysis under the alternative
+///   hypothesis; for a fixed design sized for power `1 - β`, this is `z_{1-α} + z_{1-β}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FutilityBound {
+    pub spending: SpendingFunction,
+    pub beta: f64,
+    pub drift: f64,
+}
+
+/// Stores the boundaries of one interim or final look.
+///
+/// # Fields
+///
+/// * `info_fraction` - The fraction of the maximum information available at this look.
+/// * `efficacy_bound` - The Z-statistic boundary for rejecting the null hypothesis.
+/// * `futility_bound` - The Z-statistic boundary for stopping for futility, if any.
+/// * `nominal_alpha` - The p-value threshold equivalent to the efficacy boundary.
+/// * `cumulative_alpha` - The type I error spent up to and including this look.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequentialLook {
+    pub info_fraction: f64,
+    pub efficacy_bound: f64,
+    pub futility_bound: Option<f64>,
+    pub nominal_alpha: f64,
+    pub cumulative_alpha: f64,
+}
+
+/// Stores a group sequential design.
+///
+/// For right-tailed designs the null hypothesis is rejected when `Z >= efficacy_bound` and the
+/// trial stops for futility when `Z <= futility_bound`; left-tailed designs mirror this with
+/// negative boundaries, and two-tailed designs reject when `|Z| >= efficacy_bound`.
+///
+/// # Fields
+///
+/// * `alpha` - The overall significance level.
+/// * `tail` - The type of tail (left, right, or two) for the test.
+/// * `looks` - The boundaries of each look, in order.
+#[derive(Debug, Clone)]
+pub struct SequentialDesign {
+    pub alpha: f64,
+    pub tail: TailType,
+    pub looks: Vec<SequentialLook>,
+}
+
+/// Represents the decision at a look of a group sequential design.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SequentialDecision {
+    /// The efficacy boundary was crossed: stop and reject the null hypothesis.
+    RejectNull,
+    /// Neither boundary was crossed: continue to the next look.
+    Continue,
+    /// The futility boundary was crossed, or the final look was reached without crossing the
+    /// efficacy boundary: stop without rejecting the null hypothesis.
+    AcceptNull,
+}
+
+/// Running count, mean and sum of squared deviations of one group, merged batch by batch.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RunningMoments {
+    pub(crate) n: f64,
+    pub(crate) mean: f64,
+    pub(crate) m2: f64,
+}
+
+/// Tracks a mixture sequential probability ratio test (mSPRT) for the difference in two means.
+///
+/// Summary statistics of each new batch of observations are merged with those seen so far, and
+/// each update returns an always-valid p-value and confidence sequence that remain valid
+/// whenever monitoring stops.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::sequential::MsprtMeans;
+///
+/// let mut test = MsprtMeans::new(0.05, 1.0, 0.0).unwrap();
+/// let result = test.update(100.0, 10.3, 4.0, 100.0, 10.0, 4.0).unwrap();
+/// assert!(result.p_value > 0.05);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MsprtMeans {
+    pub(crate) alpha: f64,
+    pub(crate) tau: f64,
+    pub(crate) null_diff: f64,
+    pub(crate) group1: RunningMoments,
+    pub(crate) group2: RunningMoments,
+    pub(crate) p_value: f64,
+    pub(crate) confidence_interval: (f64, f64),
+}
+
+/// Tracks a mixture sequential probability ratio test (mSPRT) for the difference in two
+/// proportions.
+///
+/// Success and trial counts of each new batch are added to those seen so far, and each update
+/// returns an always-valid p-value and confidence sequence that remain valid whenever
+/// monitoring stops.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::sequential::MsprtProportions;
+///
+/// let mut test = MsprtProportions::new(0.05, 0.05, 0.0).unwrap();
+/// let result = test.update(120.0, 1000.0, 100.0, 1000.0).unwrap();
+/// assert!(!result.reject_null);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MsprtProportions {
+    pub(crate) alpha: f64,
+    pub(crate) tau: f64,
+    pub(crate) null_diff: f64,
+    pub(crate) successes1: f64,
+    pub(crate) trials1: f64,
+    pub(crate) successes2: f64,
+    pub(crate) trials2: f64,
+    pub(crate) p_value: f64,
+    pub(crate) confidence_interval: (f64, f64),
+}
+
+/// Represents the observation model and the simple hypotheses of a Wald SPRT.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SprtModel {
+    /// Bernoulli (0/1) observations, testing `H0: p = p0` against `H1: p = p1`.
+    Bernoulli { p0: f64, p1: f64 },
+    /// Normal observations with known standard deviation, testing `H0: µ = mu0` against
+    /// `H1: µ = mu1`.
+    Normal { mu0: f64, mu1: f64, std_dev: f64 },
+    /// Poisson counts, testing `H0: λ = lambda0` against `H1: λ = lambda1`.
+    Poisson { lambda0: f64, lambda1: f64 },
+}
+
+/// Tracks Wald's sequential probability ratio test (SPRT).
+///
+/// Observations are added one at a time, and the log-likelihood ratio of `H1` to `H0` is
+/// compared with Wald's boundaries `ln(β / (1 - α))` and `ln((1 - β) / α)`.
+///
+/// # Example
+///
+/// ```rust
+/// use hypors::sequential::{Sprt, SprtModel, SequentialDecision};
+///
+/// let mut sprt = Sprt::new(SprtModel::Bernoulli { p0: 0.1, p1: 0.3 }, 0.05, 0.1).unwrap();
+/// assert_eq!(sprt.update(1.0).unwrap(), SequentialDecision::Continue);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sprt {
+    pub(crate) model: SprtModel,
+    pub(crate) alpha: f64,
+    pub(crate) beta: f64,
+    pub(crate) log_lr: f64,
+    pub(crate) n: usize,
+    pub(crate) decision: SequentialDecision,
+}
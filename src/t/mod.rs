@@ -8,7 +8,10 @@
 //! ## Sample Size Calculation
 //!
 //! To calculate the required sample size for t-tests, you can use the following function:
-//! - `t_sample_size`: Calculates the necessary sample size for one-sample t-tests based on effect size, alpha, power, and standard deviation.
+//! - `t_sample_size`: Calculates the necessary sample size for one-sample t-tests based on effect size, alpha, power, and standard deviation, iterating on exact power.
+//! - `t_power`: Calculates the exact power of one-sample t-tests from the noncentral t-distribution.
+//! - `t_sample_size_ind`: Calculates per-group sample sizes for independent two-sample t-tests with an allocation ratio.
+//! - `t_sample_size_paired`: Calculates the number of pairs for paired t-tests from the within-pair correlation.
 //!
 //! ## Submodules
 //!
@@ -23,10 +26,14 @@
 //! - `t_test_ind`: Performs an independent two-sample t-test.
 //! - `t_test_paired`: Performs a paired two-sample t-test.
 //! - `t_sample_size`: Calculates the required sample size for one-sample t-tests.
+//! - `t_power`: Calculates the exact power of one-sample t-tests.
+//! - `t_power_ind`: Calculates the exact power of independent two-sample t-tests.
+//! - `t_sample_size_ind`: Calculates the required per-group sample sizes for independent two-sample t-tests.
+//! - `t_sample_size_paired`: Calculates the required number of pairs for paired t-tests.
 //!
 //! ## Example
 //! ```rust
-//! use hypors::t::{t_test, t_test_ind, t_test_paired, t_sample_size};
+//! use hypors::t::{t_test, t_test_ind, t_test_paired, t_power, t_power_ind, t_sample_size, t_sample_size_ind, t_sample_size_paired};
 //! ```
 
 pub mod one_sample;
@@ -34,5 +41,7 @@ pub mod sample_size;
 pub mod two_sample;
 
 pub use one_sample::t_test;
-pub use sample_size::t_sample_size;
+pub use sample_size::{
+    t_power, t_power_ind, t_sample_size, t_sample_size_ind, t_sample_size_paired,
+};
 pub use two_sample::{t_test_ind, t_test_paired};
@@ -1,10 +1,102 @@
 use crate::common::TailType;
+use crate::common::calc::{solve_allocated_sample_size, solve_sample_size};
+use crate::noncentral::NoncentralStudentsT;
 use statrs::distribution::{ContinuousCDF, StudentsT};
 
+/// Calculates the exact power of a one-sample t-test.
+///
+/// Under the alternative, the t-statistic follows a noncentral t-distribution with `n - 1`
+/// degrees of freedom and noncentrality `δ = effect_size / std_dev * √n`.
+///
+/// # Arguments
+///
+/// * `effect_size` - The difference between the true and hypothesized mean.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `n` - The sample size (at least 2).
+/// * `std_dev` - The population standard deviation (or a reasonable estimate).
+/// * `tail` - The type of tail (left, right, or two) for the test.
+///
+/// # Returns
+///
+/// The probability of rejecting the null hypothesis, or `NaN` if the inputs are invalid.
+///
+/// # Example
+/// ```rust
+/// use hypors::t::t_power;
+/// use hypors::common::TailType;
+///
+/// let power = t_power(0.5, 0.05, 34.0, 1.0, TailType::Two);
+/// assert!((power - 0.8078).abs() < 1e-3);
+/// ```
+pub fn t_power(effect_size: f64, alpha: f64, n: f64, std_dev: f64, tail: TailType) -> f64 {
+    noncentral_power(effect_size / std_dev * n.sqrt(), n - 1.0, alpha, &tail)
+}
+
+/// Calculates the power of a t-test whose statistic follows a noncentral t-distribution with
+/// `df` degrees of freedom and noncentrality `delta` under the alternative.
+fn noncentral_power(delta: f64, df: f64, alpha: f64, tail: &TailType) -> f64 {
+    let (t_dist, nct) = match (
+        StudentsT::new(0.0, 1.0, df),
+        NoncentralStudentsT::new(df, delta),
+    ) {
+        (Ok(t_dist), Ok(nct)) => (t_dist, nct),
+        _ => return f64::NAN,
+    };
+
+    match tail {
+        TailType::Right => 1.0 - nct.cdf(t_dist.inverse_cdf(1.0 - alpha)),
+        TailType::Left => nct.cdf(t_dist.inverse_cdf(alpha)),
+        TailType::Two => {
+            let t_crit = t_dist.inverse_cdf(1.0 - alpha / 2.0);
+            1.0 - nct.cdf(t_crit) + nct.cdf(-t_crit)
+        }
+    }
+}
+
+/// Calculates the exact power of an independent two-sample t-test with pooled variance.
+///
+/// Under the alternative, the t-statistic follows a noncentral t-distribution with
+/// `n1 + n2 - 2` degrees of freedom and noncentrality
+/// `δ = effect_size / (std_dev * √(1/n1 + 1/n2))`.
+///
+/// # Arguments
+///
+/// * `effect_size` - The difference between the means of the second and first groups.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `n1` - The sample size of the first group.
+/// * `n2` - The sample size of the second group.
+/// * `std_dev` - The common population standard deviation (or a reasonable estimate).
+/// * `tail` - The type of tail (left, right, or two) for the test.
+///
+/// # Returns
+///
+/// The probability of rejecting the null hypothesis, or `NaN` if the inputs are invalid.
+///
+/// # Example
+/// ```rust
+/// use hypors::t::t_power_ind;
+/// use hypors::common::TailType;
+///
+/// let power = t_power_ind(0.5, 0.05, 64.0, 64.0, 1.0, TailType::Two);
+/// assert!((power - 0.8015).abs() < 1e-3);
+/// ```
+pub fn t_power_ind(
+    effect_size: f64,
+    alpha: f64,
+    n1: f64,
+    n2: f64,
+    std_dev: f64,
+    tail: TailType,
+) -> f64 {
+    let std_error = std_dev * (1.0 / n1 + 1.0 / n2).sqrt();
+    noncentral_power(effect_size / std_error, n1 + n2 - 2.0, alpha, &tail)
+}
+
 /// Calculates the required sample size for a one-sample t-test.
 ///
-/// This function computes the necessary sample size to detect a minimum detectable effect size
-/// for a given alpha, power, and population mean.
+/// This function finds the smallest sample size whose exact power, computed from the
+/// noncentral t-distribution, reaches the desired power for the given effect size and alpha.
+/// For one-tailed tests the effect is taken in the direction of the tail.
 ///
 /// # Arguments
 ///
@@ -16,7 +108,8 @@ use statrs::distribution::{ContinuousCDF, StudentsT};
 ///
 /// # Returns
 ///
-/// The estimated sample size required to achieve the specified power and significance level.
+/// The sample size required to achieve the specified power and significance level, or `NaN`
+/// if the inputs are invalid.
 ///
 /// # Example
 /// ```rust
@@ -30,7 +123,7 @@ use statrs::distribution::{ContinuousCDF, StudentsT};
 /// let tail = TailType::Two; // Two-tailed test
 ///
 /// let sample_size = t_sample_size(effect_size, alpha, power, std_dev, tail);
-/// println!("Required sample size: {}", sample_size);
+/// assert_eq!(sample_size, 34.0);
 /// ```
 pub fn t_sample_size(
     effect_size: f64,
@@ -39,19 +132,113 @@ pub fn t_sample_size(
     std_dev: f64,
     tail: TailType,
 ) -> f64 {
-    // Determine critical t-values based on tail type and alpha
-    let df = 1e6; // Approximation for large sample sizes (will be refined later)
-    let t_dist = StudentsT::new(0.0, 1.0, df).expect("Failed to create StudentsT distribution");
+    // Orient the effect along the tail so one-tailed tests are powered in their own direction
+    let effect = match tail {
+        TailType::Left => -effect_size.abs(),
+        _ => effect_size.abs(),
+    };
+
+    solve_sample_size(
+        |n| t_power(effect, alpha, n, std_dev, tail.clone()),
+        alpha,
+        power,
+        2.0,
+    )
+}
 
-    let alpha_value = match tail {
-        TailType::Two => alpha / 2.0, // Two-tailed
-        _ => alpha,                   // One-tailed (left or right)
+/// Calculates the required per-group sample sizes for an independent two-sample t-test.
+///
+/// The second group receives `ratio` times as many units as the first (rounded up), so a
+/// 90/10 split is `ratio = 9.0` or `ratio = 1.0 / 9.0`. The first group size is the smallest
+/// whose exact power, computed with `t_power_ind`, reaches the desired power. For one-tailed
+/// tests the effect is taken in the direction of the tail.
+///
+/// # Arguments
+///
+/// * `effect_size` - The minimum detectable difference in means.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `power` - The desired statistical power (e.g., 0.80 for 80% power).
+/// * `std_dev` - The common population standard deviation (or a reasonable estimate).
+/// * `ratio` - The allocation ratio `n2 / n1`.
+/// * `tail` - The type of tail (left, right, or two) for the test.
+///
+/// # Returns
+///
+/// A tuple `(n1, n2)` of the sample sizes required in each group, or `NaN` sizes if the inputs
+/// are invalid.
+///
+/// # Example
+/// ```rust
+/// use hypors::t::t_sample_size_ind;
+/// use hypors::common::TailType;
+///
+/// let (n1, n2) = t_sample_size_ind(0.5, 0.05, 0.80, 1.0, 1.0, TailType::Two);
+/// assert_eq!((n1, n2), (64.0, 64.0));
+///
+/// let (n1, n2) = t_sample_size_ind(0.5, 0.05, 0.80, 1.0, 9.0, TailType::Two);
+/// assert_eq!((n1, n2), (36.0, 324.0));
+/// ```
+pub fn t_sample_size_ind(
+    effect_size: f64,
+    alpha: f64,
+    power: f64,
+    std_dev: f64,
+    ratio: f64,
+    tail: TailType,
+) -> (f64, f64) {
+    let effect = match tail {
+        TailType::Left => -effect_size.abs(),
+        _ => effect_size.abs(),
     };
 
-    let t_alpha = t_dist.inverse_cdf(1.0 - alpha_value);
-    let t_beta = t_dist.inverse_cdf(power);
+    solve_allocated_sample_size(
+        |n1, n2| t_power_ind(effect, alpha, n1, n2, std_dev, tail.clone()),
+        alpha,
+        power,
+        ratio,
+        2.0,
+    )
+}
 
-    // Formula: n = ((t_alpha + t_beta) * std_dev / effect_size)^2
-    let n = ((t_alpha + t_beta) * std_dev / effect_size).powi(2);
-    n.ceil() // Rounds up to the next whole sample size
+/// Calculates the required number of pairs for a paired t-test.
+///
+/// The standard deviation of the within-pair differences is derived from the standard
+/// deviation of each measurement and their correlation as `std_dev * √(2(1 - correlation))`,
+/// and the one-sample calculation of `t_sample_size` is applied to the differences.
+///
+/// # Arguments
+///
+/// * `effect_size` - The minimum detectable mean difference.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `power` - The desired statistical power (e.g., 0.80 for 80% power).
+/// * `std_dev` - The standard deviation of each measurement (or a reasonable estimate).
+/// * `correlation` - The correlation between the two measurements of a pair, in `(-1, 1)`.
+/// * `tail` - The type of tail (left, right, or two) for the test.
+///
+/// # Returns
+///
+/// The number of pairs required, or `NaN` if the correlation is outside `(-1, 1)`.
+///
+/// # Example
+/// ```rust
+/// use hypors::t::t_sample_size_paired;
+/// use hypors::common::TailType;
+///
+/// // A correlation of 0.5 makes the differences as variable as a single measurement
+/// let n = t_sample_size_paired(0.5, 0.05, 0.80, 1.0, 0.5, TailType::Two);
+/// assert_eq!(n, 34.0);
+/// ```
+pub fn t_sample_size_paired(
+    effect_size: f64,
+    alpha: f64,
+    power: f64,
+    std_dev: f64,
+    correlation: f64,
+    tail: TailType,
+) -> f64 {
+    if correlation <= -1.0 || correlation >= 1.0 {
+        return f64::NAN;
+    }
+    let diff_std_dev = std_dev * (2.0 * (1.0 - correlation)).sqrt();
+    t_sample_size(effect_size, alpha, power, diff_std_dev, tail)
 }
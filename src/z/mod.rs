@@ -10,6 +10,9 @@
 //!
 //! To calculate the required sample size for Z-tests, you can use the following function:
 //! - `z_sample_size`: Calculates the necessary sample size for one-sample and two-sample Z-tests based on desired power, significance level, and effect size.
+//! - `z_power`: Calculates the exact power of one-sample Z-tests.
+//! - `z_sample_size_ind`: Calculates per-group sample sizes for independent two-sample Z-tests with an allocation ratio.
+//! - `z_sample_size_paired`: Calculates the number of pairs for paired Z-tests from the within-pair correlation.
 //!
 //! ## Submodules
 //!
@@ -24,10 +27,14 @@
 //! - `z_test_ind`: Performs an independent two-sample Z-test.
 //! - `z_test_paired`: Performs a paired two-sample Z-test.
 //! - `z_sample_size`: Calculates the required sample size for one-sample and two-sample Z-tests.
+//! - `z_power`: Calculates the exact power of one-sample Z-tests.
+//! - `z_power_ind`: Calculates the exact power of independent two-sample Z-tests.
+//! - `z_sample_size_ind`: Calculates the required per-group sample sizes for independent two-sample Z-tests.
+//! - `z_sample_size_paired`: Calculates the required number of pairs for paired Z-tests.
 //!
 //! ## Example
 //! ```rust
-//! use hypors::z::{z_test, z_test_ind, z_test_paired, z_sample_size};
+//! use hypors::z::{z_test, z_test_ind, z_test_paired, z_power, z_power_ind, z_sample_size, z_sample_size_ind, z_sample_size_paired};
 //! ```
 
 pub mod one_sample;
@@ -35,5 +42,7 @@ pub mod sample_size;
 pub mod two_sample;
 
 pub use one_sample::z_test;
-pub use sample_size::z_sample_size;
+pub use sample_size::{
+    z_power, z_power_ind, z_sample_size, z_sample_size_ind, z_sample_size_paired,
+};
 pub use two_sample::{z_test_ind, z_test_paired};
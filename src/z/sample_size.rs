@@ -1,10 +1,101 @@
 use crate::common::TailType;
+use crate::common::calc::{solve_allocated_sample_size, solve_sample_size};
 use statrs::distribution::{ContinuousCDF, Normal};
 
+/// Calculates the exact power of a one-sample Z-test.
+///
+/// Under the alternative, the Z-statistic is normal with mean `δ = effect_size / std_dev * √n`
+/// and unit variance, so the power includes the (usually negligible) probability of rejecting
+/// in the opposite tail of a two-tailed test.
+///
+/// # Arguments
+///
+/// * `effect_size` - The difference between the true and hypothesized mean.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `n` - The sample size.
+/// * `std_dev` - The population standard deviation.
+/// * `tail` - The type of tail (left, right, or two) for the test.
+///
+/// # Returns
+///
+/// The probability of rejecting the null hypothesis, or `NaN` if the inputs are invalid.
+///
+/// # Example
+/// ```rust
+/// use hypors::z::z_power;
+/// use hypors::common::TailType;
+///
+/// let power = z_power(0.5, 0.05, 32.0, 1.0, TailType::Two);
+/// assert!((power - 0.8074).abs() < 1e-3);
+/// ```
+pub fn z_power(effect_size: f64, alpha: f64, n: f64, std_dev: f64, tail: TailType) -> f64 {
+    normal_power(effect_size / std_dev * n.sqrt(), alpha, &tail)
+}
+
+/// Calculates the power of a Z-test whose statistic is normal with mean `delta` and unit
+/// variance under the alternative, or NaN if `delta` is not finite (e.g. a zero standard
+/// deviation).
+fn normal_power(delta: f64, alpha: f64, tail: &TailType) -> f64 {
+    if !delta.is_finite() {
+        return f64::NAN;
+    }
+    let normal_dist = Normal::new(0.0, 1.0).expect("Failed to create Normal distribution");
+
+    match tail {
+        TailType::Right => 1.0 - normal_dist.cdf(normal_dist.inverse_cdf(1.0 - alpha) - delta),
+        TailType::Left => normal_dist.cdf(normal_dist.inverse_cdf(alpha) - delta),
+        TailType::Two => {
+            let z_crit = normal_dist.inverse_cdf(1.0 - alpha / 2.0);
+            1.0 - normal_dist.cdf(z_crit - delta) + normal_dist.cdf(-z_crit - delta)
+        }
+    }
+}
+
+/// Calculates the exact power of an independent two-sample Z-test.
+///
+/// Under the alternative, the Z-statistic is normal with mean
+/// `δ = effect_size / √(std_dev1²/n1 + std_dev2²/n2)` and unit variance.
+///
+/// # Arguments
+///
+/// * `effect_size` - The difference between the means of the second and first groups.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `n1` - The sample size of the first group.
+/// * `n2` - The sample size of the second group.
+/// * `std_dev1` - The population standard deviation of the first group.
+/// * `std_dev2` - The population standard deviation of the second group.
+/// * `tail` - The type of tail (left, right, or two) for the test.
+///
+/// # Returns
+///
+/// The probability of rejecting the null hypothesis, or `NaN` if the inputs are invalid.
+///
+/// # Example
+/// ```rust
+/// use hypors::z::z_power_ind;
+/// use hypors::common::TailType;
+///
+/// let power = z_power_ind(0.5, 0.05, 63.0, 63.0, 1.0, 1.0, TailType::Two);
+/// assert!((power - 0.8013).abs() < 1e-3);
+/// ```
+pub fn z_power_ind(
+    effect_size: f64,
+    alpha: f64,
+    n1: f64,
+    n2: f64,
+    std_dev1: f64,
+    std_dev2: f64,
+    tail: TailType,
+) -> f64 {
+    let std_error = (std_dev1.powi(2) / n1 + std_dev2.powi(2) / n2).sqrt();
+    normal_power(effect_size / std_error, alpha, &tail)
+}
+
 /// Calculates the required sample size for a one-sample Z-test.
 ///
-/// This function computes the necessary sample size to detect a minimum detectable effect size
-/// for a given alpha, power, and standard deviation.
+/// This function finds the smallest sample size whose exact power reaches the desired power
+/// for the given effect size, alpha, and standard deviation. For one-tailed tests the effect
+/// is taken in the direction of the tail.
 ///
 /// # Arguments
 ///
@@ -16,7 +107,8 @@ use statrs::distribution::{ContinuousCDF, Normal};
 ///
 /// # Returns
 ///
-/// The estimated sample size required to achieve the specified power and significance level.
+/// The sample size required to achieve the specified power and significance level, or `NaN`
+/// if the inputs are invalid.
 ///
 /// # Example
 /// ```rust
@@ -30,7 +122,7 @@ use statrs::distribution::{ContinuousCDF, Normal};
 /// let tail = TailType::Two; // Two-tailed test
 ///
 /// let sample_size = z_sample_size(effect_size, alpha, power, std_dev, tail);
-/// println!("Required sample size: {}", sample_size);
+/// assert_eq!(sample_size, 32.0);
 /// ```
 pub fn z_sample_size(
     effect_size: f64,
@@ -39,18 +131,112 @@ pub fn z_sample_size(
     std_dev: f64,
     tail: TailType,
 ) -> f64 {
-    // Z-distribution for critical values
-    let normal_dist = Normal::new(0.0, 1.0).expect("Failed to create Normal distribution");
+    // Orient the effect along the tail so one-tailed tests are powered in their own direction
+    let effect = match tail {
+        TailType::Left => -effect_size.abs(),
+        _ => effect_size.abs(),
+    };
 
-    let alpha_value = match tail {
-        TailType::Two => alpha / 2.0, // Two-tailed
-        _ => alpha,                   // One-tailed (left or right)
+    solve_sample_size(
+        |n| z_power(effect, alpha, n, std_dev, tail.clone()),
+        alpha,
+        power,
+        1.0,
+    )
+}
+
+/// Calculates the required per-group sample sizes for an independent two-sample Z-test.
+///
+/// The second group receives `ratio` times as many units as the first (rounded up), so a
+/// 90/10 split is `ratio = 9.0` or `ratio = 1.0 / 9.0`. The first group size is the smallest
+/// whose exact power, computed with `z_power_ind`, reaches the desired power. For one-tailed
+/// tests the effect is taken in the direction of the tail.
+///
+/// # Arguments
+///
+/// * `effect_size` - The minimum detectable difference in means.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `power` - The desired statistical power (e.g., 0.80 for 80% power).
+/// * `std_dev1` - The population standard deviation of the first group.
+/// * `std_dev2` - The population standard deviation of the second group.
+/// * `ratio` - The allocation ratio `n2 / n1`.
+/// * `tail` - The type of tail (left, right, or two) for the test.
+///
+/// # Returns
+///
+/// A tuple `(n1, n2)` of the sample sizes required in each group, or `NaN` sizes if the inputs
+/// are invalid.
+///
+/// # Example
+/// ```rust
+/// use hypors::z::z_sample_size_ind;
+/// use hypors::common::TailType;
+///
+/// let (n1, n2) = z_sample_size_ind(0.5, 0.05, 0.80, 1.0, 1.0, 2.0, TailType::Two);
+/// assert_eq!((n1, n2), (48.0, 96.0));
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn z_sample_size_ind(
+    effect_size: f64,
+    alpha: f64,
+    power: f64,
+    std_dev1: f64,
+    std_dev2: f64,
+    ratio: f64,
+    tail: TailType,
+) -> (f64, f64) {
+    let effect = match tail {
+        TailType::Left => -effect_size.abs(),
+        _ => effect_size.abs(),
     };
 
-    let z_alpha = normal_dist.inverse_cdf(1.0 - alpha_value);
-    let z_beta = normal_dist.inverse_cdf(power);
+    solve_allocated_sample_size(
+        |n1, n2| z_power_ind(effect, alpha, n1, n2, std_dev1, std_dev2, tail.clone()),
+        alpha,
+        power,
+        ratio,
+        1.0,
+    )
+}
 
-    // Formula: n = ((z_alpha + z_beta) * std_dev / effect_size)^2
-    let n = ((z_alpha + z_beta) * std_dev / effect_size).powi(2);
-    n.ceil() // Rounds up to the next whole sample size
+/// Calculates the required number of pairs for a paired Z-test.
+///
+/// The standard deviation of the within-pair differences is derived from the standard
+/// deviation of each measurement and their correlation as `std_dev * √(2(1 - correlation))`,
+/// and the one-sample calculation of `z_sample_size` is applied to the differences.
+///
+/// # Arguments
+///
+/// * `effect_size` - The minimum detectable mean difference.
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval).
+/// * `power` - The desired statistical power (e.g., 0.80 for 80% power).
+/// * `std_dev` - The population standard deviation of each measurement.
+/// * `correlation` - The correlation between the two measurements of a pair, in `(-1, 1)`.
+/// * `tail` - The type of tail (left, right, or two) for the test.
+///
+/// # Returns
+///
+/// The number of pairs required, or `NaN` if the correlation is outside `(-1, 1)`.
+///
+/// # Example
+/// ```rust
+/// use hypors::z::z_sample_size_paired;
+/// use hypors::common::TailType;
+///
+/// let n = z_sample_size_paired(0.5, 0.05, 0.80, 1.0, 0.75, TailType::Two);
+/// assert_eq!(n, 16.0);
+/// ```
+pub fn z_sample_size_paired(
+    effect_size: f64,
+    alpha: f64,
+    power: f64,
+    std_dev: f64,
+    correlation: f64,
+    tail: TailType,
+) -> f64 {
+    if correlation <= -1.0 || correlation >= 1.0 {
+        return f64::NAN;
+    }
+    let diff_std_dev = std_dev * (2.0 * (1.0 - correlation)).sqrt();
+    z_sample_size(effect_size, alpha, power, diff_std_dev, tail)
 }
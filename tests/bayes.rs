@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests_bayes {
+    use hypors::bayes::{
+        BetaPrior, NormalInverseGammaPrior, SamplingPlan, beta_binomial_ab,
+        beta_binomial_ab_counts, gunel_dickey_bf, jzs_bf, jzs_bf_ind, jzs_bf_paired, jzs_bf_t,
+        normal_ab,
+    };
+
+    const EPSILON: f64 = 1e-6; // For floating-point comparisons
+
+    #[test]
+    fn test_beta_binomial_exact() {
+        // Reference values by numerical integration in mpmath
+        let result =
+            beta_binomial_ab_counts(100.0, 1000.0, 130.0, 1000.0, &BetaPrior::default(), 0.05)
+                .unwrap();
+        assert!((result.prob_b_beats_a - 0.982165066).abs() < EPSILON);
+        assert!((result.expected_loss_a - 0.030032494).abs() < EPSILON);
+        assert!((result.posterior_mean_a - 101.0 / 1002.0).abs() < 1e-12);
+        assert!(result.credible_interval_diff.0 > 0.0);
+
+        // Non-integer shapes fall back to numerical integration
+        let jeffreys = BetaPrior {
+            alpha: 0.5,
+            beta: 0.5,
+        };
+        let result =
+            beta_binomial_ab_counts(100.0, 1000.0, 130.0, 1000.0, &jeffreys, 0.05).unwrap();
+        assert!((result.prob_b_beats_a - 0.982345471).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_beta_binomial_unbounded_density() {
+        // A Jeffreys prior with no successes leaves a posterior shape below 1
+        let jeffreys = BetaPrior {
+            alpha: 0.5,
+            beta: 0.5,
+        };
+        let result = beta_binomial_ab_counts(0.0, 100.0, 5.0, 100.0, &jeffreys, 0.05).unwrap();
+        assert!((result.prob_b_beats_a - 0.993625925).abs() < EPSILON);
+
+        // Identical posteriors are exchangeable
+        let result = beta_binomial_ab_counts(0.0, 1000.0, 0.0, 1000.0, &jeffreys, 0.05).unwrap();
+        assert!((result.prob_b_beats_a - 0.5).abs() < EPSILON);
+        assert!((result.expected_loss_a - result.expected_loss_b).abs() < 1e-12);
+        let (low, high) = result.credible_interval_diff;
+        assert!(low < 0.0 && high > 0.0);
+        assert!((low + high).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_beta_binomial_data() {
+        let a = vec![1, 0, 0, 1, 0, 0, 0, 1, 0, 0];
+        let b = vec![1, 1, 0, 1, 0, 1, 0, 1, 1, 0];
+        let from_data = beta_binomial_ab(a, b, &BetaPrior::default(), 0.05).unwrap();
+        let from_counts =
+            beta_binomial_ab_counts(3.0, 10.0, 6.0, 10.0, &BetaPrior::default(), 0.05).unwrap();
+        assert_eq!(from_data.prob_b_beats_a, from_counts.prob_b_beats_a);
+
+        // Identical data under a symmetric prior gives even odds and equal losses
+        let same =
+            beta_binomial_ab_counts(5.0, 20.0, 5.0, 20.0, &BetaPrior::default(), 0.05).unwrap();
+        assert!((same.prob_b_beats_a - 0.5).abs() < EPSILON);
+        assert!((same.expected_loss_a - same.expected_loss_b).abs() < EPSILON);
+
+        assert!(beta_binomial_ab(vec![0, 2], vec![1, 0], &BetaPrior::default(), 0.05).is_err());
+        assert!(beta_binomial_ab(Vec::<i32>::new(), vec![1], &BetaPrior::default(), 0.05).is_err());
+    }
+
+    #[test]
+    fn test_normal_inverse_gamma() {
+        let a = vec![5.1, 4.9, 5.3, 5.0, 4.8, 5.2];
+        let b = vec![5.6, 5.4, 5.9, 5.5, 5.7, 5.3];
+        let result = normal_ab(a, b, &NormalInverseGammaPrior::default(), 0.05).unwrap();
+
+        // Reference prior: µ_A ~ t_6(5.05, √(0.175 / 36)), so the interval is 5.05 ± 2.4469 * 0.0697
+        assert!((result.posterior_mean_a - 5.05).abs() < 1e-12);
+        assert!((result.credible_interval_a.0 - 4.879397).abs() < EPSILON);
+        assert!((result.credible_interval_a.1 - 5.220603).abs() < EPSILON);
+        assert!(result.prob_b_beats_a > 0.99);
+
+        // A strong prior pulls both means towards the prior mean
+        let prior = NormalInverseGammaPrior {
+            mu: 5.0,
+            kappa: 100.0,
+            alpha: 2.0,
+            beta: 0.1,
+        };
+        let shrunk = normal_ab(vec![5.1, 4.9, 5.3], vec![5.6, 5.4, 5.9], &prior, 0.05).unwrap();
+        assert!(shrunk.posterior_mean_b < 5.05);
+
+        assert!(
+            normal_ab(
+                vec![1.0],
+                vec![2.0, 3.0],
+                &NormalInverseGammaPrior::default(),
+                0.05
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_jzs_bf_t() {
+        // Reference values by numerical integration in mpmath
+        let r = 2.0_f64.sqrt() / 2.0;
+        assert!((jzs_bf_t(2.5, 30.0, 29.0, r) - 2.709100713).abs() < EPSILON);
+        assert!((jzs_bf_t(0.5, 10.0, 9.0, 1.0) - 0.261363631).abs() < EPSILON);
+        assert!((jzs_bf_t(8.0, 50.0, 49.0, r) / 54372900.02 - 1.0).abs() < EPSILON);
+        assert!(jzs_bf_t(2.5, 30.0, 29.0, 0.0).is_nan());
+
+        // A wider prior penalizes a moderate effect
+        assert!(jzs_bf_t(2.0, 30.0, 29.0, 2.0) < jzs_bf_t(2.0, 30.0, 29.0, r));
+    }
+
+    #[test]
+    fn test_jzs_bf_samples() {
+        let r = 2.0_f64.sqrt() / 2.0;
+        let bf = jzs_bf(vec![0.8, 1.2, 0.4, 1.5, 0.9, 1.1, 0.7, 1.3], 0.0, r).unwrap();
+        assert!(bf > 100.0);
+
+        let bf = jzs_bf_paired(
+            vec![5.0, 6.0, 7.0, 8.0, 9.0],
+            vec![5.1, 5.9, 7.2, 7.9, 9.1],
+            r,
+        );
+        assert!(bf.unwrap() < 1.0);
+
+        let group1 = vec![5.1, 4.9, 5.3, 5.0, 4.8, 5.2];
+        let group2 = vec![5.6, 5.4, 5.9, 5.5, 5.7, 5.3];
+        let medium = jzs_bf_ind(group1.clone(), group2.clone(), r).unwrap();
+        assert!(medium > 10.0);
+        assert!(jzs_bf_ind(group1, group2, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_gunel_dickey_bf() {
+        // Reference values from the multivariate Beta formulas in mpmath
+        let table = vec![vec![30.0, 10.0], vec![15.0, 25.0]];
+        let joint = gunel_dickey_bf(&table, SamplingPlan::JointMultinomial, 1.0).unwrap();
+        let rows = gunel_dickey_bf(&table, SamplingPlan::IndependentRows, 1.0).unwrap();
+        assert!((joint - 121.256715146).abs() < 1e-6);
+        assert!((rows - 81.823637050).abs() < 1e-6);
+
+        // Fixed column totals are fixed row totals of the transposed table
+        let transposed = vec![vec![30.0, 15.0], vec![10.0, 25.0]];
+        let cols = gunel_dickey_bf(&table, SamplingPlan::IndependentColumns, 1.0).unwrap();
+        let rows_t = gunel_dickey_bf(&transposed, SamplingPlan::IndependentRows, 1.0).unwrap();
+        assert!((cols - rows_t).abs() < 1e-9);
+
+        let null = vec![vec![20.0, 20.0], vec![20.0, 20.0]];
+        assert!(gunel_dickey_bf(&null, SamplingPlan::IndependentRows, 1.0).unwrap() < 1.0);
+        assert!(gunel_dickey_bf(&[vec![1.0, 2.0]], SamplingPlan::JointMultinomial, 1.0).is_err());
+        assert!(gunel_dickey_bf(&table, SamplingPlan::JointMultinomial, 0.4).is_err());
+    }
+}
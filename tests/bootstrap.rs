@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests_bootstrap {
+    use hypors::bootstrap::{BootstrapMethod, bootstrap, bootstrap_block, bootstrap_stratified};
+    use hypors::common::StatError;
+
+    const EPSILON: f64 = 0.05; // Tolerance for Monte Carlo comparisons
+
+    fn mean(x: &[f64]) -> f64 {
+        x.iter().sum::<f64>() / x.len() as f64
+    }
+
+    fn data() -> Vec<f64> {
+        vec![
+            2.1, 3.4, 1.9, 5.6, 4.2, 3.3, 2.8, 4.9, 3.7, 2.5, 3.1, 4.4, 2.9, 3.8, 3.0, 4.1,
+        ]
+    }
+
+    #[test]
+    fn test_bootstrap_percentile() {
+        let result = bootstrap(data(), mean, BootstrapMethod::Percentile, 4000, 0.05, 42).unwrap();
+
+        // Analytic standard error of the mean: s / sqrt(n) * sqrt((n - 1) / n)
+        let expected_estimate = 3.48125;
+        let expected_std_error = 0.2419;
+
+        assert!((result.estimate - expected_estimate).abs() < 1e-9);
+        assert!((result.std_error - expected_std_error).abs() < EPSILON);
+        assert!(result.bias.abs() < EPSILON);
+        assert!(result.confidence_interval.0 < expected_estimate);
+        assert!(result.confidence_interval.1 > expected_estimate);
+        assert_eq!(result.n_resamples, 4000);
+    }
+
+    #[test]
+    fn test_bootstrap_methods_agree_for_mean() {
+        let methods = [
+            BootstrapMethod::Percentile,
+            BootstrapMethod::Basic,
+            BootstrapMethod::Studentized {
+                inner_resamples: 50,
+            },
+            BootstrapMethod::BCa,
+        ];
+
+        // t-interval for the mean: (2.9487, 4.0138)
+        for method in methods {
+            let result = bootstrap(data(), mean, method, 2000, 0.05, 7).unwrap();
+            assert!((result.confidence_interval.0 - 2.95).abs() < 0.15);
+            assert!((result.confidence_interval.1 - 4.01).abs() < 0.15);
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_seed_is_deterministic() {
+        let first = bootstrap(data(), mean, BootstrapMethod::BCa, 500, 0.05, 11).unwrap();
+        let second = bootstrap(data(), mean, BootstrapMethod::BCa, 500, 0.05, 11).unwrap();
+
+        assert_eq!(first.confidence_interval, second.confidence_interval);
+        assert_eq!(first.std_error, second.std_error);
+    }
+
+    #[test]
+    fn test_bootstrap_stratified() {
+        let control = vec![12.0, 15.0, 14.0, 16.0, 13.0, 18.0];
+        let treatment = vec![20.0, 22.0, 19.0, 24.0, 21.0];
+
+        let result = bootstrap_stratified(
+            &[control, treatment],
+            |s: &[Vec<f64>]| mean(&s[1]) - mean(&s[0]),
+            BootstrapMethod::Percentile,
+            2000,
+            0.05,
+            3,
+        )
+        .unwrap();
+
+        assert!((result.estimate - 6.5333).abs() < 1e-4);
+        assert!(result.confidence_interval.0 > 0.0);
+        assert!(result.confidence_interval.1 > result.estimate);
+    }
+
+    #[test]
+    fn test_bootstrap_block() {
+        let series: Vec<f64> = (0..40).map(|i| ((i as f64) / 3.0).sin()).collect();
+
+        let result = bootstrap_block(
+            series.iter().copied(),
+            5,
+            mean,
+            BootstrapMethod::BCa,
+            1000,
+            0.05,
+            5,
+        )
+        .unwrap();
+
+        assert!(result.confidence_interval.0 < result.estimate);
+        assert!(result.confidence_interval.1 > result.estimate);
+        assert!(bootstrap_block(series, 0, mean, BootstrapMethod::Basic, 100, 0.05, 5).is_err());
+    }
+
+    #[test]
+    fn test_bootstrap_errors() {
+        let empty: Vec<f64> = vec![];
+        assert_eq!(
+            bootstrap(empty, mean, BootstrapMethod::Percentile, 100, 0.05, 1).unwrap_err(),
+            StatError::EmptyData
+        );
+        assert!(bootstrap(data(), mean, BootstrapMethod::Percentile, 1, 0.05, 1).is_err());
+        assert!(bootstrap(data(), mean, BootstrapMethod::Percentile, 100, 1.5, 1).is_err());
+    }
+}
@@ -1,8 +1,11 @@
 #[cfg(test)]
 mod tests_chi_square {
     use hypors::chi_square::{
-        chi2_sample_size_gof, chi2_sample_size_ind, chi2_sample_size_variance, goodness_of_fit,
-        independence, variance,
+        FittedDistribution, IndependenceStatistic, chi2_power_gof, chi2_sample_size_gof,
+        chi2_sample_size_ind, chi2_sample_size_variance, cochran_armitage, goodness_of_fit,
+        goodness_of_fit_ddof, goodness_of_fit_exact, goodness_of_fit_fitted,
+        goodness_of_fit_monte_carlo, independence, independence_monte_carlo, independence_test,
+        linear_by_linear, merge_sparse_bins, variance,
     };
     use hypors::common::TailType;
 
@@ -51,6 +54,80 @@ mod tests_chi_square {
         assert_eq!(result.reject_null, true);
     }
 
+    #[test]
+    fn test_independence_statistics() {
+        let table = vec![vec![20.0, 30.0], vec![50.0, 10.0]];
+
+        let expected_statistics = [
+            (IndependenceStatistic::Pearson, 22.1310),
+            (IndependenceStatistic::Yates, 20.2979),
+            (IndependenceStatistic::LikelihoodRatio, 22.8375),
+            (IndependenceStatistic::Williams, 22.4941),
+        ];
+        for (statistic, expected) in expected_statistics {
+            let result = independence_test(&table, statistic, 0.05).unwrap();
+            assert!((result.result.test_statistic - expected).abs() < EPSILON);
+            assert!(result.result.reject_null);
+        }
+
+        // The plain test is the Pearson statistic
+        let pearson = independence_test(&table, IndependenceStatistic::Pearson, 0.05).unwrap();
+        let plain = independence(&table, 0.05).unwrap();
+        assert_eq!(pearson.result.test_statistic, plain.test_statistic);
+        assert_eq!(pearson.result.p_value, plain.p_value);
+
+        // Yates' correction is only defined for 2x2 tables
+        let wide = vec![vec![12.0, 5.0, 9.0], vec![7.0, 15.0, 4.0]];
+        assert!(independence_test(&wide, IndependenceStatistic::Yates, 0.05).is_err());
+        let negative = vec![vec![-1.0, 5.0], vec![7.0, 15.0]];
+        assert!(independence_test(&negative, IndependenceStatistic::Pearson, 0.05).is_err());
+    }
+
+    #[test]
+    fn test_independence_residuals_and_effect_sizes() {
+        let table = vec![vec![20.0, 30.0], vec![50.0, 10.0]];
+        let result = independence_test(&table, IndependenceStatistic::Pearson, 0.05).unwrap();
+
+        // Noncentral chi-square intervals for the noncentrality, transformed through λ + df
+        let phi = result.phi.unwrap();
+        assert!((phi.estimate + 0.4485).abs() < EPSILON);
+        assert!((phi.confidence_interval.0 + 0.6425).abs() < EPSILON);
+        assert!((phi.confidence_interval.1 + 0.2785).abs() < EPSILON);
+        assert!((result.cramers_v.estimate - 0.4485).abs() < EPSILON);
+        assert!((result.cramers_v.confidence_interval.0 - 0.2785).abs() < EPSILON);
+        assert!((result.contingency_coefficient.estimate - 0.4093).abs() < EPSILON);
+
+        let table = vec![vec![12.0, 5.0, 9.0], vec![7.0, 15.0, 4.0]];
+        let result = independence_test(&table, IndependenceStatistic::Pearson, 0.05).unwrap();
+        assert!(result.phi.is_none());
+        assert_eq!(result.expected[0], vec![9.5, 10.0, 6.5]);
+        assert!((result.pearson_residuals[0][1] + 1.5811).abs() < EPSILON);
+        assert!((result.adjusted_residuals[0][0] - 1.4399).abs() < EPSILON);
+        assert!((result.adjusted_residuals[0][1] + 2.8504).abs() < EPSILON);
+        assert!((result.cramers_v.estimate - 0.3980).abs() < EPSILON);
+        assert!((result.cramers_v.confidence_interval.0 - 0.2078).abs() < EPSILON);
+        assert!((result.cramers_v.confidence_interval.1 - 0.6798).abs() < EPSILON);
+        assert!(result.warnings.is_empty());
+
+        // X² below its degrees of freedom is consistent with no association
+        let table: Vec<Vec<f64>> = (0..6)
+            .map(|i| (0..6).map(|j| if i == j { 12.0 } else { 10.0 }).collect())
+            .collect();
+        let result = independence_test(&table, IndependenceStatistic::Pearson, 0.05).unwrap();
+        assert!(result.result.test_statistic < 25.0);
+        for measure in [&result.cramers_v, &result.contingency_coefficient] {
+            let (low, high) = measure.confidence_interval;
+            assert_eq!(low, 0.0);
+            assert!(measure.estimate > 0.0 && measure.estimate < high);
+        }
+
+        // Sparse tables are flagged
+        let sparse = vec![vec![3.0, 1.0], vec![2.0, 6.0]];
+        let result = independence_test(&sparse, IndependenceStatistic::Pearson, 0.05).unwrap();
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].starts_with("4 of 4 expected counts"));
+    }
+
     #[test]
     fn test_goodness_of_fit() {
         let observed = vec![30.0, 10.0, 20.0];
@@ -74,13 +151,231 @@ mod tests_chi_square {
         assert_eq!(result.reject_null, false);
     }
 
+    #[test]
+    fn test_goodness_of_fit_exact() {
+        // Enumerating all 28 outcomes of 6 draws from three equally likely categories
+        let result = goodness_of_fit_exact(vec![1.0, 1.0, 4.0], vec![2.0, 2.0, 2.0], 0.05).unwrap();
+        assert!((result.test_statistic - 3.0).abs() < 1e-12);
+        assert!((result.p_value - 31.0 / 81.0).abs() < 1e-9);
+        assert!(!result.reject_null);
+
+        // Expected frequencies are rescaled to the observed total
+        let scaled = goodness_of_fit_exact(vec![1.0, 1.0, 4.0], vec![1.0, 1.0, 1.0], 0.05).unwrap();
+        assert!((scaled.p_value - result.p_value).abs() < 1e-12);
+
+        assert!(goodness_of_fit_exact(vec![1.5, 1.0], vec![1.0, 1.0], 0.05).is_err());
+        assert!(goodness_of_fit_exact(vec![1.0, 1.0], vec![0.0, 1.0], 0.05).is_err());
+    }
+
+    #[test]
+    fn test_monte_carlo_p_values() {
+        // Close to the exact p-value, and reproducible from the seed
+        let observed = vec![1.0, 1.0, 4.0];
+        let expected = vec![2.0, 2.0, 2.0];
+        let result =
+            goodness_of_fit_monte_carlo(observed.clone(), expected.clone(), 20000, 0.05, 1)
+                .unwrap();
+        assert!((result.p_value - 31.0 / 81.0).abs() < 0.01);
+        let repeat = goodness_of_fit_monte_carlo(observed, expected, 20000, 0.05, 1).unwrap();
+        assert_eq!(result.p_value, repeat.p_value);
+
+        // Samples of a billion units agree with the asymptotic p-value, exp(-2.4)
+        let observed = vec![250_030_000.0, 249_990_000.0, 499_980_000.0];
+        let expected = vec![1.0, 1.0, 2.0];
+        let result = goodness_of_fit_monte_carlo(observed, expected, 20000, 0.05, 1).unwrap();
+        assert!((result.test_statistic - 4.8).abs() < EPSILON);
+        assert!((result.p_value - (-2.4f64).exp()).abs() < 0.01);
+
+        // Exact permutation p-value of the table with fixed margins is 1/30
+        let table = vec![vec![3.0, 1.0, 0.0], vec![0.0, 2.0, 4.0]];
+        let result = independence_monte_carlo(&table, 20000, 0.05, 42).unwrap();
+        assert!((result.test_statistic - 7.2222).abs() < EPSILON);
+        assert!((result.p_value - 1.0 / 30.0).abs() < 0.005);
+        assert!(result.reject_null);
+
+        // The p-value is never below 1 / (B + 1)
+        let table = vec![vec![20.0, 30.0], vec![50.0, 10.0]];
+        let result = independence_monte_carlo(&table, 999, 0.05, 42).unwrap();
+        assert_eq!(result.p_value, 0.001);
+
+        assert!(independence_monte_carlo(&table, 0, 0.05, 42).is_err());
+        assert!(independence_monte_carlo(&[vec![1.5, 2.0], vec![3.0, 4.0]], 10, 0.05, 42).is_err());
+    }
+
+    #[test]
+    fn test_goodness_of_fit_ddof() {
+        let observed = vec![30.0, 10.0, 20.0, 15.0];
+        let expected = vec![25.0, 15.0, 20.0, 15.0];
+
+        let plain = goodness_of_fit(observed.clone(), expected.clone(), 0.05).unwrap();
+        let adjusted = goodness_of_fit_ddof(observed.clone(), expected.clone(), 1, 0.05).unwrap();
+        assert_eq!(plain.test_statistic, adjusted.test_statistic);
+        // Two degrees of freedom remain
+        assert!((adjusted.p_value - (-adjusted.test_statistic / 2.0).exp()).abs() < 1e-9);
+        assert!(adjusted.p_value < plain.p_value);
+
+        assert!(goodness_of_fit_ddof(observed, expected, 3, 0.05).is_err());
+    }
+
+    #[test]
+    fn test_goodness_of_fit_fitted_discrete() {
+        let counts = [(0, 20), (1, 30), (2, 25), (3, 15), (4, 7), (5, 3)];
+        let data: Vec<f64> = counts
+            .iter()
+            .flat_map(|&(value, n)| std::iter::repeat_n(value as f64, n))
+            .collect();
+
+        // The sparse counts of 4 and 5 merge into an open upper tail
+        let fit =
+            goodness_of_fit_fitted(data.clone(), FittedDistribution::Poisson, 5.0, 0.05).unwrap();
+        assert!((fit.parameters[0] - 1.68).abs() < 1e-12);
+        assert_eq!(fit.observed, vec![20.0, 30.0, 25.0, 15.0, 10.0]);
+        assert_eq!(fit.bins.last().unwrap(), &(4.0, f64::INFINITY));
+        assert!((fit.expected[0] - 18.6374).abs() < EPSILON);
+        assert!((fit.expected.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+        assert!((fit.result.test_statistic - 0.3299).abs() < EPSILON);
+        assert_eq!(fit.ddof, 1);
+        assert!(!fit.result.reject_null);
+
+        let fit = goodness_of_fit_fitted(
+            data.clone(),
+            FittedDistribution::Binomial { trials: 5 },
+            5.0,
+            0.05,
+        )
+        .unwrap();
+        assert!((fit.parameters[0] - 0.336).abs() < 1e-12);
+        assert_eq!(fit.observed.len(), 4);
+        assert!((fit.result.test_statistic - 6.6860).abs() < EPSILON);
+
+        let fit =
+            goodness_of_fit_fitted(data.clone(), FittedDistribution::Geometric, 5.0, 0.05).unwrap();
+        assert!((fit.parameters[0] - 1.0 / 2.68).abs() < 1e-12);
+        assert!(fit.result.reject_null);
+
+        // An outlier does not create a bin for every value up to it
+        let mut outlier = data.clone();
+        outlier.push(1e12);
+        for distribution in [FittedDistribution::Poisson, FittedDistribution::Geometric] {
+            let fit = goodness_of_fit_fitted(outlier.clone(), distribution, 5.0, 0.05).unwrap();
+            assert!(fit.bins.len() <= 21);
+            assert_eq!(fit.bins.last().unwrap().1, f64::INFINITY);
+            assert!((fit.expected.iter().sum::<f64>() - 101.0).abs() < 1e-6);
+            assert!(fit.result.reject_null);
+        }
+
+        assert!(
+            goodness_of_fit_fitted(data, FittedDistribution::Binomial { trials: 4 }, 5.0, 0.05)
+                .is_err()
+        );
+        assert!(
+            goodness_of_fit_fitted(vec![0.0; 10], FittedDistribution::Poisson, 5.0, 0.05).is_err()
+        );
+        assert!(
+            goodness_of_fit_fitted(vec![0.5, 1.0], FittedDistribution::Poisson, 5.0, 0.05).is_err()
+        );
+    }
+
+    #[test]
+    fn test_goodness_of_fit_fitted_normal() {
+        let data: Vec<f64> = (0..50)
+            .map(|i| ((i * 37 % 50) as f64 / 50.0 + 0.01).ln())
+            .collect();
+        let fit = goodness_of_fit_fitted(data, FittedDistribution::Normal { bins: 5 }, 5.0, 0.05)
+            .unwrap();
+        assert_eq!(fit.expected, vec![10.0; 5]);
+        assert_eq!(fit.observed.iter().sum::<f64>(), 50.0);
+        assert_eq!(fit.ddof, 2);
+        assert!((fit.result.test_statistic - 8.2).abs() < 1e-9);
+
+        // Too few observations per bin leave no degrees of freedom after merging
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        assert!(
+            goodness_of_fit_fitted(data, FittedDistribution::Normal { bins: 3 }, 5.0, 0.05)
+                .is_err()
+        );
+
+        let (observed, expected) = merge_sparse_bins(
+            &[2.0, 9.0, 12.0, 4.0, 1.0],
+            &[3.0, 8.0, 10.0, 5.0, 2.0],
+            5.0,
+        )
+        .unwrap();
+        assert_eq!(observed, vec![11.0, 12.0, 5.0]);
+        assert_eq!(expected, vec![11.0, 10.0, 7.0]);
+    }
+
+    #[test]
+    fn test_cochran_armitage() {
+        // prop.trend.test(c(83, 90, 129, 70), c(86, 93, 136, 82)) in R
+        let smokers = vec![83.0, 90.0, 129.0, 70.0];
+        let non_smokers = vec![3.0, 3.0, 7.0, 12.0];
+        let table = vec![smokers, non_smokers];
+        let scores = [1.0, 2.0, 3.0, 4.0];
+
+        let result = cochran_armitage(&table, &scores, TailType::Two, 0.05).unwrap();
+        assert!((result.test_statistic + 2.8679).abs() < EPSILON);
+        assert!((result.test_statistic.powi(2) - 8.2249).abs() < EPSILON);
+        assert!((result.p_value - 0.004132).abs() < 1e-5);
+        assert!(result.reject_null);
+
+        // The proportion of smokers decreases with age
+        let left = cochran_armitage(&table, &scores, TailType::Left, 0.05).unwrap();
+        assert!((left.p_value - result.p_value / 2.0).abs() < 1e-12);
+        assert_eq!(
+            left.alt_hypothesis,
+            "Ha: Proportions decrease with the scores"
+        );
+        let right = cochran_armitage(&table, &scores, TailType::Right, 0.05).unwrap();
+        assert!(!right.reject_null);
+
+        // Rescaling the scores linearly leaves the test unchanged
+        let doses = [10.0, 20.0, 30.0, 40.0];
+        let rescaled = cochran_armitage(&table, &doses, TailType::Two, 0.05).unwrap();
+        assert!((rescaled.test_statistic - result.test_statistic).abs() < 1e-9);
+
+        assert!(cochran_armitage(&table, &[1.0, 2.0, 3.0], TailType::Two, 0.05).is_err());
+        assert!(cochran_armitage(&table, &[1.0; 4], TailType::Two, 0.05).is_err());
+        let three_rows = vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]];
+        assert!(cochran_armitage(&three_rows, &[1.0, 2.0], TailType::Two, 0.05).is_err());
+    }
+
+    #[test]
+    fn test_linear_by_linear() {
+        // Infant malformation by maternal alcohol consumption (Agresti, 2002): M² = 6.57
+        let table = vec![
+            vec![17066.0, 48.0],
+            vec![14464.0, 38.0],
+            vec![788.0, 5.0],
+            vec![126.0, 1.0],
+            vec![37.0, 1.0],
+        ];
+        let alcohol = [0.0, 0.5, 1.5, 4.0, 7.0];
+        let result = linear_by_linear(&table, &alcohol, &[0.0, 1.0], TailType::Two, 0.05).unwrap();
+        assert!((result.test_statistic.powi(2) - 6.5699).abs() < EPSILON);
+        assert!((result.p_value - 0.0104).abs() < EPSILON);
+        assert!(result.reject_null);
+
+        // For 2×k tables, M² = (N - 1) / N · Z² of the Cochran-Armitage test
+        let table = vec![vec![83.0, 90.0, 129.0, 70.0], vec![3.0, 3.0, 7.0, 12.0]];
+        let scores = [1.0, 2.0, 3.0, 4.0];
+        let z = cochran_armitage(&table, &scores, TailType::Two, 0.05).unwrap();
+        let m = linear_by_linear(&table, &[1.0, 0.0], &scores, TailType::Two, 0.05).unwrap();
+        assert!((m.test_statistic.powi(2) - z.test_statistic.powi(2) * 396.0 / 397.0).abs() < 1e-9);
+
+        assert!(linear_by_linear(&table, &[1.0, 1.0], &scores, TailType::Two, 0.05).is_err());
+        assert!(linear_by_linear(&table, &[1.0, 0.0], &[1.0, 2.0], TailType::Two, 0.05).is_err());
+    }
+
     #[test]
     fn test_chi2_sample_size_gof() {
-        let expected_counts = vec![20, 30, 50];
+        let effect_size = 0.3;
         let alpha = 0.05;
+        let power = 0.80;
+        let num_categories = 3;
 
-        let n = chi2_sample_size_gof(&expected_counts, alpha);
-        let expected_sample_size = 480.0;
+        let n = chi2_sample_size_gof(effect_size, alpha, power, num_categories);
+        let expected_sample_size = 108.0;
 
         assert!(
             (n - expected_sample_size).abs() < 1.0,
@@ -90,11 +385,12 @@ mod tests_chi_square {
 
     #[test]
     fn test_chi2_sample_size_ind() {
-        let expected_counts = vec![20, 30, 50];
+        let effect_size = 0.3;
         let alpha = 0.05;
+        let power = 0.80;
 
-        let n = chi2_sample_size_ind(&expected_counts, alpha);
-        let expected_sample_size = 480.0;
+        let n = chi2_sample_size_ind(effect_size, alpha, power, 3, 3);
+        let expected_sample_size = 133.0;
 
         assert!(
             (n - expected_sample_size).abs() < 1.0,
@@ -102,6 +398,14 @@ mod tests_chi_square {
         );
     }
 
+    #[test]
+    fn test_chi2_power_gof() {
+        let power = chi2_power_gof(0.3, 0.05, 108.0, 3);
+        let expected_power = 0.80369;
+
+        assert!((power - expected_power).abs() < EPSILON);
+    }
+
     #[test]
     fn test_chi2_sample_size_variance() {
         let effect_size = 0.5;
@@ -110,7 +414,7 @@ mod tests_chi_square {
         let variance = 1.0;
 
         let n = chi2_sample_size_variance(effect_size, alpha, power, variance);
-        let expected_sample_size = 121.0;
+        let expected_sample_size = 74.0;
 
         assert!(
             (n - expected_sample_size).abs() < 1.0,
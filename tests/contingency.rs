@@ -0,0 +1,323 @@
+#[cfg(test)]
+mod tests_contingency {
+    use hypors::contingency::{
+        ContingencyTable, LogLinearModel, OddsRatioMethod, RiskDifferenceMethod, breslow_day,
+        cmh_test, compare_loglinear, fit_loglinear, mantel_haenszel_or, odds_ratio,
+        risk_difference, risk_ratio,
+    };
+
+    const EPSILON: f64 = 1e-4; // For comparisons with published values
+
+    #[test]
+    fn test_risk_difference_newcombe_1998() {
+        // Newcombe (1998), example (a): 56/70 vs 48/80
+        let table = vec![vec![56.0, 14.0], vec![48.0, 32.0]];
+
+        let newcombe = risk_difference(&table, RiskDifferenceMethod::Newcombe, 0.05).unwrap();
+        assert!((newcombe.estimate - 0.2).abs() < 1e-12);
+        assert!((newcombe.result.confidence_interval.0 - 0.0524).abs() < EPSILON);
+        assert!((newcombe.result.confidence_interval.1 - 0.3339).abs() < EPSILON);
+
+        let mn = risk_difference(&table, RiskDifferenceMethod::MiettinenNurminen, 0.05).unwrap();
+        assert!((mn.result.confidence_interval.0 - 0.0528).abs() < EPSILON);
+        assert!((mn.result.confidence_interval.1 - 0.3382).abs() < EPSILON);
+        assert!(mn.result.reject_null);
+    }
+
+    #[test]
+    fn test_risk_difference_extremes() {
+        // All events in one group and none in the other keeps the interval inside [-1, 1]
+        let table = vec![vec![10.0, 0.0], vec![0.0, 10.0]];
+        for method in [
+            RiskDifferenceMethod::Newcombe,
+            RiskDifferenceMethod::MiettinenNurminen,
+        ] {
+            let rd = risk_difference(&table, method, 0.05).unwrap();
+            assert_eq!(rd.estimate, 1.0);
+            let (low, high) = rd.result.confidence_interval;
+            assert!(low > 0.0 && low < 1.0);
+            assert!((high - 1.0).abs() < 1e-9);
+        }
+
+        // No difference gives a zero score statistic
+        let table = vec![vec![0.0, 10.0], vec![0.0, 20.0]];
+        let rd = risk_difference(&table, RiskDifferenceMethod::MiettinenNurminen, 0.05).unwrap();
+        assert_eq!(rd.result.test_statistic, 0.0);
+        assert_eq!(rd.result.p_value, 1.0);
+    }
+
+    #[test]
+    fn test_risk_ratio_katz() {
+        let table = vec![vec![30.0, 70.0], vec![15.0, 85.0]];
+        let rr = risk_ratio(&table, 0.05).unwrap();
+        assert!((rr.estimate - 2.0).abs() < 1e-12);
+        // exp(ln 2 ± 1.96 √(1/30 - 1/100 + 1/15 - 1/100))
+        assert!((rr.result.confidence_interval.0 - 1.1489).abs() < EPSILON);
+        assert!((rr.result.confidence_interval.1 - 3.4817).abs() < EPSILON);
+        assert!((rr.result.p_value - 0.0143).abs() < EPSILON);
+
+        // Zero events in a group add 0.5 to every cell
+        let table = vec![vec![0.0, 10.0], vec![5.0, 5.0]];
+        let rr = risk_ratio(&table, 0.05).unwrap();
+        assert!((rr.estimate - (0.5 / 11.0) / (5.5 / 11.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_odds_ratio_methods() {
+        // Exact values by root finding in mpmath
+        let table = vec![vec![3.0, 1.0], vec![1.0, 3.0]];
+
+        let woolf = odds_ratio(&table, OddsRatioMethod::Woolf, 0.05).unwrap();
+        assert!((woolf.estimate - 9.0).abs() < 1e-12);
+
+        let exact = odds_ratio(&table, OddsRatioMethod::Exact, 0.05).unwrap();
+        assert!((exact.estimate - 6.408320).abs() < EPSILON);
+        assert!((exact.result.confidence_interval.0 - 0.211736).abs() < 1e-6);
+        assert!((exact.result.confidence_interval.1 / 626.243531 - 1.0).abs() < 1e-6);
+        assert!((exact.result.p_value - 17.0 / 35.0).abs() < 1e-9);
+
+        let mid_p = odds_ratio(&table, OddsRatioMethod::MidP, 0.05).unwrap();
+        assert!((mid_p.result.confidence_interval.0 - 0.310055).abs() < 1e-6);
+        assert!((mid_p.result.confidence_interval.1 / 308.556772 - 1.0).abs() < 1e-6);
+        assert!((mid_p.result.p_value - 9.0 / 35.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_odds_ratio_boundaries_and_errors() {
+        let table = vec![vec![0.0, 10.0], vec![5.0, 5.0]];
+        let exact = odds_ratio(&table, OddsRatioMethod::Exact, 0.05).unwrap();
+        assert_eq!(exact.estimate, 0.0);
+        assert_eq!(exact.result.confidence_interval.0, 0.0);
+        assert!(exact.result.confidence_interval.1 < 1.0);
+        assert!(exact.result.reject_null);
+
+        let woolf = odds_ratio(&table, OddsRatioMethod::Woolf, 0.05).unwrap();
+        assert!((woolf.estimate - 0.5 * 5.5 / (10.5 * 5.5)).abs() < 1e-12);
+
+        assert!(
+            odds_ratio(
+                &[vec![1.5, 2.0], vec![3.0, 4.0]],
+                OddsRatioMethod::Exact,
+                0.05
+            )
+            .is_err()
+        );
+        assert!(
+            odds_ratio(
+                &[vec![1.0, 2.0, 3.0], vec![3.0, 4.0, 5.0]],
+                OddsRatioMethod::Woolf,
+                0.05
+            )
+            .is_err()
+        );
+        assert!(risk_ratio(&[vec![0.0, 0.0], vec![3.0, 4.0]], 0.05).is_err());
+        assert!(
+            risk_difference(
+                &[vec![-1.0, 2.0], vec![3.0, 4.0]],
+                RiskDifferenceMethod::Newcombe,
+                0.05
+            )
+            .is_err()
+        );
+    }
+
+    fn rabbits() -> Vec<Vec<Vec<f64>>> {
+        // Penicillin and rabbits data from R's mantelhaen.test documentation
+        vec![
+            vec![vec![0.0, 6.0], vec![0.0, 5.0]],
+            vec![vec![3.0, 3.0], vec![0.0, 6.0]],
+            vec![vec![6.0, 0.0], vec![2.0, 4.0]],
+            vec![vec![5.0, 1.0], vec![6.0, 0.0]],
+            vec![vec![2.0, 0.0], vec![5.0, 0.0]],
+        ]
+    }
+
+    #[test]
+    fn test_cmh_test_2x2() {
+        let tables = rabbits();
+
+        let corrected = cmh_test(&tables, true, 0.05).unwrap();
+        assert!((corrected.test_statistic - 3.928571).abs() < EPSILON);
+        assert!((corrected.p_value - 0.047472).abs() < EPSILON);
+        assert!(corrected.reject_null);
+
+        let uncorrected = cmh_test(&tables, false, 0.05).unwrap();
+        assert!((uncorrected.test_statistic - 5.657143).abs() < EPSILON);
+
+        // The interval is the Robins-Breslow-Greenland interval of the common odds ratio
+        assert!((corrected.confidence_interval.0 - 1.026713).abs() < EPSILON);
+        assert!((corrected.confidence_interval.1 - 47.725133).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_cmh_test_general_association() {
+        let tables = vec![
+            vec![vec![10.0, 5.0, 3.0], vec![4.0, 8.0, 6.0]],
+            vec![vec![7.0, 6.0, 2.0], vec![3.0, 9.0, 8.0]],
+        ];
+        let result = cmh_test(&tables, false, 0.05).unwrap();
+        assert!((result.test_statistic - 8.913515).abs() < EPSILON);
+        assert!((result.p_value - (-8.913515_f64 / 2.0).exp()).abs() < EPSILON); // df = 2
+        assert!(result.confidence_interval.0.is_nan());
+
+        // A single 2x2 stratum matches the generalized statistic
+        let single = vec![vec![vec![12.0, 8.0], vec![5.0, 15.0]]];
+        let two_by_two = cmh_test(&single, false, 0.05).unwrap();
+        let n: f64 = 40.0;
+        let expected =
+            (12.0 - 20.0 * 17.0 / n).powi(2) / (20.0 * 20.0 * 17.0 * 23.0 / (n * n * (n - 1.0)));
+        assert!((two_by_two.test_statistic - expected).abs() < 1e-9);
+
+        assert!(cmh_test(&[], false, 0.05).is_err());
+        assert!(
+            cmh_test(
+                &[
+                    vec![vec![1.0, 2.0], vec![3.0, 4.0]],
+                    vec![vec![1.0, 2.0, 3.0], vec![3.0, 4.0, 5.0]],
+                ],
+                false,
+                0.05
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_mantel_haenszel_or() {
+        let or = mantel_haenszel_or(&rabbits(), 0.05).unwrap();
+        assert!((or.estimate - 7.0).abs() < 1e-12);
+        assert!((or.result.confidence_interval.0 - 1.026713).abs() < EPSILON);
+        assert!((or.result.confidence_interval.1 - 47.725133).abs() < EPSILON);
+        assert!(or.result.reject_null);
+
+        // Identical strata reproduce the odds ratio of each stratum
+        let stratum = vec![vec![20.0, 10.0], vec![10.0, 20.0]];
+        let or = mantel_haenszel_or(&[stratum.clone(), stratum], 0.05).unwrap();
+        assert!((or.estimate - 4.0).abs() < 1e-12);
+
+        assert!(
+            mantel_haenszel_or(&[vec![vec![1.0, 2.0, 3.0], vec![3.0, 4.0, 5.0]]], 0.05).is_err()
+        );
+        assert!(mantel_haenszel_or(&[vec![vec![5.0, 0.0], vec![0.0, 5.0]]], 0.05).is_err());
+    }
+
+    #[test]
+    fn test_breslow_day() {
+        let tables = vec![
+            vec![vec![20.0, 10.0], vec![10.0, 20.0]],
+            vec![vec![10.0, 20.0], vec![20.0, 10.0]],
+            vec![vec![15.0, 12.0], vec![9.0, 14.0]],
+        ];
+        let result = breslow_day(&tables, false, 0.05).unwrap();
+        assert!((result.test_statistic - 14.314326).abs() < EPSILON);
+        assert!((result.p_value - (-14.314326_f64 / 2.0).exp()).abs() < EPSILON); // df = 2
+        assert!(result.reject_null);
+
+        let tarone = breslow_day(&tables, true, 0.05).unwrap();
+        assert!((tarone.test_statistic - 14.311958).abs() < EPSILON);
+
+        // Identical strata are perfectly homogeneous
+        let stratum = vec![vec![20.0, 10.0], vec![10.0, 20.0]];
+        let result = breslow_day(&[stratum.clone(), stratum.clone()], true, 0.05).unwrap();
+        assert!(result.test_statistic.abs() < 1e-9);
+        assert!(!result.reject_null);
+
+        assert!(breslow_day(&[stratum], false, 0.05).is_err());
+    }
+
+    fn substance_use() -> ContingencyTable {
+        // Alcohol (A), cigarette (C) and marijuana (M) use of high school seniors (Agresti, 2002)
+        ContingencyTable::new(
+            vec![2, 2, 2],
+            vec![911.0, 538.0, 44.0, 456.0, 3.0, 43.0, 2.0, 279.0],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_fit_loglinear_three_way() {
+        let table = substance_use();
+
+        // Deviances and degrees of freedom from Agresti (2002), Table 8.5
+        let models = [
+            (LogLinearModel::mutual_independence(3), 1286.02, 4),
+            (LogLinearModel::new(vec![vec![0, 1], vec![2]]), 843.83, 3),
+            (LogLinearModel::conditional_independence(3, 1, 2), 497.37, 2),
+            (LogLinearModel::conditional_independence(3, 0, 2), 92.02, 2),
+            (LogLinearModel::conditional_independence(3, 0, 1), 187.75, 2),
+            (LogLinearModel::homogeneous_association(3), 0.37, 1),
+        ];
+        for (model, deviance, df) in models {
+            let fit = fit_loglinear(&table, &model, 0.05).unwrap();
+            assert!((fit.deviance - deviance).abs() < 0.01);
+            assert_eq!(fit.df, df);
+            assert!(fit.converged);
+        }
+
+        let fit = fit_loglinear(&table, &LogLinearModel::homogeneous_association(3), 0.05).unwrap();
+        assert!((fit.fitted[0] - 910.38).abs() < 0.01);
+        assert!((fit.pearson - 0.401).abs() < 1e-3);
+        assert!(!fit.result.reject_null);
+        // Fitted margins reproduce the observed margins
+        assert!((fit.fitted[0] + fit.fitted[1] - 1449.0).abs() < 1e-6);
+
+        // Closed-form fit of mutual independence: n · p_A · p_C · p_M
+        let fit = fit_loglinear(&table, &LogLinearModel::mutual_independence(3), 0.05).unwrap();
+        let expected = 2276.0 * (1949.0 / 2276.0) * (1495.0 / 2276.0) * (960.0 / 2276.0);
+        assert!((fit.fitted[0] - expected).abs() < 1e-6);
+
+        // A and C remain associated given M
+        let reduced = fit_loglinear(
+            &table,
+            &LogLinearModel::conditional_independence(3, 0, 1),
+            0.05,
+        )
+        .unwrap();
+        let full =
+            fit_loglinear(&table, &LogLinearModel::homogeneous_association(3), 0.05).unwrap();
+        let comparison = compare_loglinear(&reduced, &full, 0.05).unwrap();
+        assert!((comparison.test_statistic - 187.38).abs() < 0.01);
+        assert!(comparison.reject_null);
+        assert!(compare_loglinear(&full, &reduced, 0.05).is_err());
+    }
+
+    #[test]
+    fn test_fit_loglinear_four_way_and_errors() {
+        let counts: Vec<f64> = (0..24).map(|i| ((i * 7) % 11 + 3) as f64).collect();
+        let table = ContingencyTable::new(vec![2, 3, 2, 2], counts).unwrap();
+        assert_eq!(table.get(&[1, 2, 1, 1]), ((23 * 7) % 11 + 3) as f64);
+
+        let mutual = fit_loglinear(&table, &LogLinearModel::mutual_independence(4), 0.05).unwrap();
+        assert_eq!(mutual.df, 18);
+        let homogeneous =
+            fit_loglinear(&table, &LogLinearModel::homogeneous_association(4), 0.05).unwrap();
+        assert_eq!(homogeneous.df, 9);
+        assert!(homogeneous.converged);
+        assert!(homogeneous.deviance <= mutual.deviance);
+        let conditional = fit_loglinear(
+            &table,
+            &LogLinearModel::conditional_independence(4, 0, 1),
+            0.05,
+        )
+        .unwrap();
+        assert_eq!(conditional.model.terms, vec![vec![0, 2, 3], vec![1, 2, 3]]);
+        assert_eq!(conditional.df, 8);
+
+        // Layers of two-way tables form a three-way table
+        let layers = vec![
+            vec![vec![911.0, 538.0], vec![44.0, 456.0]],
+            vec![vec![3.0, 43.0], vec![2.0, 279.0]],
+        ];
+        let from_layers = ContingencyTable::from_layers(&layers).unwrap();
+        assert_eq!(from_layers.counts(), substance_use().counts());
+
+        let saturated = LogLinearModel::new(vec![vec![0, 1, 2]]);
+        assert!(fit_loglinear(&substance_use(), &saturated, 0.05).is_err());
+        let outside = LogLinearModel::new(vec![vec![0, 3]]);
+        assert!(fit_loglinear(&substance_use(), &outside, 0.05).is_err());
+        let repeated = LogLinearModel::new(vec![vec![0, 0], vec![1], vec![2]]);
+        assert!(fit_loglinear(&substance_use(), &repeated, 0.05).is_err());
+        assert!(ContingencyTable::new(vec![2, 2], vec![1.0, 2.0, 3.0]).is_err());
+        assert!(ContingencyTable::new(vec![2, 2], vec![1.0, -2.0, 3.0, 4.0]).is_err());
+    }
+}
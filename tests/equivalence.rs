@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod tests_equivalence {
+    use hypors::common::TailType;
+    use hypors::equivalence::{
+        MarginType, prop_margin_ind, prop_tost_ind, t_margin, t_margin_ind, t_tost, t_tost_ind,
+        t_tost_paired, z_tost,
+    };
+
+    const EPSILON: f64 = 0.001; // Tolerance for floating-point comparisons
+
+    #[test]
+    fn test_t_tost_ind() {
+        let data1 = vec![5.1, 4.9, 5.0, 5.2, 4.8, 5.1, 5.0, 4.9];
+        let data2 = vec![5.0, 5.1, 4.9, 5.0, 5.1, 4.9, 5.0, 5.2];
+
+        let result = t_tost_ind(data1, data2, -0.3, 0.3, 0.05, false).unwrap();
+
+        let expected_t_statistic = 4.660;
+        let expected_p_value = 0.00021;
+        let expected_ci_lower = -0.129327;
+        let expected_ci_upper = 0.079327;
+        let expected_null_hypothesis = "H0: µ1 - µ2 <= -0.3 or µ1 - µ2 >= 0.3";
+        let expected_alt_hypothesis = "Ha: -0.3 < µ1 - µ2 < 0.3";
+
+        assert!((result.test_statistic - expected_t_statistic).abs() < EPSILON);
+        assert!((result.p_value - expected_p_value).abs() < 1e-5);
+        assert!((result.confidence_interval.0 - expected_ci_lower).abs() < EPSILON);
+        assert!((result.confidence_interval.1 - expected_ci_upper).abs() < EPSILON);
+        assert_eq!(result.null_hypothesis, expected_null_hypothesis);
+        assert_eq!(result.alt_hypothesis, expected_alt_hypothesis);
+        assert!(result.reject_null);
+    }
+
+    #[test]
+    fn test_t_tost_not_equivalent() {
+        let data = vec![2.0, 3.0, 5.0, 7.0, 11.0];
+
+        let result = t_tost(data, 5.0, -1.0, 1.0, 0.05).unwrap();
+
+        assert!(!result.reject_null);
+        assert_eq!(result.null_hypothesis, "H0: µ <= 4 or µ >= 6");
+    }
+
+    #[test]
+    fn test_tost_asymmetric_bounds() {
+        let before = vec![120.0, 135.0, 140.0, 125.0, 130.0, 128.0];
+        let after = vec![119.0, 134.0, 139.5, 124.0, 129.0, 127.5];
+
+        // Mean difference is about 0.83: inside (-0.5, 2.0) but not (-2.0, 0.5)
+        let inside = t_tost_paired(before.clone(), after.clone(), -0.5, 2.0, 0.05).unwrap();
+        let outside = t_tost_paired(before, after, -2.0, 0.5, 0.05).unwrap();
+
+        assert!(inside.reject_null);
+        assert!(!outside.reject_null);
+    }
+
+    #[test]
+    fn test_tost_invalid_bounds() {
+        let data = vec![1.0, 2.0, 3.0];
+        assert!(t_tost(data.clone(), 2.0, 1.0, -1.0, 0.05).is_err());
+        assert!(z_tost(data, 2.0, -1.0, -1.0, 1.0, 0.05).is_err());
+    }
+
+    #[test]
+    fn test_prop_tost_ind() {
+        let control: Vec<u8> = (0..1000).map(|i| (i % 10 < 3) as u8).collect();
+        let variant: Vec<u8> = (0..1000).map(|i| (i % 100 < 31) as u8).collect();
+
+        let result = prop_tost_ind(control, variant, -0.05, 0.05, 0.05).unwrap();
+
+        assert!(result.reject_null);
+        assert_eq!(
+            result.null_hypothesis,
+            "H0: p1 - p2 <= -0.05 or p1 - p2 >= 0.05"
+        );
+    }
+
+    #[test]
+    fn test_t_margin_non_inferiority() {
+        let data = vec![0.81, 0.79, 0.80, 0.83, 0.78, 0.82, 0.80, 0.81];
+
+        let result = t_margin(
+            data,
+            0.80,
+            0.02,
+            MarginType::NonInferiority,
+            TailType::Right,
+            0.025,
+        )
+        .unwrap();
+
+        let expected_t_statistic = 4.4096;
+        let expected_p_value = 0.00156;
+
+        assert!((result.test_statistic - expected_t_statistic).abs() < EPSILON);
+        assert!((result.p_value - expected_p_value).abs() < 1e-5);
+        assert_eq!(result.null_hypothesis, "H0: µ <= 0.78");
+        assert_eq!(result.alt_hypothesis, "Ha: µ > 0.78");
+        assert!(result.reject_null);
+    }
+
+    #[test]
+    fn test_prop_margin_ind() {
+        let variant: Vec<u8> = (0..2000).map(|i| (i % 10 < 3) as u8).collect();
+        let control: Vec<u8> = (0..2000).map(|i| (i % 100 < 31) as u8).collect();
+
+        let result = prop_margin_ind(
+            variant.clone(),
+            control.clone(),
+            0.05,
+            MarginType::NonInferiority,
+            TailType::Right,
+            0.025,
+        )
+        .unwrap();
+
+        let expected_z_statistic = 2.7475;
+        let expected_p_value = 0.003002;
+
+        assert!((result.test_statistic - expected_z_statistic).abs() < EPSILON);
+        assert!((result.p_value - expected_p_value).abs() < 1e-5);
+        assert!(result.reject_null);
+
+        let superiority = prop_margin_ind(
+            variant,
+            control,
+            0.05,
+            MarginType::Superiority,
+            TailType::Right,
+            0.025,
+        )
+        .unwrap();
+
+        assert_eq!(superiority.null_hypothesis, "H0: p1 - p2 <= 0.05");
+        assert!(!superiority.reject_null);
+    }
+
+    #[test]
+    fn test_margin_lower_is_better() {
+        let treatment = vec![101.0, 99.0, 100.5, 98.5, 100.0, 99.5, 101.5, 100.0];
+        let control = vec![100.0, 100.5, 99.0, 101.0, 100.0, 99.5, 100.5, 100.0];
+
+        let result = t_margin_ind(
+            treatment.clone(),
+            control.clone(),
+            2.0,
+            MarginType::NonInferiority,
+            TailType::Left,
+            0.025,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(result.null_hypothesis, "H0: µ1 - µ2 >= 2");
+        assert!(result.reject_null);
+
+        let two_sided = t_margin_ind(
+            treatment,
+            control,
+            2.0,
+            MarginType::NonInferiority,
+            TailType::Two,
+            0.025,
+            true,
+        );
+        assert!(two_sided.is_err());
+    }
+}
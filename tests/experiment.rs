@@ -0,0 +1,512 @@
+#[cfg(test)]
+mod tests_experiment {
+    use hypors::common::{StatError, TailType};
+    use hypors::experiment::{
+        Correction, Experiment, LiftMethod, MetricData, MetricKind, MetricOutcome, MetricRole,
+        Scorecard, SequentialSrm, SrmMethod, adjust_p_values, cuped_test, ratio_test_ind,
+        relative_lift_mean, relative_lift_proportion, relative_lift_ratio, srm_test,
+    };
+    use hypors::proportion::z_test_ind;
+    use hypors::t::t_test_ind;
+
+    const EPSILON: f64 = 1e-6; // For floating-point comparisons
+
+    fn groups() -> (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>) {
+        let pre1 = vec![10.0, 12.0, 9.0, 15.0, 11.0, 13.0, 8.0, 14.0];
+        let post1 = vec![11.5, 12.8, 10.1, 16.2, 12.3, 13.6, 9.4, 15.1];
+        let pre2 = vec![11.0, 9.0, 14.0, 12.0, 10.0, 13.0, 15.0, 8.0];
+        let post2 = vec![11.3, 9.9, 14.2, 12.6, 10.1, 13.2, 15.4, 8.3];
+        (post1, pre1, post2, pre2)
+    }
+
+    #[test]
+    fn test_cuped_reference() {
+        // Reference values computed independently in Python
+        let (post1, pre1, post2, pre2) = groups();
+        let result = cuped_test(post1, pre1, post2, pre2, TailType::Two, 0.05).unwrap();
+        assert!((result.theta - 0.959523810).abs() < EPSILON);
+        assert!((result.adjusted_difference - 0.75).abs() < EPSILON);
+        assert!((result.std_error - 0.131772325).abs() < EPSILON);
+        assert!(
+            (result.result.test_statistic - result.adjusted_difference / result.std_error).abs()
+                < EPSILON
+        );
+        let (low, high) = result.result.confidence_interval;
+        assert!(low < result.adjusted_difference && result.adjusted_difference < high);
+    }
+
+    #[test]
+    fn test_cuped_shrinks_variance() {
+        let (post1, pre1, post2, pre2) = groups();
+        let adjusted = cuped_test(
+            post1.clone(),
+            pre1,
+            post2.clone(),
+            pre2,
+            TailType::Two,
+            0.05,
+        )
+        .unwrap();
+        let raw = t_test_ind(post1, post2, TailType::Two, 0.05, false).unwrap();
+
+        assert!(adjusted.variance_reduction > 0.9);
+        assert!(adjusted.result.p_value < raw.p_value);
+        let raw_width = raw.confidence_interval.1 - raw.confidence_interval.0;
+        let adjusted_width =
+            adjusted.result.confidence_interval.1 - adjusted.result.confidence_interval.0;
+        assert!(adjusted_width < raw_width);
+    }
+
+    #[test]
+    fn test_cuped_errors() {
+        let (post1, pre1, post2, pre2) = groups();
+        assert_eq!(
+            cuped_test(
+                vec![],
+                vec![],
+                post2.clone(),
+                pre2.clone(),
+                TailType::Two,
+                0.05
+            )
+            .unwrap_err(),
+            StatError::EmptyData
+        );
+        assert_eq!(
+            cuped_test(
+                vec![1.0],
+                vec![1.0],
+                post2.clone(),
+                pre2.clone(),
+                TailType::Two,
+                0.05
+            )
+            .unwrap_err(),
+            StatError::InsufficientData
+        );
+        assert!(
+            cuped_test(
+                post1.clone(),
+                pre1[1..].to_vec(),
+                post2.clone(),
+                pre2,
+                TailType::Two,
+                0.05
+            )
+            .is_err()
+        );
+        let constant = vec![1.0; 8];
+        assert!(
+            cuped_test(
+                post1,
+                constant.clone(),
+                post2,
+                constant,
+                TailType::Two,
+                0.05
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_ratio_delta_method() {
+        // Reference values computed independently in Python
+        let revenue1 = vec![12.0, 0.0, 30.0, 8.0, 15.0, 22.0];
+        let sessions1 = vec![3.0, 1.0, 5.0, 2.0, 3.0, 4.0];
+        let revenue2 = vec![10.0, 5.0, 18.0, 0.0, 9.0, 14.0];
+        let sessions2 = vec![3.0, 2.0, 4.0, 1.0, 3.0, 4.0];
+
+        let result = ratio_test_ind(
+            revenue1,
+            sessions1,
+            revenue2,
+            sessions2,
+            TailType::Two,
+            0.05,
+        )
+        .unwrap();
+        assert!((result.ratio1 - 87.0 / 18.0).abs() < EPSILON);
+        assert!((result.ratio2 - 56.0 / 17.0).abs() < EPSILON);
+        assert!((result.result.test_statistic - 2.343208653).abs() < EPSILON);
+        assert!((result.result.p_value - 0.019118688).abs() < EPSILON);
+        assert!(result.result.reject_null);
+
+        let relative_se = 0.237859491;
+        let (low, high) = result.relative_confidence_interval;
+        assert!((high - low - 2.0 * 1.959963985 * relative_se).abs() < EPSILON);
+        assert!((result.relative_difference - (87.0 / 18.0) / (56.0 / 17.0) + 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_ratio_errors() {
+        let x = vec![1.0, 2.0, 3.0];
+        assert_eq!(
+            ratio_test_ind(vec![], vec![], x.clone(), x.clone(), TailType::Two, 0.05).unwrap_err(),
+            StatError::EmptyData
+        );
+        assert!(
+            ratio_test_ind(
+                x.clone(),
+                x[1..].to_vec(),
+                x.clone(),
+                x.clone(),
+                TailType::Two,
+                0.05
+            )
+            .is_err()
+        );
+        let zeros = vec![0.0; 3];
+        assert!(
+            ratio_test_ind(
+                x.clone(),
+                zeros.clone(),
+                x.clone(),
+                x.clone(),
+                TailType::Two,
+                0.05
+            )
+            .is_err()
+        );
+        assert!(ratio_test_ind(x.clone(), x.clone(), zeros, x, TailType::Two, 0.05).is_err());
+    }
+
+    #[test]
+    fn test_srm_chi_square_and_exact() {
+        // Reference values by enumeration in mpmath
+        let result = srm_test(&[5120.0, 4880.0], &[1.0, 1.0], SrmMethod::ChiSquare, 0.001).unwrap();
+        assert!((result.test_statistic - 5.76).abs() < EPSILON);
+        assert!((result.p_value - 0.016395072).abs() < EPSILON);
+
+        let exact = srm_test(
+            &[5120.0, 4880.0],
+            &[1.0, 1.0],
+            SrmMethod::ExactMultinomial,
+            0.001,
+        )
+        .unwrap();
+        assert!((exact.p_value - 0.016844067).abs() < EPSILON);
+        assert!(!exact.reject_null);
+
+        let exact = srm_test(
+            &[30.0, 45.0, 25.0],
+            &[1.0, 2.0, 1.0],
+            SrmMethod::ExactMultinomial,
+            0.001,
+        )
+        .unwrap();
+        assert!((exact.p_value - 0.461929679).abs() < EPSILON);
+
+        // Two variants reduce to the exact binomial test
+        let exact = srm_test(&[8.0, 2.0], &[1.0, 1.0], SrmMethod::ExactMultinomial, 0.05).unwrap();
+        assert!((exact.p_value - 0.109375).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_srm_errors() {
+        assert!(srm_test(&[10.0], &[1.0], SrmMethod::ChiSquare, 0.05).is_err());
+        assert!(srm_test(&[10.0, 12.0], &[1.0], SrmMethod::ChiSquare, 0.05).is_err());
+        assert!(srm_test(&[10.0, 12.0], &[1.0, 0.0], SrmMethod::ChiSquare, 0.05).is_err());
+        assert!(
+            srm_test(
+                &[10.5, 12.0],
+                &[1.0, 1.0],
+                SrmMethod::ExactMultinomial,
+                0.05
+            )
+            .is_err()
+        );
+        assert_eq!(
+            srm_test(&[0.0, 0.0], &[1.0, 1.0], SrmMethod::ChiSquare, 0.05).unwrap_err(),
+            StatError::EmptyData
+        );
+    }
+
+    #[test]
+    fn test_sequential_srm() {
+        let mut srm = SequentialSrm::new(&[1.0, 1.0], 0.05, 100.0).unwrap();
+        let first = srm.update(&[510.0, 490.0]).unwrap();
+        assert!(first.test_statistic < 1.0);
+        assert_eq!(first.p_value, 1.0);
+
+        // Reference Bayes factor computed in mpmath
+        let second = srm.update(&[560.0, 440.0]).unwrap();
+        assert!((second.test_statistic - 23.282811188).abs() < 1e-6);
+        assert!(second.reject_null);
+        assert_eq!(srm.counts(), &[1070.0, 930.0]);
+
+        // The p-value never increases, even when later counts look balanced
+        let third = srm.update(&[500.0, 500.0]).unwrap();
+        assert!(third.p_value <= second.p_value);
+
+        assert!(SequentialSrm::new(&[1.0, 1.0], 1.5, 100.0).is_err());
+        assert!(SequentialSrm::new(&[1.0, 1.0], 0.05, 0.0).is_err());
+        assert!(srm.update(&[1.0]).is_err());
+    }
+
+    #[test]
+    fn test_adjust_p_values() {
+        let p_values = [0.01, 0.04, 0.03, 0.5];
+        let close = |a: &[f64], b: &[f64]| a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-12);
+
+        assert!(close(
+            &adjust_p_values(&p_values, Correction::None),
+            &p_values
+        ));
+        assert!(close(
+            &adjust_p_values(&p_values, Correction::Bonferroni),
+            &[0.04, 0.16, 0.12, 1.0]
+        ));
+        assert!(close(
+            &adjust_p_values(&p_values, Correction::Holm),
+            &[0.04, 0.09, 0.09, 0.5]
+        ));
+        assert!(close(
+            &adjust_p_values(&p_values, Correction::BenjaminiHochberg),
+            &[0.04, 0.16 / 3.0, 0.16 / 3.0, 0.5]
+        ));
+        assert!(adjust_p_values(&[], Correction::Holm).is_empty());
+    }
+
+    fn experiment() -> Experiment {
+        let mut experiment = Experiment::new("control", 0.05, Correction::Holm);
+        experiment
+            .add_metric("converted", MetricKind::Binary, MetricRole::Primary, true)
+            .add_metric(
+                "latency",
+                MetricKind::Continuous,
+                MetricRole::Guardrail,
+                false,
+            )
+            .add_metric("basket", MetricKind::Rank, MetricRole::Secondary, true)
+            .add_metric(
+                "revenue_per_session",
+                MetricKind::Ratio,
+                MetricRole::Secondary,
+                true,
+            );
+
+        let conversions = |successes: usize, n: usize| {
+            MetricData::Values(
+                (0..n)
+                    .map(|i| if i < successes { 1.0 } else { 0.0 })
+                    .collect(),
+            )
+        };
+        experiment
+            .add_data("control", "converted", conversions(100, 1000))
+            .add_data("treatment", "converted", conversions(140, 1000))
+            .add_data(
+                "control",
+                "latency",
+                MetricData::Values(vec![100.0, 102.0, 98.0, 101.0, 99.0, 100.0]),
+            )
+            .add_data(
+                "treatment",
+                "latency",
+                MetricData::Values(vec![110.0, 112.0, 108.0, 111.0, 109.0, 110.0]),
+            )
+            .add_data(
+                "control",
+                "basket",
+                MetricData::Values(vec![1.0, 2.0, 3.0, 4.0, 5.0]),
+            )
+            .add_data(
+                "treatment",
+                "basket",
+                MetricData::Values(vec![2.0, 3.0, 4.0, 5.0, 6.0]),
+            )
+            .add_data(
+                "control",
+                "revenue_per_session",
+                MetricData::Ratio {
+                    numerator: vec![10.0, 5.0, 18.0, 0.0, 9.0, 14.0],
+                    denominator: vec![3.0, 2.0, 4.0, 1.0, 3.0, 4.0],
+                },
+            )
+            .add_data(
+                "treatment",
+                "revenue_per_session",
+                MetricData::Ratio {
+                    numerator: vec![12.0, 0.0, 30.0, 8.0, 15.0, 22.0],
+                    denominator: vec![3.0, 1.0, 5.0, 2.0, 3.0, 4.0],
+                },
+            );
+        experiment
+    }
+
+    #[test]
+    fn test_experiment_scorecard() {
+        let scorecard = experiment().analyze().unwrap();
+        assert_eq!(scorecard.control, "control");
+        assert_eq!(scorecard.results.len(), 4);
+
+        let converted = &scorecard.results[0];
+        assert_eq!(converted.metric, "converted");
+        assert_eq!(converted.variant, "treatment");
+        assert!((converted.difference - 0.04).abs() < 1e-12);
+        let direct = z_test_ind(
+            (0..1000).map(|i| if i < 140 { 1.0 } else { 0.0 }),
+            (0..1000).map(|i| if i < 100 { 1.0 } else { 0.0 }),
+            TailType::Two,
+            0.05,
+            true,
+        )
+        .unwrap();
+        assert!((converted.p_value - direct.p_value).abs() < 1e-12);
+        assert_eq!(converted.outcome, MetricOutcome::Improved);
+
+        // Latency went up, which is worse, so the guardrail fails
+        let latency = &scorecard.results[1];
+        assert_eq!(latency.outcome, MetricOutcome::Degraded);
+        assert!(!scorecard.guardrails_passed);
+
+        let basket = &scorecard.results[2];
+        assert_eq!(basket.difference, 1.0);
+        assert!(basket.confidence_interval.0.is_nan());
+        assert_eq!(basket.outcome, MetricOutcome::Inconclusive);
+
+        // Secondary metrics are corrected as one family
+        let revenue = &scorecard.results[3];
+        assert!((revenue.difference - (87.0 / 18.0 - 56.0 / 17.0)).abs() < 1e-12);
+        assert!((revenue.relative_difference - (87.0 / 18.0) / (56.0 / 17.0) + 1.0).abs() < 1e-12);
+        assert!((converted.relative_difference - 0.4).abs() < 1e-12);
+        assert!(converted.relative_confidence_interval.0 > 0.0);
+        assert!(basket.relative_difference.is_nan());
+        let family = adjust_p_values(&[basket.p_value, revenue.p_value], Correction::Holm);
+        assert!((basket.adjusted_p_value - family[0]).abs() < 1e-12);
+        assert!((revenue.adjusted_p_value - family[1]).abs() < 1e-12);
+
+        fn assert_serialize<T: serde::Serialize>(_: &T) {}
+        assert_serialize::<Scorecard>(&scorecard);
+    }
+
+    #[test]
+    fn test_experiment_errors() {
+        let mut empty = Experiment::new("control", 0.05, Correction::None);
+        assert!(empty.analyze().is_err());
+        empty.add_metric("converted", MetricKind::Binary, MetricRole::Primary, true);
+        empty.add_data("control", "converted", MetricData::Values(vec![0.0, 1.0]));
+        assert!(empty.analyze().is_err()); // No treatment variant
+
+        let mut missing = experiment();
+        missing.add_data("other", "converted", MetricData::Values(vec![0.0, 1.0]));
+        assert!(missing.analyze().is_err()); // No latency data for "other"
+
+        let mut mismatched = experiment();
+        mismatched.add_data(
+            "treatment",
+            "latency",
+            MetricData::Ratio {
+                numerator: vec![1.0, 2.0],
+                denominator: vec![1.0, 1.0],
+            },
+        );
+        assert!(mismatched.analyze().is_err());
+    }
+
+    #[test]
+    fn test_relative_lift_mean() {
+        // Reference values computed independently in Python
+        let treatment = vec![10.4, 10.9, 10.1, 11.0, 10.6, 10.8];
+        let control = vec![10.0, 10.2, 9.8, 10.3, 9.9, 10.1];
+        let lift =
+            |method| relative_lift_mean(treatment.clone(), control.clone(), method, 0.05).unwrap();
+
+        let delta = lift(LiftMethod::Delta);
+        assert!((delta.lift - 0.058043118).abs() < EPSILON);
+        assert!((delta.confidence_interval.0 - 0.026814572).abs() < EPSILON);
+        assert!((delta.confidence_interval.1 - 0.089271664).abs() < EPSILON);
+
+        let fieller = lift(LiftMethod::Fieller);
+        assert!((fieller.confidence_interval.0 - 0.027045015).abs() < EPSILON);
+        assert!((fieller.confidence_interval.1 - 0.089510802).abs() < EPSILON);
+
+        let log_ratio = lift(LiftMethod::LogRatio);
+        assert!((log_ratio.confidence_interval.0 - 0.027270932).abs() < EPSILON);
+        assert!((log_ratio.confidence_interval.1 - 0.089737093).abs() < EPSILON);
+
+        // The absolute result is Welch's t-test
+        let absolute = t_test_ind(treatment, control, TailType::Two, 0.05, false).unwrap();
+        assert_eq!(delta.absolute.p_value, absolute.p_value);
+    }
+
+    #[test]
+    fn test_relative_lift_proportion_and_ratio() {
+        let treatment: Vec<f64> = (0..1000).map(|i| if i < 130 { 1.0 } else { 0.0 }).collect();
+        let control: Vec<f64> = (0..1000).map(|i| if i < 100 { 1.0 } else { 0.0 }).collect();
+        let result =
+            relative_lift_proportion(treatment, control, LiftMethod::LogRatio, 0.05).unwrap();
+        assert!((result.lift - 0.3).abs() < EPSILON);
+        assert!((result.confidence_interval.0 - 0.016984271).abs() < EPSILON);
+        assert!((result.confidence_interval.1 - 0.661775947).abs() < EPSILON);
+        assert!(
+            (result.absolute.confidence_interval.0 + result.absolute.confidence_interval.1 - 0.06)
+                .abs()
+                < EPSILON
+        );
+
+        let revenue1 = vec![12.0, 0.0, 30.0, 8.0, 15.0, 22.0];
+        let sessions1 = vec![3.0, 1.0, 5.0, 2.0, 3.0, 4.0];
+        let revenue2 = vec![10.0, 5.0, 18.0, 0.0, 9.0, 14.0];
+        let sessions2 = vec![3.0, 2.0, 4.0, 1.0, 3.0, 4.0];
+        let delta = relative_lift_ratio(
+            revenue1.clone(),
+            sessions1.clone(),
+            revenue2.clone(),
+            sessions2.clone(),
+            LiftMethod::Delta,
+            0.05,
+        )
+        .unwrap();
+        let ratio = ratio_test_ind(
+            revenue1,
+            sessions1,
+            revenue2,
+            sessions2,
+            TailType::Two,
+            0.05,
+        )
+        .unwrap();
+        assert!((delta.lift - ratio.relative_difference).abs() < 1e-12);
+        assert_eq!(
+            delta.confidence_interval,
+            ratio.relative_confidence_interval
+        );
+    }
+
+    #[test]
+    fn test_relative_lift_edge_cases() {
+        // A baseline indistinguishable from zero gives an unbounded Fieller interval
+        let result = relative_lift_mean(
+            vec![1.0, 2.0, 3.0],
+            vec![-1.0, 0.2, 0.5],
+            LiftMethod::Fieller,
+            0.05,
+        )
+        .unwrap();
+        assert_eq!(
+            result.confidence_interval,
+            (f64::NEG_INFINITY, f64::INFINITY)
+        );
+
+        assert!(
+            relative_lift_mean(
+                vec![1.0, 2.0, 3.0],
+                vec![-1.0, 0.2, 0.5],
+                LiftMethod::LogRatio,
+                0.05
+            )
+            .is_err()
+        );
+        assert!(
+            relative_lift_mean(
+                vec![1.0, 2.0, 3.0],
+                vec![-1.0, 0.0, 1.0],
+                LiftMethod::Delta,
+                0.05
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests_noncentral {
+    use hypors::noncentral::{NoncentralChiSquared, NoncentralFisherSnedecor, NoncentralStudentsT};
+    use statrs::distribution::{ChiSquared, ContinuousCDF, FisherSnedecor, StudentsT};
+
+    const EPSILON: f64 = 1e-6; // For floating-point comparisons
+
+    #[test]
+    fn test_noncentral_t_cdf() {
+        let cases = [
+            (2.0, 10.0, 1.5, 0.659154072),
+            (-1.0, 5.0, -2.0, 0.841864356),
+            (1.0, 8.0, 3.0, 0.024407076),
+            (3.0, 30.0, 5.0, 0.029603872),
+        ];
+
+        for (t, df, delta, expected) in cases {
+            let dist = NoncentralStudentsT::new(df, delta).unwrap();
+            assert!((dist.cdf(t) - expected).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_noncentral_chi_squared_cdf() {
+        let dist = NoncentralChiSquared::new(3.0, 2.0).unwrap();
+        let expected_cdf = 0.593405180;
+
+        assert!((dist.cdf(5.0) - expected_cdf).abs() < EPSILON);
+        assert_eq!(dist.cdf(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_noncentral_f_cdf() {
+        let dist = NoncentralFisherSnedecor::new(2.0, 20.0, 5.0).unwrap();
+        let expected_cdf = 0.484131733;
+
+        assert!((dist.cdf(3.0) - expected_cdf).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_zero_noncentrality_matches_central() {
+        let nct = NoncentralStudentsT::new(7.0, 0.0).unwrap();
+        let t = StudentsT::new(0.0, 1.0, 7.0).unwrap();
+        let ncx2 = NoncentralChiSquared::new(4.0, 0.0).unwrap();
+        let x2 = ChiSquared::new(4.0).unwrap();
+        let ncf = NoncentralFisherSnedecor::new(3.0, 12.0, 0.0).unwrap();
+        let f = FisherSnedecor::new(3.0, 12.0).unwrap();
+
+        for x in [-2.0, -0.5, 0.3, 1.7, 4.0] {
+            assert!((nct.cdf(x) - t.cdf(x)).abs() < EPSILON);
+        }
+        for x in [0.5, 2.0, 6.0, 11.0] {
+            assert!((ncx2.cdf(x) - x2.cdf(x)).abs() < EPSILON);
+            assert!((ncf.cdf(x) - f.cdf(x)).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_inverse_cdf_round_trip() {
+        let nct = NoncentralStudentsT::new(12.0, 2.5).unwrap();
+        let ncx2 = NoncentralChiSquared::new(5.0, 8.0).unwrap();
+        let ncf = NoncentralFisherSnedecor::new(2.0, 30.0, 10.0).unwrap();
+
+        for p in [0.05, 0.5, 0.95] {
+            assert!((nct.cdf(nct.inverse_cdf(p)) - p).abs() < EPSILON);
+            assert!((ncx2.cdf(ncx2.inverse_cdf(p)) - p).abs() < EPSILON);
+            assert!((ncf.cdf(ncf.inverse_cdf(p)) - p).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(NoncentralStudentsT::new(0.0, 1.0).is_err());
+        assert!(NoncentralChiSquared::new(3.0, -1.0).is_err());
+        assert!(NoncentralFisherSnedecor::new(2.0, -5.0, 1.0).is_err());
+    }
+}
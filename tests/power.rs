@@ -0,0 +1,255 @@
+#[cfg(test)]
+mod tests_power {
+    use hypors::anova::anova;
+    use hypors::common::{StatError, TailType};
+    use hypors::power::{
+        PowerDesign, PowerTest, SimulationGroup, cohens_h, mde_mean, mde_proportion,
+        power_curve_effect, power_curve_n, simulate_power,
+    };
+    use hypors::t::{t_power, t_sample_size_ind, t_test_ind};
+    use statrs::distribution::{Exp, Normal};
+
+    const EPSILON: f64 = 0.001;
+
+    fn design(test: PowerTest) -> PowerDesign {
+        PowerDesign {
+            test,
+            effect_size: Some(0.5),
+            alpha: Some(0.05),
+            power: Some(0.80),
+            n: Some(64.0),
+            ratio: 1.0,
+            tail: TailType::Two,
+        }
+    }
+
+    #[test]
+    fn test_solve_power_matches_t_power() {
+        let mut d = design(PowerTest::OneSampleT);
+        d.power = None;
+        d.n = Some(34.0);
+        let solution = d.solve().unwrap();
+
+        let expected = t_power(0.5, 0.05, 34.0, 1.0, TailType::Two);
+        assert!((solution.power - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_solve_n_two_sample() {
+        let mut d = design(PowerTest::TwoSampleT);
+        d.n = None;
+        let solution = d.solve().unwrap();
+
+        // pwr.t.test(d = 0.5, power = 0.8) gives n = 63.77 per group
+        assert_eq!(solution.n, 64.0);
+        assert_eq!(solution.n_total, 128.0);
+        assert!(solution.power >= 0.80);
+    }
+
+    #[test]
+    fn test_solve_n_with_ratio() {
+        let mut d = design(PowerTest::TwoSampleZ);
+        d.n = None;
+        d.ratio = 2.0;
+        let solution = d.solve().unwrap();
+
+        // n1 = (1 + 1/k)(z_α/2 + z_β)² / d² = 1.5 * 7.849 / 0.25 = 47.1
+        assert_eq!(solution.n, 48.0);
+        assert_eq!(solution.n_total, 144.0);
+
+        // The second group is rounded up as in t_sample_size_ind
+        let mut d = design(PowerTest::TwoSampleT);
+        d.n = None;
+        d.ratio = 1.0 / 3.0;
+        let solution = d.solve().unwrap();
+        let (n1, n2) = t_sample_size_ind(0.5, 0.05, 0.80, 1.0, 1.0 / 3.0, TailType::Two);
+        assert_eq!(solution.n, n1);
+        assert_eq!(solution.n_total, n1 + n2);
+        assert_eq!(n2.fract(), 0.0);
+    }
+
+    #[test]
+    fn test_solve_effect_size_round_trip() {
+        let mut d = design(PowerTest::TwoSampleT);
+        d.effect_size = None;
+        let mde = d.solve().unwrap().effect_size;
+
+        let mut check = design(PowerTest::TwoSampleT);
+        check.effect_size = Some(mde);
+        check.power = None;
+        assert!((check.solve().unwrap().power - 0.80).abs() < 1e-6);
+        assert!(mde > 0.49 && mde < 0.5);
+    }
+
+    #[test]
+    fn test_solve_alpha_and_left_tail() {
+        let mut d = design(PowerTest::PairedZ);
+        d.alpha = None;
+        d.n = Some(25.0);
+        d.tail = TailType::Left;
+        let solution = d.solve().unwrap();
+
+        // Φ(z_α - 0.5 * 5) = 0.8  =>  z_α = 2.5 - 0.8416  =>  α = Φ(-1.6584)
+        assert!((solution.alpha - 0.04862).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_solve_proportions_and_chi_square() {
+        let mut d = design(PowerTest::TwoProportions);
+        d.effect_size = Some(cohens_h(0.4, 0.5));
+        d.n = None;
+        // (1.96 + 0.8416)² * 2 / h² = 387.1
+        assert_eq!(d.solve().unwrap().n, 388.0);
+
+        let mut d = design(PowerTest::ChiSquare { df: 2 });
+        d.effect_size = Some(0.3);
+        d.n = None;
+        assert_eq!(d.solve().unwrap().n, 108.0);
+    }
+
+    #[test]
+    fn test_solve_invalid_design() {
+        let d = design(PowerTest::OneSampleT);
+        assert!(matches!(d.solve(), Err(StatError::ComputeError(_))));
+
+        let mut d = design(PowerTest::Anova { num_groups: 1 });
+        d.n = None;
+        assert!(d.solve().is_err());
+
+        let mut d = design(PowerTest::OneSampleT);
+        d.effect_size = None;
+        d.power = Some(0.01);
+        assert!(d.solve().is_err());
+    }
+
+    #[test]
+    fn test_power_curves() {
+        let d = design(PowerTest::TwoSampleT);
+        let curve = power_curve_n(&d, &[16.0, 32.0, 64.0, 128.0]).unwrap();
+        assert_eq!(curve.len(), 4);
+        assert!(curve.windows(2).all(|w| w[0].power < w[1].power));
+        assert!((curve[2].power - 0.8015).abs() < EPSILON);
+        assert_eq!(curve[3].n_total, 256.0);
+
+        let curve = power_curve_effect(&d, &[0.0, 0.25, 0.5]).unwrap();
+        assert!((curve[0].power - 0.05).abs() < 1e-9);
+        assert!((curve[2].power - 0.8015).abs() < EPSILON);
+
+        assert!(power_curve_n(&d, &[]).is_err());
+        assert!(power_curve_n(&d, &[1.0]).is_err());
+    }
+
+    #[test]
+    fn test_mde() {
+        // (z_α/2 + z_β) σ √(2/n) = 2.8016 * 20 * √0.002
+        let mde = mde_mean(
+            PowerTest::TwoSampleZ,
+            0.05,
+            0.80,
+            1000.0,
+            20.0,
+            1.0,
+            TailType::Two,
+        )
+        .unwrap();
+        assert!((mde - 2.5058).abs() < EPSILON);
+
+        // Round trip with prop_sample_size_ind, which gives 388 per group for 0.4 vs 0.5
+        let mde = mde_proportion(0.4, 0.05, 0.80, 388.0, 1.0, TailType::Two).unwrap();
+        assert!(mde > 0.099 && mde <= 0.1);
+
+        let mde = mde_proportion(0.4, 0.05, 0.80, 388.0, 1.0, TailType::Left).unwrap();
+        assert!(mde < 0.0);
+
+        assert!(
+            mde_mean(
+                PowerTest::TwoProportions,
+                0.05,
+                0.8,
+                100.0,
+                1.0,
+                1.0,
+                TailType::Two
+            )
+            .is_err()
+        );
+        assert!(mde_proportion(0.4, 0.05, 0.80, 2.0, 1.0, TailType::Two).is_err());
+    }
+
+    #[test]
+    fn test_simulate_power_matches_exact() {
+        let groups = vec![
+            SimulationGroup::new(Normal::new(0.0, 1.0).unwrap(), 64),
+            SimulationGroup::new(Normal::new(0.5, 1.0).unwrap(), 64),
+        ];
+        let test = |samples: &[Vec<f64>]| {
+            t_test_ind(
+                samples[0].clone(),
+                samples[1].clone(),
+                TailType::Two,
+                0.05,
+                true,
+            )
+        };
+
+        let result = simulate_power(&groups, test, 2000, 0.01, 7, 1).unwrap();
+        let (lower, upper) = result.confidence_interval;
+        assert!(lower < 0.8015 && 0.8015 < upper);
+        assert_eq!(result.n_simulations, 2000);
+
+        // Per-simulation seeding makes the result independent of the thread count
+        let parallel = simulate_power(&groups, test, 2000, 0.01, 7, 3).unwrap();
+        assert_eq!(parallel.n_rejections, result.n_rejections);
+    }
+
+    #[test]
+    fn test_simulate_power_mixed_distributions() {
+        let groups = vec![
+            SimulationGroup::new(Exp::new(1.0).unwrap(), 10),
+            SimulationGroup::new(Exp::new(1.0).unwrap(), 10),
+            SimulationGroup::new(Exp::new(1.0).unwrap(), 10),
+        ];
+        let result = simulate_power(&groups, |s| anova(s, 0.05), 1000, 0.05, 1, 2).unwrap();
+
+        // Under the null, the rejection rate is close to alpha
+        assert!(result.power < 0.1);
+        assert!(result.confidence_interval.0 >= 0.0);
+
+        assert!(simulate_power(&[], |s| anova(s, 0.05), 10, 0.05, 1, 1).is_err());
+        assert!(simulate_power(&groups, |s| anova(s, 0.05), 10, 0.05, 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_simulate_power_adjacent_seeds_share_no_samples() {
+        use std::sync::Mutex;
+
+        let groups = vec![SimulationGroup::new(Normal::new(0.0, 1.0).unwrap(), 5)];
+        let draws = |seed: u64| {
+            let seen = Mutex::new(Vec::new());
+            simulate_power(
+                &groups,
+                |samples: &[Vec<f64>]| {
+                    seen.lock().unwrap().push(samples[0][0]);
+                    Ok::<_, StatError>(hypors::common::TestResult {
+                        test_statistic: 0.0,
+                        p_value: 1.0,
+                        confidence_interval: (f64::NAN, f64::NAN),
+                        null_hypothesis: String::new(),
+                        alt_hypothesis: String::new(),
+                        reject_null: false,
+                    })
+                },
+                50,
+                0.05,
+                seed,
+                1,
+            )
+            .unwrap();
+            seen.into_inner().unwrap()
+        };
+
+        let first = draws(11);
+        let second = draws(12);
+        assert!(first.iter().all(|x| !second.contains(x)));
+    }
+}
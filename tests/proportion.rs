@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod tests_proportion {
     use hypors::common::TailType;
-    use hypors::proportion::{prop_sample_size, z_test, z_test_ind};
+    use hypors::proportion::{
+        McNemarMethod, cochran_q, mcnemar_bowker, mcnemar_test, prop_sample_size,
+        prop_sample_size_ind, z_test, z_test_ind,
+    };
 
     const EPSILON: f64 = 0.001; // Tolerance for floating-point comparisons
 
@@ -83,4 +86,112 @@ mod tests_proportion {
 
         assert!((n - expected_sample_size).abs() < 1.0);
     }
+
+    #[test]
+    fn test_prop_sample_size_ind() {
+        let (n1, n2) = prop_sample_size_ind(0.4, 0.5, 0.05, 0.80, 1.0, TailType::Two);
+        assert_eq!((n1, n2), (388.0, 388.0));
+
+        let (n1, n2) = prop_sample_size_ind(0.4, 0.5, 0.05, 0.80, 1.0 / 9.0, TailType::Right);
+        assert_eq!((n1, n2), (1504.0, 168.0));
+
+        // The alternative lies in the wrong direction for a left-tailed test
+        let (n1, _) = prop_sample_size_ind(0.4, 0.5, 0.05, 0.80, 1.0, TailType::Left);
+        assert!(n1.is_infinite());
+    }
+
+    fn paired_samples(b: usize, c: usize, concordant: usize) -> (Vec<f64>, Vec<f64>) {
+        let mut data1 = vec![1.0; b];
+        let mut data2 = vec![0.0; b];
+        data1.extend(vec![0.0; c]);
+        data2.extend(vec![1.0; c]);
+        data1.extend(vec![1.0; concordant]);
+        data2.extend(vec![1.0; concordant]);
+        (data1, data2)
+    }
+
+    #[test]
+    fn test_mcnemar_methods() {
+        // 15 vs 5 discordant pairs out of 100
+        let (data1, data2) = paired_samples(15, 5, 80);
+
+        let asymptotic = mcnemar_test(
+            data1.clone(),
+            data2.clone(),
+            McNemarMethod::Asymptotic,
+            0.05,
+        )
+        .unwrap();
+        assert!((asymptotic.test_statistic - 5.0).abs() < EPSILON);
+        assert!((asymptotic.p_value - 0.025347319).abs() < EPSILON);
+        let (low, high) = asymptotic.confidence_interval;
+        assert!(((low + high) / 2.0 - 0.1).abs() < EPSILON);
+
+        let corrected = mcnemar_test(
+            data1.clone(),
+            data2.clone(),
+            McNemarMethod::ContinuityCorrected,
+            0.05,
+        )
+        .unwrap();
+        assert!((corrected.test_statistic - 4.05).abs() < EPSILON);
+
+        let exact = mcnemar_test(data1, data2, McNemarMethod::Exact, 0.05).unwrap();
+        assert!((exact.p_value - 0.041389465).abs() < EPSILON);
+        assert!(exact.reject_null);
+
+        // No discordant pairs
+        let (data1, data2) = paired_samples(0, 0, 10);
+        let result = mcnemar_test(data1, data2, McNemarMethod::Exact, 0.05).unwrap();
+        assert_eq!(result.p_value, 1.0);
+
+        assert!(mcnemar_test(vec![1.0, 0.0], vec![1.0], McNemarMethod::Exact, 0.05).is_err());
+        assert!(mcnemar_test(vec![2.0, 0.0], vec![1.0, 0.0], McNemarMethod::Exact, 0.05).is_err());
+    }
+
+    #[test]
+    fn test_mcnemar_bowker() {
+        let table = vec![
+            vec![20.0, 5.0, 2.0],
+            vec![10.0, 30.0, 3.0],
+            vec![6.0, 9.0, 15.0],
+        ];
+        let result = mcnemar_bowker(&table, 0.05).unwrap();
+        assert!((result.test_statistic - (25.0 / 15.0 + 16.0 / 8.0 + 36.0 / 12.0)).abs() < EPSILON);
+
+        // A 2x2 table reduces to McNemar's asymptotic test
+        let (data1, data2) = paired_samples(15, 5, 80);
+        let mcnemar = mcnemar_test(data1, data2, McNemarMethod::Asymptotic, 0.05).unwrap();
+        let bowker = mcnemar_bowker(&[vec![80.0, 15.0], vec![5.0, 0.0]], 0.05).unwrap();
+        assert!((bowker.test_statistic - mcnemar.test_statistic).abs() < EPSILON);
+        assert!((bowker.p_value - mcnemar.p_value).abs() < EPSILON);
+
+        assert!(mcnemar_bowker(&[vec![1.0, 2.0]], 0.05).is_err());
+    }
+
+    #[test]
+    fn test_cochran_q() {
+        let flow_a = vec![1, 1, 0, 1, 1, 1, 0, 1];
+        let flow_b = vec![1, 0, 0, 1, 0, 1, 0, 0];
+        let flow_c = vec![0, 0, 0, 1, 0, 1, 0, 0];
+        let result = cochran_q(&[flow_a, flow_b, flow_c], 0.05).unwrap();
+        assert!((result.test_statistic - 6.5).abs() < EPSILON);
+        assert!((result.p_value - 0.038774208).abs() < EPSILON);
+
+        // Two treatments reduce to McNemar's asymptotic test
+        let (data1, data2) = paired_samples(15, 5, 80);
+        let mcnemar = mcnemar_test(
+            data1.clone(),
+            data2.clone(),
+            McNemarMethod::Asymptotic,
+            0.05,
+        )
+        .unwrap();
+        let q = cochran_q(&[data1, data2], 0.05).unwrap();
+        assert!((q.test_statistic - mcnemar.test_statistic).abs() < EPSILON);
+
+        assert!(cochran_q(&[vec![1, 0]], 0.05).is_err());
+        assert!(cochran_q(&[vec![1, 0], vec![1, 0]], 0.05).is_err());
+        assert!(cochran_q(&[vec![1, 0], vec![1]], 0.05).is_err());
+    }
 }
@@ -0,0 +1,287 @@
+#[cfg(test)]
+mod tests_sequential {
+    use hypors::common::TailType;
+    use hypors::sequential::{
+        BoundaryType, FutilityBound, MsprtMeans, MsprtProportions, SequentialDecision,
+        SpendingFunction, Sprt, SprtModel, group_sequential_design,
+    };
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const EPSILON: f64 = 0.005; // Tabulated boundaries are given to 3 decimals
+    const LOOKS: [f64; 5] = [0.2, 0.4, 0.6, 0.8, 1.0];
+
+    fn bounds(boundary: BoundaryType) -> Vec<f64> {
+        group_sequential_design(&LOOKS, 0.05, TailType::Two, boundary, None)
+            .unwrap()
+            .looks
+            .iter()
+            .map(|l| l.efficacy_bound)
+            .collect()
+    }
+
+    #[test]
+    fn test_classical_boundaries() {
+        // Jennison & Turnbull (2000), Tables 2.1 and 2.3: K = 5, two-sided alpha = 0.05
+        let pocock = bounds(BoundaryType::Pocock);
+        assert!(pocock.iter().all(|c| (c - 2.413).abs() < EPSILON));
+
+        let obf = bounds(BoundaryType::OBrienFleming);
+        let expected = [4.562, 3.226, 2.634, 2.281, 2.040];
+        for (c, e) in obf.iter().zip(expected) {
+            assert!((c - e).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_lan_demets_boundaries() {
+        let obf = bounds(BoundaryType::LanDeMets(SpendingFunction::OBrienFleming));
+        let expected = [4.877, 3.357, 2.680, 2.290, 2.031];
+        for (c, e) in obf.iter().zip(expected) {
+            assert!((c - e).abs() < EPSILON);
+        }
+
+        let pocock = bounds(BoundaryType::LanDeMets(SpendingFunction::Pocock));
+        let expected = [2.438, 2.427, 2.410, 2.397, 2.386];
+        for (c, e) in pocock.iter().zip(expected) {
+            assert!((c - e).abs() < EPSILON);
+        }
+
+        // The spending function determines the error spent at each look
+        let design = group_sequential_design(
+            &[0.3, 0.7, 1.0],
+            0.025,
+            TailType::Right,
+            BoundaryType::LanDeMets(SpendingFunction::Pocock),
+            None,
+        )
+        .unwrap();
+        for look in &design.looks {
+            let spent = SpendingFunction::Pocock.spend(0.025, look.info_fraction);
+            assert!((look.cumulative_alpha - spent).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_futility_boundaries() {
+        let futility = FutilityBound {
+            spending: SpendingFunction::OBrienFleming,
+            beta: 0.1,
+            drift: 3.24,
+        };
+        let design = group_sequential_design(
+            &LOOKS,
+            0.025,
+            TailType::Right,
+            BoundaryType::LanDeMets(SpendingFunction::OBrienFleming),
+            Some(futility.clone()),
+        )
+        .unwrap();
+
+        let futility_bounds: Vec<f64> = design
+            .looks
+            .iter()
+            .map(|l| l.futility_bound.unwrap())
+            .collect();
+        assert!(futility_bounds.windows(2).all(|w| w[0] < w[1]));
+        let last = &design.looks[4];
+        assert_eq!(last.futility_bound.unwrap(), last.efficacy_bound);
+
+        // Left-tailed designs mirror the boundaries
+        let mirrored = group_sequential_design(
+            &LOOKS,
+            0.025,
+            TailType::Left,
+            BoundaryType::LanDeMets(SpendingFunction::OBrienFleming),
+            Some(futility),
+        )
+        .unwrap();
+        assert_eq!(
+            mirrored.looks[2].efficacy_bound,
+            -design.looks[2].efficacy_bound
+        );
+        assert_eq!(
+            mirrored.decide(1, 0.5).unwrap(),
+            SequentialDecision::AcceptNull
+        );
+        assert_eq!(
+            mirrored.decide(1, -3.5).unwrap(),
+            SequentialDecision::RejectNull
+        );
+        assert_eq!(
+            mirrored.decide(1, -1.0).unwrap(),
+            SequentialDecision::Continue
+        );
+    }
+
+    #[test]
+    fn test_decide_and_invalid_designs() {
+        let design =
+            group_sequential_design(&LOOKS, 0.05, TailType::Two, BoundaryType::Pocock, None)
+                .unwrap();
+        assert_eq!(design.decide(0, 2.2).unwrap(), SequentialDecision::Continue);
+        assert_eq!(
+            design.decide(0, -2.5).unwrap(),
+            SequentialDecision::RejectNull
+        );
+        assert_eq!(
+            design.decide(4, 2.2).unwrap(),
+            SequentialDecision::AcceptNull
+        );
+        assert!(design.decide(5, 2.2).is_err());
+
+        assert!(
+            group_sequential_design(&[], 0.05, TailType::Two, BoundaryType::Pocock, None).is_err()
+        );
+        assert!(
+            group_sequential_design(
+                &[0.5, 0.4, 1.0],
+                0.05,
+                TailType::Two,
+                BoundaryType::Pocock,
+                None
+            )
+            .is_err()
+        );
+        assert!(
+            group_sequential_design(&[0.5, 0.8], 0.05, TailType::Two, BoundaryType::Pocock, None)
+                .is_err()
+        );
+
+        let futility = FutilityBound {
+            spending: SpendingFunction::Pocock,
+            beta: 0.2,
+            drift: 2.8,
+        };
+        assert!(
+            group_sequential_design(
+                &LOOKS,
+                0.05,
+                TailType::Two,
+                BoundaryType::Pocock,
+                Some(futility)
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_msprt_means_single_look() {
+        // Difference 0.3 with variance 4/100 + 4/100 and tau = 1
+        let mut test = MsprtMeans::new(0.05, 1.0, 0.0).unwrap();
+        let result = test.update(100.0, 10.3, 4.0, 100.0, 10.0, 4.0).unwrap();
+
+        assert!((result.test_statistic - 0.45817).abs() < 1e-4);
+        assert_eq!(result.p_value, 1.0);
+        assert!((result.confidence_interval.0 - (0.3 - 0.86171)).abs() < 1e-4);
+        assert!((result.confidence_interval.1 - (0.3 + 0.86171)).abs() < 1e-4);
+        assert_eq!(result.null_hypothesis, "H0: µ1 - µ2 = 0");
+
+        // Batches merge to the same moments as the pooled data
+        let mut batched = MsprtMeans::new(0.05, 1.0, 0.0).unwrap();
+        batched.update_data(&[1.0, 2.0, 3.0], &[2.0]).unwrap_err();
+        let merged = batched.update_data(&[4.0, 5.0], &[4.0, 6.0]).unwrap();
+        let mut whole = MsprtMeans::new(0.05, 1.0, 0.0).unwrap();
+        let direct = whole
+            .update_data(&[1.0, 2.0, 3.0, 4.0, 5.0], &[2.0, 4.0, 6.0])
+            .unwrap();
+        assert!((merged.test_statistic - direct.test_statistic).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_msprt_type_one_error_under_continuous_monitoring() {
+        // Peeking after every batch must not push the false-positive rate above alpha
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut false_positives = 0;
+        for _ in 0..200 {
+            let mut test = MsprtProportions::new(0.05, 0.05, 0.0).unwrap();
+            let mut rejected = false;
+            for _ in 0..50 {
+                let s1 = (0..100).filter(|_| rng.gen_bool(0.2)).count() as f64;
+                let s2 = (0..100).filter(|_| rng.gen_bool(0.2)).count() as f64;
+                rejected |= test.update(s1, 100.0, s2, 100.0).unwrap().reject_null;
+            }
+            if rejected {
+                false_positives += 1;
+            }
+        }
+        assert!(false_positives <= 15);
+
+        assert!(MsprtProportions::new(0.05, 0.0, 0.0).is_err());
+        let mut test = MsprtProportions::new(0.05, 0.05, 0.0).unwrap();
+        assert!(test.update(5.0, 4.0, 1.0, 4.0).is_err());
+    }
+
+    #[test]
+    fn test_sprt_oc_and_asn() {
+        let model = SprtModel::Normal {
+            mu0: 0.0,
+            mu1: 0.5,
+            std_dev: 1.0,
+        };
+        let sprt = Sprt::new(model, 0.05, 0.05).unwrap();
+        let (lower, upper) = sprt.bounds();
+        assert!((upper - 19.0_f64.ln()).abs() < 1e-12);
+        assert!((lower + 19.0_f64.ln()).abs() < 1e-12);
+
+        // At the hypotheses the OC equals 1 - α and β; at the midpoint the drift vanishes
+        assert!((sprt.oc(0.0).unwrap() - 0.95).abs() < 1e-9);
+        assert!((sprt.oc(0.5).unwrap() - 0.05).abs() < 1e-9);
+        assert!((sprt.oc(0.25).unwrap() - 0.5).abs() < 1e-9);
+        assert!((sprt.asn(0.25).unwrap() - 19.0_f64.ln().powi(2) / 0.25).abs() < 1e-6);
+
+        let poisson = Sprt::new(
+            SprtModel::Poisson {
+                lambda0: 2.0,
+                lambda1: 3.0,
+            },
+            0.05,
+            0.2,
+        )
+        .unwrap();
+        assert!((poisson.oc(2.0).unwrap() - 0.95).abs() < 1e-6);
+        assert!((poisson.oc(3.0).unwrap() - 0.2).abs() < 1e-6);
+        assert!(poisson.oc(2.5).unwrap() < poisson.oc(2.2).unwrap());
+        assert!(poisson.asn(-1.0).is_err());
+    }
+
+    #[test]
+    fn test_sprt_decisions() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let model = SprtModel::Bernoulli { p0: 0.1, p1: 0.3 };
+
+        let mut rejections = 0;
+        let mut total_n = 0;
+        for _ in 0..500 {
+            let mut sprt = Sprt::new(model.clone(), 0.05, 0.1).unwrap();
+            let mut decision = SequentialDecision::Continue;
+            while decision == SequentialDecision::Continue {
+                let x = if rng.gen_bool(0.1) { 1.0 } else { 0.0 };
+                decision = sprt.update(x).unwrap();
+            }
+            if decision == SequentialDecision::RejectNull {
+                rejections += 1;
+            }
+            total_n += sprt.n();
+        }
+
+        // Wald's bounds keep the error near alpha, and the ASN approximates the mean length
+        let sprt = Sprt::new(model.clone(), 0.05, 0.1).unwrap();
+        assert!(rejections <= 40);
+        let mean_n = total_n as f64 / 500.0;
+        assert!((mean_n - sprt.asn(0.1).unwrap()).abs() < 5.0);
+
+        // A stopped test ignores further observations
+        let mut sprt = Sprt::new(model.clone(), 0.05, 0.1).unwrap();
+        let decision = sprt.update_batch(&[1.0; 10]).unwrap();
+        assert_eq!(decision, SequentialDecision::RejectNull);
+        let n = sprt.n();
+        assert_eq!(sprt.update(0.0).unwrap(), SequentialDecision::RejectNull);
+        assert_eq!(sprt.n(), n);
+
+        let mut fresh = Sprt::new(model, 0.05, 0.1).unwrap();
+        assert!(fresh.update(0.5).is_err());
+        assert!(Sprt::new(SprtModel::Bernoulli { p0: 0.2, p1: 0.2 }, 0.05, 0.1).is_err());
+        assert!(Sprt::new(SprtModel::Bernoulli { p0: 0.1, p1: 0.2 }, 0.6, 0.5).is_err());
+    }
+}
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod tests_t_test {
     use hypors::common::TailType;
-    use hypors::t::{t_sample_size, t_test, t_test_ind, t_test_paired};
+    use hypors::t::{
+        t_sample_size, t_sample_size_ind, t_sample_size_paired, t_test, t_test_ind, t_test_paired,
+    };
 
     const EPSILON: f64 = 0.001; // For floating-point comparisons
 
@@ -111,11 +113,40 @@ mod tests_t_test {
         let tail = TailType::Two;
 
         let n = t_sample_size(effect_size, alpha, power, std_dev, tail);
-        let expected_sample_size = 88.49;
+        let expected_sample_size = 90.0;
 
         assert!(
             (n - expected_sample_size).abs() < 1.0,
             "Sample size is incorrect"
         );
     }
+
+    #[test]
+    fn test_t_sample_size_two_sample_and_paired() {
+        // Equal allocation matches pwr.t.test(d = 0.5, power = 0.8): 63.77 per group
+        let (n1, n2) = t_sample_size_ind(0.5, 0.05, 0.80, 1.0, 1.0, TailType::Two);
+        assert_eq!((n1, n2), (64.0, 64.0));
+
+        // A 10/90 split needs more units overall than equal allocation
+        let (n1, n2) = t_sample_size_ind(0.5, 0.05, 0.80, 1.0, 9.0, TailType::Two);
+        assert_eq!((n1, n2), (36.0, 324.0));
+
+        // Correlation 0.5 leaves the differences with unit standard deviation
+        let n = t_sample_size_paired(0.5, 0.05, 0.80, 1.0, 0.5, TailType::Two);
+        assert_eq!(n, 34.0);
+        assert!(t_sample_size_paired(0.5, 0.05, 0.80, 1.0, 1.0, TailType::Two).is_nan());
+    }
+
+    #[test]
+    fn test_t_sample_size_invalid_inputs() {
+        // Invalid inputs give NaN rather than a small finite sample size
+        assert!(t_sample_size(0.5, 0.05, 0.80, 0.0, TailType::Two).is_nan());
+        assert!(t_sample_size(0.5, 1.5, 0.80, 1.0, TailType::Two).is_nan());
+        assert!(t_sample_size(0.5, 0.0, 0.80, 1.0, TailType::Two).is_nan());
+        assert!(t_sample_size(0.5, 0.05, 1.0, 1.0, TailType::Two).is_nan());
+        let (n1, n2) = t_sample_size_ind(0.5, 0.05, 0.80, 0.0, 1.0, TailType::Two);
+        assert!(n1.is_nan() && n2.is_nan());
+        let (n1, n2) = t_sample_size_ind(0.5, 1.5, 0.80, 1.0, 1.0, TailType::Two);
+        assert!(n1.is_nan() && n2.is_nan());
+    }
 }
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod tests_z_test {
     use hypors::common::TailType;
-    use hypors::z::{z_sample_size, z_test, z_test_ind, z_test_paired};
+    use hypors::z::{
+        z_sample_size, z_sample_size_ind, z_sample_size_paired, z_test, z_test_ind, z_test_paired,
+    };
 
     const EPSILON: f64 = 0.001; // For floating-point comparisons
 
@@ -107,4 +109,24 @@ mod tests_z_test {
             "Sample size is incorrect"
         );
     }
+
+    #[test]
+    fn test_z_sample_size_two_sample_and_paired() {
+        // n1 = (1 + 1/k)(z_α/2 + z_β)² σ² / d² = 1.5 * 7.849 / 0.25 = 47.1
+        let (n1, n2) = z_sample_size_ind(0.5, 0.05, 0.80, 1.0, 1.0, 2.0, TailType::Two);
+        assert_eq!((n1, n2), (48.0, 96.0));
+
+        // Correlation 0.75 halves the standard deviation of the differences
+        let n = z_sample_size_paired(0.5, 0.05, 0.80, 1.0, 0.75, TailType::Two);
+        assert_eq!(n, 16.0);
+    }
+
+    #[test]
+    fn test_z_sample_size_invalid_inputs() {
+        // Invalid inputs give NaN rather than a small finite sample size
+        assert!(z_sample_size(0.5, 0.05, 0.80, 0.0, TailType::Two).is_nan());
+        assert!(z_sample_size(0.5, 1.5, 0.80, 1.0, TailType::Two).is_nan());
+        let (n1, n2) = z_sample_size_ind(0.5, 0.05, 0.80, 0.0, 0.0, 1.0, TailType::Two);
+        assert!(n1.is_nan() && n2.is_nan());
+    }
 }